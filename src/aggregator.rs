@@ -0,0 +1,372 @@
+//! Buffered Metrics Aggregator
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use crate::{client, routes, types::route::Route};
+
+/// The default flush interval
+pub const DEFAULT_FLUSH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The shortest flush interval, shorter intervals are clamped to it
+pub const MIN_FLUSH_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Buffered Metrics Aggregator
+///
+/// ## Overview
+///
+/// The aggregator buffers metric observations in memory, keyed by metric name and tags, and aggregates them over a flush interval.
+/// Each flush submits the aggregated series to the `/v2/series` route and the raw distribution values to the `/v1/distribution_points` route, so instrumenting a service costs one or two requests per interval instead of one per observation.
+///
+/// Observations are aggregated the same way the DogStatsD server does:
+///
+/// - count: values are summed and submitted as a count over the interval
+/// - gauge: the last value is submitted
+/// - rate: values are summed and submitted as a per-second rate over the interval
+/// - histogram: submitted as the `.avg`, `.median`, `.max` and `.95percentile` gauges and the `.count` count
+/// - distribution: all values are submitted as distribution points
+/// - set: the number of unique values is submitted as a gauge
+///
+/// A flush takes the buffered observations before submitting them, and a failed submission is not retried since some of its batches may have been accepted.
+/// The series and distributions of a failed submission are dropped and counted in [dropped](Aggregator::dropped).
+///
+/// ## Usage
+///
+/// ```rust
+/// use std::time::Duration;
+/// use ddog::prelude::*;
+///
+/// async {
+///     let client = Client::new().with_api_key("<api_key>");
+///     let aggregator = Aggregator::new(client)
+///         .with_flush_interval(Duration::from_secs(10))
+///         .with_tags(vec!["service:my-service"]);
+///
+///     // Flush in the background every 10 seconds
+///     let handle = aggregator.spawn();
+///
+///     aggregator.count("requests", 1.0, &["route:/"]);
+///     aggregator.distribution("request.latency", 12.5, &["route:/"]);
+///
+///     // Stop the background task and flush the remaining observations
+///     handle.abort();
+///     if let Err(e) = aggregator.flush().await {
+///         println!("Failed to flush metrics: {}", e);
+///     }
+/// };
+/// ```
+#[derive(Clone, Debug)]
+pub struct Aggregator {
+    /// The api client used to flush metrics
+    pub client: client::Client,
+    /// The interval observations are aggregated over
+    pub flush_interval: Duration,
+    /// The host attached to every flushed metric
+    pub host: Option<String>,
+    /// Tags attached to every flushed metric
+    pub tags: Vec<String>,
+    buckets: Arc<Mutex<HashMap<MetricKey, Bucket>>>,
+    dropped: Arc<AtomicUsize>,
+}
+
+/// An aggregation key
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+struct MetricKey {
+    kind: MetricKind,
+    name: String,
+    tags: Vec<String>,
+}
+
+/// The kind of an observation
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+enum MetricKind {
+    Count,
+    Gauge,
+    Rate,
+    Histogram,
+    Distribution,
+    Set,
+}
+
+/// Observations aggregated within a flush interval
+#[derive(Clone, Debug)]
+enum Bucket {
+    Sum(f64),
+    Last(f64),
+    Values(Vec<f64>),
+    Unique(HashSet<String>),
+}
+
+impl Aggregator {
+    /// Instantiates a new aggregator flushing through the given client
+    pub fn new(client: client::Client) -> Self {
+        tracing::info!(target: "aggregator", "Aggregator Created");
+        Self {
+            client,
+            flush_interval: DEFAULT_FLUSH_INTERVAL,
+            host: None,
+            tags: vec![],
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+            dropped: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Sets the interval observations are aggregated over
+    pub fn with_flush_interval(mut self, flush_interval: Duration) -> Self {
+        self.flush_interval = flush_interval.max(MIN_FLUSH_INTERVAL);
+        self
+    }
+
+    /// Sets the host attached to every flushed metric
+    pub fn with_host(mut self, host: &str) -> Self {
+        self.host = Some(host.to_string());
+        self
+    }
+
+    /// Adds tags attached to every flushed metric
+    pub fn with_tags(mut self, tags: Vec<&str>) -> Self {
+        self.tags.extend(tags.into_iter().map(String::from));
+        self
+    }
+
+    /// The number of series and distributions dropped by failed flushes
+    pub fn dropped(&self) -> usize {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Increments a count
+    pub fn count(&self, name: &str, value: f64, tags: &[&str]) {
+        self.observe(MetricKind::Count, name, tags, |bucket| match bucket {
+            Some(Bucket::Sum(sum)) => Bucket::Sum(sum + value),
+            _ => Bucket::Sum(value),
+        })
+    }
+
+    /// Sets a gauge
+    pub fn gauge(&self, name: &str, value: f64, tags: &[&str]) {
+        self.observe(MetricKind::Gauge, name, tags, |_| Bucket::Last(value))
+    }
+
+    /// Adds to a rate, submitted as the per-second rate over the flush interval
+    pub fn rate(&self, name: &str, value: f64, tags: &[&str]) {
+        self.observe(MetricKind::Rate, name, tags, |bucket| match bucket {
+            Some(Bucket::Sum(sum)) => Bucket::Sum(sum + value),
+            _ => Bucket::Sum(value),
+        })
+    }
+
+    /// Records a histogram value
+    pub fn histogram(&self, name: &str, value: f64, tags: &[&str]) {
+        self.observe(MetricKind::Histogram, name, tags, |bucket| {
+            Self::push_value(bucket, value)
+        })
+    }
+
+    /// Records a distribution value
+    pub fn distribution(&self, name: &str, value: f64, tags: &[&str]) {
+        self.observe(MetricKind::Distribution, name, tags, |bucket| {
+            Self::push_value(bucket, value)
+        })
+    }
+
+    /// Records a set member
+    pub fn set(&self, name: &str, value: &str, tags: &[&str]) {
+        self.observe(MetricKind::Set, name, tags, |bucket| {
+            let mut members = match bucket {
+                Some(Bucket::Unique(members)) => members,
+                _ => HashSet::new(),
+            };
+            members.insert(value.to_string());
+            Bucket::Unique(members)
+        })
+    }
+
+    /// Takes the buffered observations and aggregates them at the given POSIX timestamp in seconds
+    ///
+    /// Returns the series and distribution payloads a flush would submit.
+    pub fn drain(
+        &self,
+        timestamp: i64,
+    ) -> (
+        Vec<routes::metrics::series::MetricSeries>,
+        Vec<routes::metrics::distribution::DistributionSeries>,
+    ) {
+        use routes::metrics::series::MetricIntakeType;
+
+        let buckets = std::mem::take(&mut *self.buckets.lock().unwrap());
+        let interval = self.flush_interval.as_secs().max(1) as i64;
+        let mut series = vec![];
+        let mut distributions = vec![];
+        for (key, bucket) in buckets {
+            let tags = self
+                .tags
+                .iter()
+                .chain(key.tags.iter())
+                .map(String::as_str)
+                .collect::<Vec<_>>();
+            let new_series = |name: &str, type_: MetricIntakeType, value: f64| {
+                let mut s = routes::metrics::series::MetricSeries::new(name, type_)
+                    .with_tags(tags.clone())
+                    .with_point(timestamp, value);
+                if matches!(type_, MetricIntakeType::Count | MetricIntakeType::Rate) {
+                    s = s.with_interval(interval);
+                }
+                match &self.host {
+                    Some(host) => s.with_resource(host, "host"),
+                    None => s,
+                }
+            };
+            match (key.kind, bucket) {
+                (MetricKind::Count, Bucket::Sum(sum)) => {
+                    series.push(new_series(&key.name, MetricIntakeType::Count, sum))
+                }
+                (MetricKind::Rate, Bucket::Sum(sum)) => series.push(new_series(
+                    &key.name,
+                    MetricIntakeType::Rate,
+                    sum / interval as f64,
+                )),
+                (MetricKind::Gauge, Bucket::Last(value)) => {
+                    series.push(new_series(&key.name, MetricIntakeType::Gauge, value))
+                }
+                (MetricKind::Set, Bucket::Unique(members)) => series.push(new_series(
+                    &key.name,
+                    MetricIntakeType::Gauge,
+                    members.len() as f64,
+                )),
+                (MetricKind::Histogram, Bucket::Values(mut values)) => {
+                    values.sort_by(f64::total_cmp);
+                    let count = values.len() as f64;
+                    let gauges = [
+                        ("avg", values.iter().sum::<f64>() / count),
+                        ("median", percentile(&values, 0.5)),
+                        ("max", values[values.len() - 1]),
+                        ("95percentile", percentile(&values, 0.95)),
+                    ];
+                    for (suffix, value) in gauges {
+                        series.push(new_series(
+                            &format!("{}.{}", key.name, suffix),
+                            MetricIntakeType::Gauge,
+                            value,
+                        ));
+                    }
+                    series.push(new_series(
+                        &format!("{}.count", key.name),
+                        MetricIntakeType::Count,
+                        count,
+                    ));
+                }
+                (MetricKind::Distribution, Bucket::Values(values)) => {
+                    let mut d = routes::metrics::distribution::DistributionSeries::new(&key.name)
+                        .with_tags(tags.clone())
+                        .with_point(timestamp, values);
+                    if let Some(host) = &self.host {
+                        d = d.with_host(host);
+                    }
+                    distributions.push(d);
+                }
+                (kind, bucket) => {
+                    tracing::error!(target: "aggregator", "Mismatched {:?} bucket {:?}", kind, bucket)
+                }
+            }
+        }
+        (series, distributions)
+    }
+
+    /// Submits the buffered observations
+    ///
+    /// Both the series and distribution submissions are attempted, the first failure is returned.
+    /// The payloads of a failed submission are dropped, see [dropped](Aggregator::dropped).
+    pub async fn flush(&self) -> crate::Result<()> {
+        let (series, distributions) = self.drain(chrono::Utc::now().timestamp());
+        tracing::info!(target: "aggregator", "Flushing {} series and {} distributions", series.len(), distributions.len());
+        let mut result = Ok(());
+        if !series.is_empty() {
+            let count = series.len();
+            if let Err(e) = routes::metrics::series::Series::new()
+                .with_client(self.client.clone())
+                .series(series)
+                .execute()
+                .await
+            {
+                tracing::error!(target: "aggregator", "Failed to flush series with error {}, dropping {} series", e, count);
+                self.dropped.fetch_add(count, Ordering::Relaxed);
+                result = Err(e);
+            }
+        }
+        if !distributions.is_empty() {
+            let count = distributions.len();
+            if let Err(e) = routes::metrics::distribution::Distribution::new()
+                .with_client(self.client.clone())
+                .series(distributions)
+                .execute()
+                .await
+            {
+                tracing::error!(target: "aggregator", "Failed to flush distributions with error {}, dropping {} distributions", e, count);
+                self.dropped.fetch_add(count, Ordering::Relaxed);
+                result = result.and(Err(e));
+            }
+        }
+        result
+    }
+
+    /// Spawns a tokio task flushing the aggregator every flush interval
+    ///
+    /// Aborting the returned handle stops the task; call [flush](Aggregator::flush) afterwards to submit the remaining observations.
+    pub fn spawn(&self) -> tokio::task::JoinHandle<()> {
+        let aggregator = self.clone();
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(aggregator.flush_interval.max(MIN_FLUSH_INTERVAL));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            // The first tick completes immediately
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                // Errors are logged by flush
+                let _ = aggregator.flush().await;
+            }
+        })
+    }
+
+    /// Updates the bucket for an observation
+    fn observe(
+        &self,
+        kind: MetricKind,
+        name: &str,
+        tags: &[&str],
+        update: impl FnOnce(Option<Bucket>) -> Bucket,
+    ) {
+        let mut tags = tags.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+        tags.sort();
+        let key = MetricKey {
+            kind,
+            name: name.to_string(),
+            tags,
+        };
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = update(buckets.remove(&key));
+        buckets.insert(key, bucket);
+    }
+
+    /// Appends a value to a values bucket
+    fn push_value(bucket: Option<Bucket>, value: f64) -> Bucket {
+        let mut values = match bucket {
+            Some(Bucket::Values(values)) => values,
+            _ => vec![],
+        };
+        values.push(value);
+        Bucket::Values(values)
+    }
+}
+
+/// The nearest-rank percentile of sorted values
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
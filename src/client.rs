@@ -1,73 +1,707 @@
 //! Api Client
 
+use std::{borrow::Cow, str::FromStr, sync::OnceLock};
+
 use crate::{routes, types};
 
 /// Client for Lazy API Usage
 ///
 /// ## Getting Started
 ///
-/// The Client is a wrapper for creating requests to the Datadog API.
+/// The Client is a long-lived wrapper for creating requests to the Datadog API.
 ///
-/// It features a configurable HTTP Client and uses the exposed [Builder](ddog::prelude::Builder) as a middleware for request construction.
+/// It owns the Datadog credentials, the [Site](crate::types::base::Site), a set of default request headers and a pooled [reqwest::Client].
+/// Every route created from the client shares the same connection pool, so the client should be built once and reused (it is cheap to clone).
+/// Route factories return an [Error::UnsupportedVersion](crate::Error::UnsupportedVersion) when the route does not exist in the client's api version.
 ///
-/// Below we demonstrate using the ddog [Client](ddog::prelude::Client) to post metrics to the Datadog API.
+/// Below we demonstrate using the ddog [Client](crate::prelude::Client) to post metrics to the Datadog API.
 ///
 /// ```rust
-/// use ddog::prelude::Client as DdogClient;
+/// use ddog::prelude::*;
 ///
 /// // Execute queries in an async context
 /// async {
 ///     // Instantiate and configure the client
-///     let mut client = DdogClient {
-///         name: String::from("Example Datadog Client"),
-///         host: String::from("imahost.com"),
-///         port: 8196,
-///         logger: 
-///         tracing: true,
-///         env:    
-    /// Datadog agent port, defaults to `8196`.
-    pub port: String,
-    /// Optional Logging Config to also set this tracer as the main logger
-    pub logging_config: Option<LoggingConfig>,
-    /// APM Config to set up APM Analytics (default is to disable)
-    pub apm_config: ApmConfig,
-    /// Turn on tracing
-    pub enable_tracing: bool,
-    /// Number of threads to send the HTTP messages to the Datadog agent
-    pub num_client_send_threads: u32,
-///         Some(vec![
-///             ("DD-API-KEY", "<api_key>"),
-///             ("DD-APPLICATION-KEY", "<application_key>"),
-///         ])
-///     };
-///     client.set_client(reqwest::Client::new());
-///     client.set_
-/// 
-///     // Create a new metric
-///     
-/// 
-/// 
-///    let (status, res) = client.v2()
-///     let (status, res) = builder.v2()
-///         .create_new_tag_config("my.metric.name")
-///         .headers(vec![
-///             ("Accept", "application/json"),
-///             ("Content-Type", "application/json"),
-///             ("DD-API-KEY", "<api_key>"),
-///             ("DD-APPLICATION-KEY", "<application_key>"),
-///         ])
+///     let mut client = Client::new()
+///         .with_api_key("<api_key>")
+///         .with_application_key("<application_key>");
+///
+///     // Create a new tag configuration
+///     let res = client.v2()
+///         .create_new_tag_config("my.metric.name")?
 ///         .execute().await;
 ///
 ///     // This should return a 403 status code now since the above API key is invalid.
-///     println!("Status Code: {:?}", status);
-///     println!("Response: {:?}", res);
+///     match res {
+///         Ok(response) => println!("Response: {:?}", response),
+///         Err(e) => println!("Status Code: {:?}, Error: {}", e.status(), e),
+///     }
+///     Ok::<(), Error>(())
 /// };
 /// ```
-#[derive(Clone, Default, Debug, Eq, PartialEq)]
-pub struct Builder {
+#[derive(Clone, Debug)]
+pub struct Client {
     /// API Version
     pub version: types::version::ApiVersion,
-    /// Request headers
-    pub headers: Vec<(String, String)>,
+    /// The `DD-API-KEY` sent with every request
+    pub api_key: Option<String>,
+    /// The `DD-APPLICATION-KEY` sent with every request
+    pub application_key: Option<String>,
+    /// The Datadog site requests are sent to
+    pub site: types::base::Site,
+    /// Default request headers
+    pub headers: reqwest::header::HeaderMap,
+    /// The pooled http client, shared by clones of this client and the routes created from it
+    pub http: reqwest::Client,
+    /// Compression applied to metric submission bodies, disabled by default
+    pub compression: Option<types::compression::Compression>,
+    /// Retry policy for failed idempotent requests, disabled by default
+    pub retry: Option<types::retry::RetryPolicy>,
+    /// The longest time to wait for a rate limit reset before retrying a rate limited request, disabled by default
+    pub rate_limit_wait: Option<std::time::Duration>,
 }
 
+impl Default for Client {
+    fn default() -> Self {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_static("application/json"),
+        );
+        headers.insert(
+            reqwest::header::CONTENT_TYPE,
+            reqwest::header::HeaderValue::from_static("application/json"),
+        );
+        Self {
+            version: types::version::ApiVersion::default(),
+            api_key: None,
+            application_key: None,
+            site: types::base::Site::default(),
+            headers,
+            http: reqwest::Client::new(),
+            compression: None,
+            retry: None,
+            rate_limit_wait: None,
+        }
+    }
+}
+
+/// Creates a [Client](crate::client::Client) from the keys in an [EnvConfig](crate::types::env::EnvConfig).
+impl From<types::env::EnvConfig> for Client {
+    fn from(config: types::env::EnvConfig) -> Self {
+        Self {
+            api_key: config.api_key,
+            application_key: config.application_key,
+            ..Self::default()
+        }
+    }
+}
+
+impl Client {
+    /// Instantiates a new Client
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The default client of routes created without one
+    ///
+    /// It is built once per process so such routes share a connection pool.
+    /// Pooled connections are driven by the tokio runtime that opened them, so applications running several runtimes should create routes from their own [Client].
+    pub fn shared() -> Self {
+        static SHARED: OnceLock<Client> = OnceLock::new();
+        SHARED.get_or_init(Client::default).clone()
+    }
+
+    /// The client a route is executed with, falling back to the [shared](Client::shared) client
+    pub(crate) fn or_shared(client: &Option<Client>) -> Cow<'_, Client> {
+        match client {
+            Some(client) => Cow::Borrowed(client),
+            None => Cow::Owned(Self::shared()),
+        }
+    }
+
+    /// Sets the api key sent with every request
+    pub fn with_api_key(mut self, key: &str) -> Self {
+        self.api_key = Some(key.to_string());
+        self
+    }
+
+    /// Sets the application key sent with every request
+    pub fn with_application_key(mut self, key: &str) -> Self {
+        self.application_key = Some(key.to_string());
+        self
+    }
+
+    /// Sets the Datadog site requests are sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.site = site;
+        self
+    }
+
+    /// Sets a custom base api url, for example a proxy or a local mock server
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.site = types::base::Site::Custom(base_url.to_string());
+        self
+    }
+
+    /// Adds a default header sent with every request
+    pub fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Replaces the underlying http client
+    ///
+    /// Useful to configure timeouts, proxies or pool sizes on the [reqwest::ClientBuilder].
+    pub fn with_http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Enables compression of metric submission bodies
+    pub fn with_compression(mut self, compression: types::compression::Compression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Enables retries of failed idempotent requests
+    pub fn with_retry(mut self, retry: types::retry::RetryPolicy) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Waits for the rate limit to reset and retries when a request is rate limited (429)
+    ///
+    /// Requests wait for the reset only when the `X-RateLimit-Reset` header is at most `max_wait` away, and at most as many times as the retry policy's attempts.
+    /// Otherwise the retry policy, if any, decides whether and when to retry.
+    pub fn with_rate_limit_wait(mut self, max_wait: std::time::Duration) -> Self {
+        self.rate_limit_wait = Some(max_wait);
+        self
+    }
+
+    /// Sets the api version to v1
+    pub fn v1(&mut self) -> &mut Self {
+        self.version = types::version::ApiVersion::V1;
+        self
+    }
+
+    /// Sets the api version to v2
+    pub fn v2(&mut self) -> &mut Self {
+        self.version = types::version::ApiVersion::V2;
+        self
+    }
+
+    /// Builds the request headers from the default headers and credentials
+    pub fn request_headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = self.headers.clone();
+        if let Some(key) = &self.api_key {
+            headers.insert(
+                reqwest::header::HeaderName::from_static("dd-api-key"),
+                reqwest::header::HeaderValue::from_str(key).unwrap(),
+            );
+        }
+        if let Some(key) = &self.application_key {
+            headers.insert(
+                reqwest::header::HeaderName::from_static("dd-application-key"),
+                reqwest::header::HeaderValue::from_str(key).unwrap(),
+            );
+        }
+        headers
+    }
+
+    /// Creates a request for the given route path with the client's headers and credentials
+    pub fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        self.request_url(method, &format!("{}/{}", self.site.api_url(), path))
+    }
+
+    /// Creates a request for an absolute url with the client's headers and credentials
+    ///
+    /// Used by routes hosted outside the api domain, such as the logs intake.
+    pub fn request_url(&self, method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
+        tracing::info!(target: "client", "Sending {} Request to {}", method, url);
+        self.http
+            .request(method, url)
+            .headers(self.request_headers())
+    }
+
+    /// Attaches a body to the request, compressing it when compression is enabled
+    ///
+    /// Streaming bodies are always sent as is.
+    pub fn compressed_body(
+        &self,
+        request: reqwest::RequestBuilder,
+        body: reqwest::Body,
+    ) -> crate::Result<reqwest::RequestBuilder> {
+        let (compression, payload) = match (&self.compression, body.as_bytes()) {
+            (Some(compression), Some(payload)) => (compression, payload.to_vec()),
+            _ => return Ok(request.body(body)),
+        };
+        let size = payload.len();
+        match compression.compress(payload) {
+            Ok((payload, Some(encoding))) => {
+                tracing::info!(target: "client", "Compressed body from {} to {} bytes with {}", size, payload.len(), encoding);
+                Ok(request
+                    .header(reqwest::header::CONTENT_ENCODING, encoding.to_string())
+                    .body(payload))
+            }
+            Ok((payload, None)) => Ok(request.body(payload)),
+            Err(e) => Err(crate::Error::Validation(format!(
+                "failed to compress body: {}",
+                e
+            ))),
+        }
+    }
+
+    /// Sends a request and decodes the json response
+    ///
+    /// Any non-success status code is returned as an [Error::Status](crate::Error::Status) with the decoded Datadog `errors` array.
+    ///
+    /// When a [RetryPolicy](types::retry::RetryPolicy) is set, idempotent requests (`GET`, `HEAD`, `PUT`, `DELETE`) are retried.
+    pub async fn send<T>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> crate::Result<types::response::Response<T>>
+    where
+        T: serde::de::DeserializeOwned + std::fmt::Debug,
+    {
+        self.send_with_retry(request, false).await
+    }
+
+    /// Sends a request that is safe to retry regardless of its method, such as a metric intake submission
+    pub async fn send_idempotent<T>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> crate::Result<types::response::Response<T>>
+    where
+        T: serde::de::DeserializeOwned + std::fmt::Debug,
+    {
+        self.send_with_retry(request, true).await
+    }
+
+    /// Sends a request, retrying it according to the retry policy
+    async fn send_with_retry<T>(
+        &self,
+        request: reqwest::RequestBuilder,
+        idempotent: bool,
+    ) -> crate::Result<types::response::Response<T>>
+    where
+        T: serde::de::DeserializeOwned + std::fmt::Debug,
+    {
+        let request = request.build()?;
+        let policy = self.retry.as_ref().filter(|_| {
+            idempotent
+                || matches!(
+                    *request.method(),
+                    reqwest::Method::GET
+                        | reqwest::Method::HEAD
+                        | reqwest::Method::PUT
+                        | reqwest::Method::DELETE
+                )
+        });
+
+        let mut attempt = 1;
+        loop {
+            // Streaming bodies cannot be replayed
+            let attempt_request = match request.try_clone() {
+                Some(attempt_request) => attempt_request,
+                None => return self.send_once(request).await,
+            };
+            let error = match self.send_once(attempt_request).await {
+                Ok(response) => return Ok(response),
+                Err(e) => e,
+            };
+            let delay = match self.retry_delay(policy, attempt, &error) {
+                Some(delay) => delay,
+                None => return Err(error),
+            };
+            tracing::warn!(target: "client", "Attempt {} failed with {}, retrying in {:?}", attempt, error, delay);
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// The delay before retrying a failed attempt, or `None` if it should not be retried
+    ///
+    /// Rate limited requests were not processed by Datadog, so they are retried regardless of the request method when waiting on rate limits is enabled.
+    fn retry_delay(
+        &self,
+        policy: Option<&types::retry::RetryPolicy>,
+        attempt: u32,
+        error: &crate::Error,
+    ) -> Option<std::time::Duration> {
+        let max_attempts = self
+            .retry
+            .as_ref()
+            .map(|r| r.max_attempts)
+            .unwrap_or_else(|| types::retry::RetryPolicy::default().max_attempts);
+        if let (
+            Some(max_wait),
+            crate::Error::Status {
+                status: reqwest::StatusCode::TOO_MANY_REQUESTS,
+                rate_limit: Some(rate_limit),
+                ..
+            },
+        ) = (self.rate_limit_wait, error)
+        {
+            // A reset beyond max_wait falls back to the retry policy's backoff
+            if let Some(reset) = rate_limit
+                .reset_after()
+                .filter(|reset| *reset <= max_wait && attempt < max_attempts)
+            {
+                return Some(reset);
+            }
+        }
+        let policy = policy.filter(|p| attempt < p.max_attempts)?;
+        let retryable = match error {
+            crate::Error::Transport(e) => policy.is_retryable_error(e),
+            crate::Error::Status { status, .. } => policy.is_retryable_status(*status),
+            _ => false,
+        };
+        retryable.then(|| policy.backoff(attempt))
+    }
+
+    /// Sends a request once and decodes the json response
+    async fn send_once<T>(
+        &self,
+        request: reqwest::Request,
+    ) -> crate::Result<types::response::Response<T>>
+    where
+        T: serde::de::DeserializeOwned + std::fmt::Debug,
+    {
+        let response = self.http.execute(request).await.map_err(|e| {
+            tracing::error!(target: "client", "Request failed with error {:?}", e);
+            crate::Error::Transport(e)
+        })?;
+        let status = response.status();
+        let rate_limit = types::rate_limit::RateLimit::from_headers(response.headers());
+        let body = response.bytes().await?;
+
+        if !status.is_success() {
+            let error = crate::Error::from_response(status, &body).with_rate_limit(rate_limit);
+            tracing::error!(target: "client", "Received non-ok status code {:?}: {}", status, error);
+            return Err(error);
+        }
+
+        // Some endpoints acknowledge requests with an empty body
+        let body: &[u8] = match body.is_empty() {
+            true => b"{}",
+            false => &body,
+        };
+        match serde_json::from_slice::<T>(body) {
+            Ok(data) => {
+                tracing::info!(target: "client", "Deserialized Response into json: {:?}", data);
+                Ok(types::response::Response {
+                    status,
+                    rate_limit,
+                    data,
+                })
+            }
+            Err(source) => {
+                tracing::error!(target: "client", "Failed to parse response with error {:?}", source);
+                Err(crate::Error::Deserialize { status, source })
+            }
+        }
+    }
+
+    /// Create a new Tag Configuration
+    pub fn create_new_tag_config(
+        &self,
+        metric_name: &str,
+    ) -> crate::Result<routes::metrics::tags::Tags> {
+        let tags = routes::metrics::tags::Tags::try_from(self.version)?;
+        Ok(tags
+            .with_client(self.clone())
+            .set_metric_name(metric_name.to_string()))
+    }
+
+    /// Posts series data to the metrics endpoint
+    pub fn post_series(&self) -> crate::Result<routes::metrics::series::Series> {
+        let series = routes::metrics::series::Series::try_from(self.version)?;
+        Ok(series.with_client(self.clone()))
+    }
+
+    /// Posts distribution points to the metrics endpoint
+    pub fn post_distribution(&self) -> crate::Result<routes::metrics::distribution::Distribution> {
+        let distribution = routes::metrics::distribution::Distribution::try_from(self.version)?;
+        Ok(distribution.with_client(self.clone()))
+    }
+
+    /// Gets a list of metrics actively reporting since the given time
+    pub fn get_metrics(
+        &self,
+        from: chrono::DateTime<chrono::Utc>,
+    ) -> crate::Result<routes::metrics::get_metrics::GetMetrics> {
+        let metrics = routes::metrics::get_metrics::GetMetrics::try_from(self.version)?;
+        Ok(metrics.with_client(self.clone()).set_from(from))
+    }
+
+    /// Posts an event to the event stream
+    pub fn post_event(&self) -> crate::Result<routes::events::post_event::PostEvent> {
+        let event = routes::events::post_event::PostEvent::try_from(self.version)?;
+        Ok(event.with_client(self.clone()))
+    }
+
+    /// Gets the details of an event
+    pub fn get_event(&self, event_id: i64) -> crate::Result<routes::events::get_event::GetEvent> {
+        let event = routes::events::get_event::GetEvent::try_from(self.version)?;
+        Ok(event.with_client(self.clone()).set_event_id(event_id))
+    }
+
+    /// Queries the event stream between two POSIX timestamps
+    pub fn list_events(
+        &self,
+        start: i64,
+        end: i64,
+    ) -> crate::Result<routes::events::list_events::ListEvents> {
+        let events = routes::events::list_events::ListEvents::try_from(self.version)?;
+        Ok(events.with_client(self.clone()).set_time_range(start, end))
+    }
+
+    /// Sends log entries to the logs intake
+    pub fn post_logs(&self) -> crate::Result<routes::logs::send_logs::SendLogs> {
+        let logs = routes::logs::send_logs::SendLogs::try_from(self.version)?;
+        Ok(logs.with_client(self.clone()))
+    }
+
+    /// Searches logs matching a query
+    pub fn search_logs(&self) -> crate::Result<routes::logs::search_logs::SearchLogs> {
+        let logs = routes::logs::search_logs::SearchLogs::try_from(self.version)?;
+        Ok(logs.with_client(self.clone()))
+    }
+
+    /// Queries timeseries points between two timestamps, in seconds since the Unix epoch
+    pub fn query_metrics(
+        &self,
+        from: i64,
+        to: i64,
+        query: &str,
+    ) -> crate::Result<routes::metrics::query_metrics::QueryMetrics> {
+        let metrics = routes::metrics::query_metrics::QueryMetrics::try_from(self.version)?;
+        Ok(metrics
+            .with_client(self.clone())
+            .set_time_range(from, to)
+            .set_query(query))
+    }
+
+    /// Queries timeseries data, applying formulas to named queries
+    pub fn query_timeseries(
+        &self,
+    ) -> crate::Result<routes::metrics::query_timeseries::QueryTimeseries> {
+        let query = routes::metrics::query_timeseries::QueryTimeseries::try_from(self.version)?;
+        Ok(query.with_client(self.clone()))
+    }
+
+    /// Queries scalar values, applying formulas to named queries
+    pub fn query_scalar(&self) -> crate::Result<routes::metrics::query_scalar::QueryScalar> {
+        let query = routes::metrics::query_scalar::QueryScalar::try_from(self.version)?;
+        Ok(query.with_client(self.clone()))
+    }
+
+    /// Gets the Tag Configuration of a metric
+    pub fn get_tag_config(
+        &self,
+        metric_name: &str,
+    ) -> crate::Result<routes::metrics::get_tags::GetTags> {
+        let tags = routes::metrics::get_tags::GetTags::try_from(self.version)?;
+        Ok(tags
+            .with_client(self.clone())
+            .set_metric_name(metric_name.to_string()))
+    }
+
+    /// Updates the Tag Configuration of a metric
+    pub fn update_tag_config(
+        &self,
+        metric_name: &str,
+    ) -> crate::Result<routes::metrics::update_tags::UpdateTags> {
+        let tags = routes::metrics::update_tags::UpdateTags::try_from(self.version)?;
+        Ok(tags
+            .with_client(self.clone())
+            .set_metric_name(metric_name.to_string()))
+    }
+
+    /// Deletes the Tag Configuration of a metric
+    pub fn delete_tag_config(
+        &self,
+        metric_name: &str,
+    ) -> crate::Result<routes::metrics::delete_tags::DeleteTags> {
+        let tags = routes::metrics::delete_tags::DeleteTags::try_from(self.version)?;
+        Ok(tags
+            .with_client(self.clone())
+            .set_metric_name(metric_name.to_string()))
+    }
+
+    /// Lists the Tag Configurations of all metrics
+    pub fn list_tag_configs(
+        &self,
+    ) -> crate::Result<routes::metrics::list_tag_configurations::ListTagConfigurations> {
+        let tags = routes::metrics::list_tag_configurations::ListTagConfigurations::try_from(
+            self.version,
+        )?;
+        Ok(tags.with_client(self.clone()))
+    }
+
+    /// Gets the metadata of a metric
+    pub fn get_metric_metadata(
+        &self,
+        metric_name: &str,
+    ) -> crate::Result<routes::metrics::get_metadata::GetMetadata> {
+        let metadata = routes::metrics::get_metadata::GetMetadata::try_from(self.version)?;
+        Ok(metadata
+            .with_client(self.clone())
+            .set_metric_name(metric_name.to_string()))
+    }
+
+    /// Edits the metadata of a metric
+    pub fn update_metric_metadata(
+        &self,
+        metric_name: &str,
+    ) -> crate::Result<routes::metrics::update_metadata::UpdateMetadata> {
+        let metadata = routes::metrics::update_metadata::UpdateMetadata::try_from(self.version)?;
+        Ok(metadata
+            .with_client(self.clone())
+            .set_metric_name(metric_name.to_string()))
+    }
+
+    /// Gets the distinct, ingested and indexed volumes of a metric
+    pub fn get_metric_volumes(
+        &self,
+        metric_name: &str,
+    ) -> crate::Result<routes::metrics::volumes::Volumes> {
+        let volumes = routes::metrics::volumes::Volumes::try_from(self.version)?;
+        Ok(volumes
+            .with_client(self.clone())
+            .set_metric_name(metric_name.to_string()))
+    }
+
+    /// Gets the indexed and ingested tags of a metric
+    pub fn get_metric_all_tags(
+        &self,
+        metric_name: &str,
+    ) -> crate::Result<routes::metrics::all_tags::AllTags> {
+        let all_tags = routes::metrics::all_tags::AllTags::try_from(self.version)?;
+        Ok(all_tags
+            .with_client(self.clone())
+            .set_metric_name(metric_name.to_string()))
+    }
+
+    /// Gets the actively queried tags and aggregations of a metric
+    pub fn get_active_configurations(
+        &self,
+        metric_name: &str,
+    ) -> crate::Result<routes::metrics::active_configurations::ActiveConfigurations> {
+        let active_configurations =
+            routes::metrics::active_configurations::ActiveConfigurations::try_from(self.version)?;
+        Ok(active_configurations
+            .with_client(self.clone())
+            .set_metric_name(metric_name.to_string()))
+    }
+
+    /// Estimates the cardinality of a metric for a tag configuration
+    pub fn estimate_tag_config(
+        &self,
+        metric_name: &str,
+    ) -> crate::Result<routes::metrics::estimate::Estimate> {
+        let estimate = routes::metrics::estimate::Estimate::try_from(self.version)?;
+        Ok(estimate
+            .with_client(self.clone())
+            .set_metric_name(metric_name.to_string()))
+    }
+
+    /// Configures the tags of all metrics matching a prefix
+    pub fn bulk_tag_config(
+        &self,
+        prefix: &str,
+    ) -> crate::Result<routes::metrics::bulk_tags::BulkTags> {
+        let tags = routes::metrics::bulk_tags::BulkTags::try_from(self.version)?;
+        Ok(tags
+            .with_client(self.clone())
+            .set_prefix(prefix.to_string()))
+    }
+
+    /// Deletes the tag configurations of all metrics matching a prefix
+    pub fn delete_bulk_tag_config(
+        &self,
+        prefix: &str,
+    ) -> crate::Result<routes::metrics::delete_bulk_tags::DeleteBulkTags> {
+        let tags = routes::metrics::delete_bulk_tags::DeleteBulkTags::try_from(self.version)?;
+        Ok(tags
+            .with_client(self.clone())
+            .set_prefix(prefix.to_string()))
+    }
+
+    /// Creates a monitor
+    pub fn create_monitor(&self) -> crate::Result<routes::monitors::create_monitor::CreateMonitor> {
+        let monitor = routes::monitors::create_monitor::CreateMonitor::try_from(self.version)?;
+        Ok(monitor.with_client(self.clone()))
+    }
+
+    /// Gets the details of a monitor
+    pub fn get_monitor(
+        &self,
+        monitor_id: i64,
+    ) -> crate::Result<routes::monitors::get_monitor::GetMonitor> {
+        let monitor = routes::monitors::get_monitor::GetMonitor::try_from(self.version)?;
+        Ok(monitor.with_client(self.clone()).set_monitor_id(monitor_id))
+    }
+
+    /// Edits a monitor
+    pub fn update_monitor(
+        &self,
+        monitor_id: i64,
+    ) -> crate::Result<routes::monitors::update_monitor::UpdateMonitor> {
+        let monitor = routes::monitors::update_monitor::UpdateMonitor::try_from(self.version)?;
+        Ok(monitor.with_client(self.clone()).set_monitor_id(monitor_id))
+    }
+
+    /// Deletes a monitor
+    pub fn delete_monitor(
+        &self,
+        monitor_id: i64,
+    ) -> crate::Result<routes::monitors::delete_monitor::DeleteMonitor> {
+        let monitor = routes::monitors::delete_monitor::DeleteMonitor::try_from(self.version)?;
+        Ok(monitor.with_client(self.clone()).set_monitor_id(monitor_id))
+    }
+
+    /// Lists monitors
+    pub fn list_monitors(&self) -> crate::Result<routes::monitors::list_monitors::ListMonitors> {
+        let monitor = routes::monitors::list_monitors::ListMonitors::try_from(self.version)?;
+        Ok(monitor.with_client(self.clone()))
+    }
+
+    /// Validates a monitor definition without creating it
+    pub fn validate_monitor(
+        &self,
+    ) -> crate::Result<routes::monitors::validate_monitor::ValidateMonitor> {
+        let monitor = routes::monitors::validate_monitor::ValidateMonitor::try_from(self.version)?;
+        Ok(monitor.with_client(self.clone()))
+    }
+
+    /// Searches monitors
+    pub fn search_monitors(
+        &self,
+    ) -> crate::Result<routes::monitors::search_monitors::SearchMonitors> {
+        let monitor = routes::monitors::search_monitors::SearchMonitors::try_from(self.version)?;
+        Ok(monitor.with_client(self.clone()))
+    }
+
+    /// Mutes a monitor
+    pub fn mute_monitor(
+        &self,
+        monitor_id: i64,
+    ) -> crate::Result<routes::monitors::mute_monitor::MuteMonitor> {
+        let monitor = routes::monitors::mute_monitor::MuteMonitor::try_from(self.version)?;
+        Ok(monitor.with_client(self.clone()).set_monitor_id(monitor_id))
+    }
+
+    /// Unmutes a monitor
+    pub fn unmute_monitor(
+        &self,
+        monitor_id: i64,
+    ) -> crate::Result<routes::monitors::unmute_monitor::UnmuteMonitor> {
+        let monitor = routes::monitors::unmute_monitor::UnmuteMonitor::try_from(self.version)?;
+        Ok(monitor.with_client(self.clone()).set_monitor_id(monitor_id))
+    }
+}
@@ -0,0 +1,178 @@
+//! DogStatsD Events
+
+use std::fmt;
+
+use super::metric::{sanitize, write_tags};
+
+/// The priority of an event
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Priority {
+    /// `normal`
+    #[default]
+    Normal,
+    /// `low`
+    Low,
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Priority::Normal => write!(f, "normal"),
+            Priority::Low => write!(f, "low"),
+        }
+    }
+}
+
+/// The alert type of an event
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum AlertType {
+    /// `info`
+    #[default]
+    Info,
+    /// `warning`
+    Warning,
+    /// `error`
+    Error,
+    /// `success`
+    Success,
+}
+
+impl fmt::Display for AlertType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlertType::Info => write!(f, "info"),
+            AlertType::Warning => write!(f, "warning"),
+            AlertType::Error => write!(f, "error"),
+            AlertType::Success => write!(f, "success"),
+        }
+    }
+}
+
+/// A DogStatsD Event
+///
+/// Renders to the `_e{title.length,text.length}:title|text|d:timestamp|h:hostname|p:priority|t:alert_type|#tags` datagram format, see the [Datadog Documentation](https://docs.datadoghq.com/developers/dogstatsd/datagram_shell/?tab=events).
+/// Newlines in the title and text are escaped as `\n`.
+///
+/// ```rust
+/// use ddog::dogstatsd::event::*;
+///
+/// let event = Event::new("Deploy", "Deployed v1.2.3\nto production")
+///     .with_alert_type(AlertType::Success)
+///     .with_tags(vec!["service:api"]);
+/// assert_eq!(
+///     event.to_string(),
+///     "_e{6,30}:Deploy|Deployed v1.2.3\\nto production|t:success|#service:api"
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Event {
+    /// The event title
+    pub title: String,
+    /// The event text
+    pub text: String,
+    /// POSIX timestamp of the event in seconds, defaults to the time the agent receives it
+    pub timestamp: Option<i64>,
+    /// The host of the event
+    pub hostname: Option<String>,
+    /// A key grouping events in the event stream
+    pub aggregation_key: Option<String>,
+    /// The event priority
+    pub priority: Option<Priority>,
+    /// The event source
+    pub source_type_name: Option<String>,
+    /// The event alert type
+    pub alert_type: Option<AlertType>,
+    /// The event tags
+    pub tags: Vec<String>,
+}
+
+impl Event {
+    /// Instantiates a new event
+    pub fn new(title: &str, text: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            text: text.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the POSIX timestamp of the event in seconds
+    pub fn with_timestamp(mut self, timestamp: i64) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Sets the host of the event
+    pub fn with_hostname(mut self, hostname: &str) -> Self {
+        self.hostname = Some(hostname.to_string());
+        self
+    }
+
+    /// Sets the key grouping events in the event stream
+    pub fn with_aggregation_key(mut self, aggregation_key: &str) -> Self {
+        self.aggregation_key = Some(aggregation_key.to_string());
+        self
+    }
+
+    /// Sets the event priority
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Sets the event source
+    pub fn with_source_type_name(mut self, source_type_name: &str) -> Self {
+        self.source_type_name = Some(source_type_name.to_string());
+        self
+    }
+
+    /// Sets the event alert type
+    pub fn with_alert_type(mut self, alert_type: AlertType) -> Self {
+        self.alert_type = Some(alert_type);
+        self
+    }
+
+    /// Adds a tag
+    pub fn with_tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    /// Adds tags
+    pub fn with_tags(mut self, tags: Vec<&str>) -> Self {
+        self.tags.extend(tags.into_iter().map(String::from));
+        self
+    }
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let title = escape_newlines(&self.title);
+        let text = escape_newlines(&self.text);
+        write!(f, "_e{{{},{}}}:{}|{}", title.len(), text.len(), title, text)?;
+        if let Some(timestamp) = self.timestamp {
+            write!(f, "|d:{}", timestamp)?;
+        }
+        if let Some(hostname) = &self.hostname {
+            write!(f, "|h:{}", sanitize(hostname, &['|']))?;
+        }
+        if let Some(aggregation_key) = &self.aggregation_key {
+            write!(f, "|k:{}", sanitize(aggregation_key, &['|']))?;
+        }
+        if let Some(priority) = self.priority {
+            write!(f, "|p:{}", priority)?;
+        }
+        if let Some(source_type_name) = &self.source_type_name {
+            write!(f, "|s:{}", sanitize(source_type_name, &['|']))?;
+        }
+        if let Some(alert_type) = self.alert_type {
+            write!(f, "|t:{}", alert_type)?;
+        }
+        write_tags(f, &self.tags)
+    }
+}
+
+/// Escapes newlines as `\n`
+pub(crate) fn escape_newlines(field: &str) -> String {
+    field.replace("\r\n", "\\n").replace('\n', "\\n")
+}
@@ -0,0 +1,157 @@
+//! DogStatsD Metrics
+
+use std::{fmt, time::Duration};
+
+/// A DogStatsD Metric Type
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum MetricType {
+    /// `c` - the value is added to the count
+    Count,
+    /// `g` - the value replaces the gauge
+    Gauge,
+    /// `h` - the value is aggregated by the agent into a histogram
+    Histogram,
+    /// `d` - the value is sent to Datadog as a distribution point
+    Distribution,
+    /// `s` - the value is counted once per unique value
+    Set,
+    /// `ms` - the value is a duration in milliseconds, aggregated like a histogram
+    Timer,
+}
+
+impl fmt::Display for MetricType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MetricType::Count => write!(f, "c"),
+            MetricType::Gauge => write!(f, "g"),
+            MetricType::Histogram => write!(f, "h"),
+            MetricType::Distribution => write!(f, "d"),
+            MetricType::Set => write!(f, "s"),
+            MetricType::Timer => write!(f, "ms"),
+        }
+    }
+}
+
+/// A DogStatsD Metric
+///
+/// Renders to the `metric.name:value|type|@sample_rate|#tag1:value,tag2` datagram format, see the [Datadog Documentation](https://docs.datadoghq.com/developers/dogstatsd/datagram_shell/?tab=metrics).
+///
+/// ```rust
+/// use ddog::dogstatsd::metric::*;
+///
+/// let metric = Metric::count("page.views", 1.0)
+///     .with_sample_rate(0.5)
+///     .with_tags(vec!["env:prod", "route:/"]);
+/// assert_eq!(metric.to_string(), "page.views:1|c|@0.5|#env:prod,route:/");
+///
+/// let metric = Metric::timer("request.duration", std::time::Duration::from_micros(1500));
+/// assert_eq!(metric.to_string(), "request.duration:1.5|ms");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Metric {
+    /// The metric name
+    pub name: String,
+    /// The formatted metric value
+    pub value: String,
+    /// The metric type
+    pub type_: MetricType,
+    /// The rate in `(0, 1]` the metric is sampled at
+    pub sample_rate: Option<f64>,
+    /// The metric tags
+    pub tags: Vec<String>,
+}
+
+impl Metric {
+    /// Instantiates a new metric
+    pub fn new(name: &str, value: impl fmt::Display, type_: MetricType) -> Self {
+        Self {
+            name: name.to_string(),
+            value: value.to_string(),
+            type_,
+            sample_rate: None,
+            tags: vec![],
+        }
+    }
+
+    /// Instantiates a count
+    pub fn count(name: &str, value: f64) -> Self {
+        Self::new(name, value, MetricType::Count)
+    }
+
+    /// Instantiates a gauge
+    pub fn gauge(name: &str, value: f64) -> Self {
+        Self::new(name, value, MetricType::Gauge)
+    }
+
+    /// Instantiates a histogram
+    pub fn histogram(name: &str, value: f64) -> Self {
+        Self::new(name, value, MetricType::Histogram)
+    }
+
+    /// Instantiates a distribution
+    pub fn distribution(name: &str, value: f64) -> Self {
+        Self::new(name, value, MetricType::Distribution)
+    }
+
+    /// Instantiates a set
+    pub fn set(name: &str, value: &str) -> Self {
+        Self::new(name, value, MetricType::Set)
+    }
+
+    /// Instantiates a timer in milliseconds
+    pub fn timer(name: &str, duration: Duration) -> Self {
+        Self::new(name, duration.as_secs_f64() * 1000.0, MetricType::Timer)
+    }
+
+    /// Sets the rate the metric is sampled at, clamped to `(0, 1]`
+    pub fn with_sample_rate(mut self, sample_rate: f64) -> Self {
+        self.sample_rate = Some(sample_rate.clamp(f64::MIN_POSITIVE, 1.0));
+        self
+    }
+
+    /// Adds a tag
+    pub fn with_tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    /// Adds tags
+    pub fn with_tags(mut self, tags: Vec<&str>) -> Self {
+        self.tags.extend(tags.into_iter().map(String::from));
+        self
+    }
+}
+
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}|{}",
+            sanitize(&self.name, &[':', '|', '@']),
+            sanitize(&self.value, &['|']),
+            self.type_
+        )?;
+        if let Some(sample_rate) = self.sample_rate.filter(|r| *r < 1.0) {
+            write!(f, "|@{}", sample_rate)?;
+        }
+        write_tags(f, &self.tags)
+    }
+}
+
+/// Writes the `|#tag1,tag2` tags field, if there are tags
+pub(crate) fn write_tags(f: &mut fmt::Formatter, tags: &[String]) -> fmt::Result {
+    for (i, tag) in tags.iter().enumerate() {
+        let separator = if i == 0 { "|#" } else { "," };
+        write!(f, "{}{}", separator, sanitize(tag, &['|', ',']))?;
+    }
+    Ok(())
+}
+
+/// Replaces characters reserved by the datagram format, and newlines, with underscores
+pub(crate) fn sanitize<'a>(field: &'a str, reserved: &[char]) -> std::borrow::Cow<'a, str> {
+    let is_reserved = |c: char| c == '\n' || c == '\r' || reserved.contains(&c);
+    match field.contains(is_reserved) {
+        true => field.replace(is_reserved, "_").into(),
+        false => field.into(),
+    }
+}
@@ -0,0 +1,373 @@
+//! DogStatsD Client
+//!
+//! ## Overview
+//!
+//! [DogStatsD](https://docs.datadoghq.com/developers/dogstatsd/) is the metrics aggregation service bundled with the Datadog Agent.
+//! Metrics, events and service checks are sent to a local agent as plain-text datagrams, so no api key is needed in-process and sending never waits on the Datadog API.
+//!
+//! The agent is reached over UDP (`host:port`) or, as in most Kubernetes deployments, over a Unix domain socket (`unix:///var/run/datadog/dsd.socket`).
+//!
+//! ```rust
+//! use ddog::dogstatsd::*;
+//!
+//! fn instrument() -> ddog::Result<()> {
+//!     let statsd = DogStatsd::new("127.0.0.1:8125")?.with_tags(vec!["service:my-service"]);
+//!     statsd.incr("page.views", &["route:/"])?;
+//!     statsd.gauge("queue.depth", 12.0, &[])?;
+//!     statsd.send(&metric::Metric::histogram("request.size", 512.0).with_sample_rate(0.1))?;
+//!     statsd.send_event(&event::Event::new("Deploy", "Deployed v1.2.3"))?;
+//!     statsd.send_service_check(&service_check::ServiceCheck::new(
+//!         "app.is_up",
+//!         service_check::ServiceCheckStatus::Ok,
+//!     ))
+//! }
+//!
+//! fn instrument_uds() -> ddog::Result<()> {
+//!     let statsd = DogStatsd::new("unix:///var/run/datadog/dsd.socket")?
+//!         .with_origin_detection()
+//!         .with_buffering();
+//!     for i in 0..100 {
+//!         statsd.histogram("batch.item.size", i as f64, &[])?;
+//!     }
+//!     // Buffered metrics are also flushed when the last clone of the client is dropped
+//!     statsd.flush()
+//! }
+//! ```
+
+use std::{
+    collections::hash_map::RandomState,
+    fmt,
+    hash::{BuildHasher, Hasher},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// DogStatsD Metrics
+pub mod metric;
+
+/// DogStatsD Events
+pub mod event;
+
+/// DogStatsD Service Checks
+pub mod service_check;
+
+/// DogStatsD Transports
+mod transport;
+
+/// The default agent host
+pub const DEFAULT_AGENT_HOST: &str = "localhost";
+
+/// The default DogStatsD port
+pub const DEFAULT_DOGSTATSD_PORT: u16 = 8125;
+
+/// The default DogStatsD Unix domain socket path
+pub const DEFAULT_DOGSTATSD_SOCKET: &str = "/var/run/datadog/dsd.socket";
+
+/// The default maximum payload size over UDP, fitting a single ethernet frame
+pub const DEFAULT_UDP_MAX_PAYLOAD_SIZE: usize = 1432;
+
+/// The default maximum payload size over Unix domain sockets
+pub const DEFAULT_UDS_MAX_PAYLOAD_SIZE: usize = 8192;
+
+/// A DogStatsD Client sending metrics to an agent over UDP or Unix domain sockets
+///
+/// The agent address is either `host:port`, `udp://host:port`, `unix:///path` or `unixgram:///path` for a datagram socket, or `unixstream:///path` for a stream socket.
+///
+/// Cloning the client is cheap, clones share the same socket and buffer.
+#[derive(Clone, Debug)]
+pub struct DogStatsd {
+    /// Tags added to every metric
+    pub tags: Vec<String>,
+    /// The container id sent in the `|c:` field for origin detection
+    pub container_id: Option<String>,
+    /// The maximum payload size in bytes, buffered metrics are sent once the next one would not fit
+    pub max_payload_size: usize,
+    buffered: bool,
+    sink: Arc<Sink>,
+}
+
+/// A transport and its pending payload
+#[derive(Debug)]
+struct Sink {
+    transport: transport::Transport,
+    buffer: Mutex<Vec<u8>>,
+}
+
+impl Sink {
+    /// Sends and clears the pending payload
+    fn flush(&self, buffer: &mut Vec<u8>) -> crate::Result<()> {
+        if buffer.is_empty() {
+            return Ok(());
+        }
+        let result = self.transport.send(buffer);
+        buffer.clear();
+        result
+    }
+}
+
+impl Drop for Sink {
+    fn drop(&mut self) {
+        let mut buffer = std::mem::take(&mut *self.buffer.lock().unwrap());
+        if let Err(e) = self.flush(&mut buffer) {
+            tracing::error!(target: "dogstatsd", "Failed to flush buffered metrics with error {}", e);
+        }
+    }
+}
+
+impl DogStatsd {
+    /// Instantiates a client sending to the agent at the given address
+    pub fn new(addr: &str) -> crate::Result<Self> {
+        let transport = transport::Transport::connect(addr)?;
+        tracing::info!(target: "dogstatsd", "DogStatsD Client Created for {}", addr);
+        Ok(Self {
+            tags: vec![],
+            container_id: None,
+            max_payload_size: transport.default_max_payload_size(),
+            buffered: false,
+            sink: Arc::new(Sink {
+                transport,
+                buffer: Mutex::new(vec![]),
+            }),
+        })
+    }
+
+    /// Instantiates a client from the environment
+    ///
+    /// The agent address is read from `DD_DOGSTATSD_URL`, then `DD_DOGSTATSD_SOCKET`, then `DD_AGENT_HOST` and `DD_DOGSTATSD_PORT`, defaulting to [DEFAULT_AGENT_HOST] and [DEFAULT_DOGSTATSD_PORT].
+    /// Origin detection is enabled unless `DD_ORIGIN_DETECTION_ENABLED` is `false`.
+    pub fn from_env() -> crate::Result<Self> {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+        let addr = match (var("DD_DOGSTATSD_URL"), var("DD_DOGSTATSD_SOCKET")) {
+            (Some(url), _) => url,
+            (None, Some(socket)) => format!("unix://{}", socket),
+            (None, None) => {
+                let host = var("DD_AGENT_HOST").unwrap_or_else(|| DEFAULT_AGENT_HOST.to_string());
+                let port = var("DD_DOGSTATSD_PORT")
+                    .and_then(|p| p.parse::<u16>().ok())
+                    .unwrap_or(DEFAULT_DOGSTATSD_PORT);
+                format!("{}:{}", host, port)
+            }
+        };
+        let statsd = Self::new(&addr)?;
+        match var("DD_ORIGIN_DETECTION_ENABLED").as_deref() {
+            Some("false") => Ok(statsd),
+            _ => Ok(statsd.with_origin_detection()),
+        }
+    }
+
+    /// Adds tags to every metric
+    pub fn with_tags(mut self, tags: Vec<&str>) -> Self {
+        self.tags.extend(tags.into_iter().map(String::from));
+        self
+    }
+
+    /// Sets the container id sent with every metric
+    pub fn with_container_id(mut self, container_id: &str) -> Self {
+        self.container_id = Some(container_id.to_string());
+        self
+    }
+
+    /// Sets the container id from `/proc/self/cgroup`, if the process runs in a container
+    pub fn with_origin_detection(mut self) -> Self {
+        if let Ok(cgroup) = std::fs::read_to_string("/proc/self/cgroup") {
+            if let Some(container_id) = parse_container_id(&cgroup) {
+                self.container_id = Some(container_id);
+            }
+        }
+        self
+    }
+
+    /// Sets the maximum payload size in bytes
+    ///
+    /// Larger metrics, events and service checks are rejected with an [Error::Validation](crate::Error::Validation) instead of being sent.
+    pub fn with_max_payload_size(mut self, max_payload_size: usize) -> Self {
+        self.max_payload_size = max_payload_size;
+        self
+    }
+
+    /// Buffers metrics, sending newline separated metrics in a single payload up to the maximum payload size
+    ///
+    /// Buffered metrics are sent when the next metric does not fit, on [flush](DogStatsd::flush), and when the last clone of the client is dropped.
+    pub fn with_buffering(mut self) -> Self {
+        self.buffered = true;
+        self
+    }
+
+    /// Sends the buffered metrics
+    pub fn flush(&self) -> crate::Result<()> {
+        self.sink.flush(&mut self.sink.buffer.lock().unwrap())
+    }
+
+    /// Sends a metric, dropping it at random according to its sample rate
+    pub fn send(&self, metric: &metric::Metric) -> crate::Result<()> {
+        if let Some(rate) = metric.sample_rate {
+            if !sampled(rate) {
+                return Ok(());
+            }
+        }
+        self.send_datagram(metric, |m| &mut m.tags)
+    }
+
+    /// Sends an event
+    pub fn send_event(&self, event: &event::Event) -> crate::Result<()> {
+        self.send_datagram(event, |e| &mut e.tags)
+    }
+
+    /// Sends a service check
+    pub fn send_service_check(&self, check: &service_check::ServiceCheck) -> crate::Result<()> {
+        self.send_datagram(check, |c| &mut c.tags)
+    }
+
+    /// Renders a datagram with the client tags and container id, then sends or buffers it
+    fn send_datagram<T: Clone + fmt::Display>(
+        &self,
+        item: &T,
+        tags: fn(&mut T) -> &mut Vec<String>,
+    ) -> crate::Result<()> {
+        let mut datagram = match self.tags.is_empty() {
+            true => item.to_string(),
+            false => {
+                let mut item = item.clone();
+                tags(&mut item).extend(self.tags.iter().cloned());
+                item.to_string()
+            }
+        };
+        if let Some(container_id) = &self.container_id {
+            datagram.push_str("|c:");
+            datagram.push_str(container_id);
+        }
+        self.write(datagram.as_bytes())
+    }
+
+    /// Sends or buffers a datagram
+    ///
+    /// A datagram larger than the maximum payload size is rejected, the agent would drop it.
+    fn write(&self, datagram: &[u8]) -> crate::Result<()> {
+        if datagram.len() > self.max_payload_size {
+            return Err(crate::Error::Validation(format!(
+                "datagram is {} bytes, exceeding the {} byte payload limit",
+                datagram.len(),
+                self.max_payload_size
+            )));
+        }
+        if !self.buffered {
+            return self.sink.transport.send(datagram);
+        }
+        let mut buffer = self.sink.buffer.lock().unwrap();
+        let mut result = Ok(());
+        if !buffer.is_empty() && buffer.len() + 1 + datagram.len() > self.max_payload_size {
+            result = self.sink.flush(&mut buffer);
+        }
+        if !buffer.is_empty() {
+            buffer.push(b'\n');
+        }
+        buffer.extend_from_slice(datagram);
+        result
+    }
+
+    /// Adds the value to a count
+    pub fn count(&self, name: &str, value: f64, tags: &[&str]) -> crate::Result<()> {
+        self.send(&metric::Metric::count(name, value).with_tags(tags.to_vec()))
+    }
+
+    /// Increments a count by one
+    pub fn incr(&self, name: &str, tags: &[&str]) -> crate::Result<()> {
+        self.count(name, 1.0, tags)
+    }
+
+    /// Decrements a count by one
+    pub fn decr(&self, name: &str, tags: &[&str]) -> crate::Result<()> {
+        self.count(name, -1.0, tags)
+    }
+
+    /// Sets a gauge
+    pub fn gauge(&self, name: &str, value: f64, tags: &[&str]) -> crate::Result<()> {
+        self.send(&metric::Metric::gauge(name, value).with_tags(tags.to_vec()))
+    }
+
+    /// Records a histogram value
+    pub fn histogram(&self, name: &str, value: f64, tags: &[&str]) -> crate::Result<()> {
+        self.send(&metric::Metric::histogram(name, value).with_tags(tags.to_vec()))
+    }
+
+    /// Records a distribution value
+    pub fn distribution(&self, name: &str, value: f64, tags: &[&str]) -> crate::Result<()> {
+        self.send(&metric::Metric::distribution(name, value).with_tags(tags.to_vec()))
+    }
+
+    /// Records a set member
+    pub fn set(&self, name: &str, value: &str, tags: &[&str]) -> crate::Result<()> {
+        self.send(&metric::Metric::set(name, value).with_tags(tags.to_vec()))
+    }
+
+    /// Records a duration in milliseconds
+    pub fn timing(&self, name: &str, duration: Duration, tags: &[&str]) -> crate::Result<()> {
+        self.send(&metric::Metric::timer(name, duration).with_tags(tags.to_vec()))
+    }
+}
+
+/// Parses the container id from the contents of a `/proc/<pid>/cgroup` file
+///
+/// Matches the Docker and containerd 64 character ids, and the ids used by ECS Fargate and Kubernetes pods.
+///
+/// ```rust
+/// use ddog::dogstatsd::parse_container_id;
+///
+/// let cgroup = "12:memory:/kubepods/burstable/pod2d3da189/3726184226f5d3147c25fdeab5b60097e378e8a720503a5e19ecfdf29f869860\n";
+/// assert_eq!(
+///     parse_container_id(cgroup).as_deref(),
+///     Some("3726184226f5d3147c25fdeab5b60097e378e8a720503a5e19ecfdf29f869860")
+/// );
+/// assert_eq!(parse_container_id("0::/\n"), None);
+/// ```
+pub fn parse_container_id(cgroup: &str) -> Option<String> {
+    cgroup
+        .lines()
+        .filter_map(|line| line.splitn(3, ':').nth(2))
+        .filter_map(|path| path.rsplit('/').next())
+        .map(|segment| {
+            let segment = segment.strip_suffix(".scope").unwrap_or(segment);
+            segment
+                .rsplit('-')
+                .next()
+                .filter(|id| is_hex(id, 64))
+                .unwrap_or(segment)
+        })
+        .find(|id| is_container_id(id))
+        .map(String::from)
+}
+
+/// Whether a cgroup path segment is a container id
+fn is_container_id(id: &str) -> bool {
+    // Docker and containerd
+    if is_hex(id, 64) {
+        return true;
+    }
+    // ECS Fargate, `<32 hex>-<task number>`
+    if let Some((hex, number)) = id.split_once('-') {
+        if is_hex(hex, 32) && !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()) {
+            return true;
+        }
+    }
+    // UUIDs, with `-` or `_` separators
+    let groups = id.split(['-', '_']).collect::<Vec<_>>();
+    groups.len() == 5
+        && groups
+            .iter()
+            .zip([8, 4, 4, 4, 12])
+            .all(|(group, len)| is_hex(group, len))
+}
+
+/// Whether the string is `len` lowercase hex characters
+fn is_hex(s: &str, len: usize) -> bool {
+    s.len() == len && s.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+}
+
+/// Whether a metric with the given sample rate should be sent
+fn sampled(rate: f64) -> bool {
+    if rate >= 1.0 {
+        return true;
+    }
+    let random = RandomState::new().build_hasher().finish();
+    ((random % 1_000_000) as f64 / 1_000_000.0) < rate
+}
@@ -0,0 +1,125 @@
+//! DogStatsD Service Checks
+
+use std::fmt;
+
+use super::{
+    event::escape_newlines,
+    metric::{sanitize, write_tags},
+};
+
+/// The status of a service check
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ServiceCheckStatus {
+    /// `0`
+    Ok,
+    /// `1`
+    Warning,
+    /// `2`
+    Critical,
+    /// `3`
+    Unknown,
+}
+
+impl fmt::Display for ServiceCheckStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ServiceCheckStatus::Ok => write!(f, "0"),
+            ServiceCheckStatus::Warning => write!(f, "1"),
+            ServiceCheckStatus::Critical => write!(f, "2"),
+            ServiceCheckStatus::Unknown => write!(f, "3"),
+        }
+    }
+}
+
+/// A DogStatsD Service Check
+///
+/// Renders to the `_sc|name|status|d:timestamp|h:hostname|#tags|m:message` datagram format, see the [Datadog Documentation](https://docs.datadoghq.com/developers/dogstatsd/datagram_shell/?tab=servicechecks).
+///
+/// ```rust
+/// use ddog::dogstatsd::service_check::*;
+///
+/// let check = ServiceCheck::new("app.is_up", ServiceCheckStatus::Critical)
+///     .with_tags(vec!["service:api"])
+///     .with_message("health check timed out");
+/// assert_eq!(
+///     check.to_string(),
+///     "_sc|app.is_up|2|#service:api|m:health check timed out"
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ServiceCheck {
+    /// The service check name
+    pub name: String,
+    /// The service check status
+    pub status: ServiceCheckStatus,
+    /// POSIX timestamp of the check in seconds, defaults to the time the agent receives it
+    pub timestamp: Option<i64>,
+    /// The host of the check
+    pub hostname: Option<String>,
+    /// The check tags
+    pub tags: Vec<String>,
+    /// A message describing the current status
+    pub message: Option<String>,
+}
+
+impl ServiceCheck {
+    /// Instantiates a new service check
+    pub fn new(name: &str, status: ServiceCheckStatus) -> Self {
+        Self {
+            name: name.to_string(),
+            status,
+            timestamp: None,
+            hostname: None,
+            tags: vec![],
+            message: None,
+        }
+    }
+
+    /// Sets the POSIX timestamp of the check in seconds
+    pub fn with_timestamp(mut self, timestamp: i64) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Sets the host of the check
+    pub fn with_hostname(mut self, hostname: &str) -> Self {
+        self.hostname = Some(hostname.to_string());
+        self
+    }
+
+    /// Adds a tag
+    pub fn with_tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    /// Adds tags
+    pub fn with_tags(mut self, tags: Vec<&str>) -> Self {
+        self.tags.extend(tags.into_iter().map(String::from));
+        self
+    }
+
+    /// Sets the message describing the current status
+    pub fn with_message(mut self, message: &str) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+impl fmt::Display for ServiceCheck {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "_sc|{}|{}", sanitize(&self.name, &['|']), self.status)?;
+        if let Some(timestamp) = self.timestamp {
+            write!(f, "|d:{}", timestamp)?;
+        }
+        if let Some(hostname) = &self.hostname {
+            write!(f, "|h:{}", sanitize(hostname, &['|']))?;
+        }
+        write_tags(f, &self.tags)?;
+        // The message is last, `m:` is escaped so the agent does not split it
+        if let Some(message) = &self.message {
+            write!(f, "|m:{}", escape_newlines(message).replace("m:", "m\\:"))?;
+        }
+        Ok(())
+    }
+}
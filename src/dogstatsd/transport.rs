@@ -0,0 +1,101 @@
+//! DogStatsD Transports
+
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+
+#[cfg(unix)]
+use std::{
+    io::Write,
+    os::unix::net::{UnixDatagram, UnixStream},
+    path::PathBuf,
+    sync::Mutex,
+};
+
+/// A connected socket to the agent
+#[derive(Debug)]
+pub(crate) enum Transport {
+    /// `host:port` or `udp://host:port`
+    Udp(UdpSocket),
+    /// `unix:///path` or `unixgram:///path`
+    #[cfg(unix)]
+    UnixDatagram(UnixDatagram),
+    /// `unixstream:///path`, reconnecting after a failed write
+    #[cfg(unix)]
+    UnixStream {
+        path: PathBuf,
+        stream: Mutex<Option<UnixStream>>,
+    },
+}
+
+impl Transport {
+    /// Connects to the agent at the given address
+    pub(crate) fn connect(addr: &str) -> crate::Result<Self> {
+        #[cfg(unix)]
+        {
+            if let Some(path) = addr
+                .strip_prefix("unix://")
+                .or_else(|| addr.strip_prefix("unixgram://"))
+            {
+                let socket = UnixDatagram::unbound()?;
+                socket.connect(path)?;
+                return Ok(Transport::UnixDatagram(socket));
+            }
+            if let Some(path) = addr.strip_prefix("unixstream://") {
+                let stream = UnixStream::connect(path)?;
+                return Ok(Transport::UnixStream {
+                    path: PathBuf::from(path),
+                    stream: Mutex::new(Some(stream)),
+                });
+            }
+        }
+        let host = addr.strip_prefix("udp://").unwrap_or(addr);
+        let agent = host.to_socket_addrs()?.next().ok_or_else(|| {
+            crate::Error::Validation(format!("unresolvable agent address {}", addr))
+        })?;
+        let local: SocketAddr = match agent {
+            SocketAddr::V4(_) => ([0, 0, 0, 0], 0).into(),
+            SocketAddr::V6(_) => ([0u16; 8], 0).into(),
+        };
+        let socket = UdpSocket::bind(local)?;
+        socket.connect(agent)?;
+        Ok(Transport::Udp(socket))
+    }
+
+    /// The default maximum payload size of the transport
+    pub(crate) fn default_max_payload_size(&self) -> usize {
+        match self {
+            Transport::Udp(_) => super::DEFAULT_UDP_MAX_PAYLOAD_SIZE,
+            #[cfg(unix)]
+            _ => super::DEFAULT_UDS_MAX_PAYLOAD_SIZE,
+        }
+    }
+
+    /// Sends a payload
+    ///
+    /// Stream payloads are framed with their length as a little-endian `u32`, as the agent expects.
+    pub(crate) fn send(&self, payload: &[u8]) -> crate::Result<()> {
+        match self {
+            Transport::Udp(socket) => {
+                socket.send(payload)?;
+            }
+            #[cfg(unix)]
+            Transport::UnixDatagram(socket) => {
+                socket.send(payload)?;
+            }
+            #[cfg(unix)]
+            Transport::UnixStream { path, stream } => {
+                let mut stream = stream.lock().unwrap();
+                let connected = match stream.take() {
+                    Some(connected) => connected,
+                    None => UnixStream::connect(path)?,
+                };
+                let mut frame = Vec::with_capacity(payload.len() + 4);
+                frame.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+                frame.extend_from_slice(payload);
+                // Drop the connection on failure, the next payload reconnects
+                (&connected).write_all(&frame)?;
+                *stream = Some(connected);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+//! Error Types
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types;
+
+/// A ddog Result
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors returned when executing a route
+#[derive(Debug)]
+pub enum Error {
+    /// The request could not be sent or the response could not be read
+    Transport(reqwest::Error),
+    /// Datadog responded with a non-success status code
+    Status {
+        /// The response status code
+        status: reqwest::StatusCode,
+        /// The decoded `errors` array of the response body
+        errors: Vec<String>,
+        /// The rate limit headers of the response
+        rate_limit: Option<types::rate_limit::RateLimit>,
+    },
+    /// The response body could not be deserialized into the route's response type
+    Deserialize {
+        /// The response status code
+        status: reqwest::StatusCode,
+        /// The deserialization error
+        source: serde_json::Error,
+    },
+    /// The request failed client-side validation and was not sent
+    Validation(String),
+    /// The route does not support the api version
+    UnsupportedVersion(types::version::ApiVersion),
+    /// A socket or file operation failed
+    Io(std::io::Error),
+}
+
+/// A Datadog Error Response Body
+///
+/// #### Example
+///
+/// ```json
+/// {
+///     "errors": [ "Forbidden" ]
+/// }
+/// ```
+#[derive(Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct ErrorResponse {
+    /// The reported errors
+    #[serde(default)]
+    pub errors: Vec<serde_json::value::Value>,
+}
+
+impl Error {
+    /// The response status code, if the request reached Datadog
+    pub fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Error::Transport(e) => e.status(),
+            Error::Status { status, .. } => Some(*status),
+            Error::Deserialize { status, .. } => Some(*status),
+            Error::Validation(_) | Error::UnsupportedVersion(_) | Error::Io(_) => None,
+        }
+    }
+
+    /// Builds a status error from a response body, decoding the Datadog `errors` array when present
+    pub fn from_response(status: reqwest::StatusCode, body: &[u8]) -> Self {
+        let errors = serde_json::from_slice::<ErrorResponse>(body)
+            .map(|r| {
+                r.errors
+                    .into_iter()
+                    .map(|e| match e {
+                        serde_json::value::Value::String(s) => s,
+                        other => other.to_string(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_else(|_| match String::from_utf8_lossy(body).trim() {
+                "" => vec![],
+                text => vec![text.to_string()],
+            });
+        Error::Status {
+            status,
+            errors,
+            rate_limit: None,
+        }
+    }
+
+    /// Attaches the response rate limit to a status error
+    pub fn with_rate_limit(self, rate_limit: Option<types::rate_limit::RateLimit>) -> Self {
+        match self {
+            Error::Status { status, errors, .. } => Error::Status {
+                status,
+                errors,
+                rate_limit,
+            },
+            e => e,
+        }
+    }
+
+    /// The rate limit headers of the response, if the request was rate limited or failed with a status error
+    pub fn rate_limit(&self) -> Option<&types::rate_limit::RateLimit> {
+        match self {
+            Error::Status { rate_limit, .. } => rate_limit.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Transport(e) => write!(f, "request failed: {}", e),
+            Error::Status { status, errors, .. } => {
+                write!(f, "received status {}: {}", status, errors.join(", "))
+            }
+            Error::Deserialize { status, source } => write!(
+                f,
+                "failed to deserialize response with status {}: {}",
+                status, source
+            ),
+            Error::Validation(reason) => write!(f, "invalid request: {}", reason),
+            Error::UnsupportedVersion(version) => {
+                write!(f, "unsupported api version: {}", version)
+            }
+            Error::Io(e) => write!(f, "io error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Transport(e) => Some(e),
+            Error::Deserialize { source, .. } => Some(source),
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        Error::Transport(e)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
@@ -0,0 +1,336 @@
+//! Tracing Logs Layer
+
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tracing::{field, span, Event, Subscriber};
+use tracing_subscriber::{layer, registry::LookupSpan};
+
+use crate::{client, routes, types::route::Route};
+
+/// The default flush interval
+pub const DEFAULT_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The shortest flush interval, shorter intervals are clamped to it
+pub const MIN_FLUSH_INTERVAL: Duration = Duration::from_millis(1);
+
+/// The default maximum number of buffered log entries
+pub const DEFAULT_MAX_BUFFERED: usize = 10_000;
+
+/// Modules whose events are never shipped, since shipping logs emits events from them
+const IGNORED_MODULES: [&str; 5] = ["ddog", "reqwest", "hyper", "h2", "rustls"];
+
+/// A Tracing Layer shipping events to Datadog Logs
+///
+/// ## Overview
+///
+/// The layer converts every enabled [tracing] event into a Datadog [LogEntry](routes::logs::send_logs::LogEntry) and buffers it in memory.
+/// A background tokio task started with [spawn](LogsLayer::spawn) sends the buffered entries to the logs intake every flush interval.
+///
+/// Each log entry carries:
+///
+/// - `message`: the event message
+/// - `status`: the event level
+/// - `logger.name`: the event target
+/// - `spans`: the names of the spans the event occurred in, from the root span
+/// - the fields of these spans and of the event itself as attributes, inner fields taking precedence
+///
+/// Fields named after a reserved log attribute (`service`, `hostname`, `ddsource`, `ddtags`) set that attribute instead,
+/// overriding the layer defaults, and `ddtags` adding to the layer tags.
+///
+/// Events from ddog and its http stack are ignored so that shipping logs does not produce more logs.
+/// When the buffer holds [max_buffered](LogsLayer::max_buffered) entries, new events are dropped until the next flush.
+///
+/// ## Usage
+///
+/// ```rust
+/// use ddog::prelude::*;
+/// use tracing_subscriber::layer::SubscriberExt;
+///
+/// async {
+///     let client = Client::new().with_api_key("<api_key>");
+///     let layer = LogsLayer::new(client)
+///         .with_service("my-service")
+///         .with_tags(vec!["env:prod"]);
+///     let handle = layer.spawn();
+///
+///     let subscriber = tracing_subscriber::registry().with(layer.clone());
+///     tracing::subscriber::set_global_default(subscriber).unwrap();
+///     tracing::info!(user = "alice", "user logged in");
+///
+///     // Stop the background task and flush the remaining logs
+///     handle.abort();
+///     if let Err(e) = layer.flush().await {
+///         println!("Failed to flush logs: {}", e);
+///     }
+/// };
+/// ```
+#[derive(Clone, Debug)]
+pub struct LogsLayer {
+    /// The api client used to send logs
+    pub client: client::Client,
+    /// The interval buffered logs are sent at
+    pub flush_interval: Duration,
+    /// The most verbose level shipped
+    pub level: tracing::Level,
+    /// The `ddsource` of every log
+    pub source: String,
+    /// The service of every log
+    pub service: Option<String>,
+    /// The host of every log
+    pub hostname: Option<String>,
+    /// Tags added to every log
+    pub tags: Vec<String>,
+    /// The maximum number of buffered log entries
+    pub max_buffered: usize,
+    buffer: Arc<Mutex<Buffer>>,
+}
+
+/// Log entries waiting to be sent
+#[derive(Debug, Default)]
+struct Buffer {
+    entries: Vec<routes::logs::send_logs::LogEntry>,
+    dropped: usize,
+}
+
+/// The recorded fields of a span, stored in the span extensions
+struct SpanFields(serde_json::Map<String, serde_json::Value>);
+
+/// Records event and span fields as json attributes
+struct FieldVisitor<'a> {
+    message: Option<String>,
+    fields: &'a mut serde_json::Map<String, serde_json::Value>,
+}
+
+impl field::Visit for FieldVisitor<'_> {
+    fn record_f64(&mut self, field: &field::Field, value: f64) {
+        self.fields.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_i64(&mut self, field: &field::Field, value: i64) {
+        self.fields.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_u64(&mut self, field: &field::Field, value: u64) {
+        self.fields.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_bool(&mut self, field: &field::Field, value: bool) {
+        self.fields.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_str(&mut self, field: &field::Field, value: &str) {
+        match field.name() {
+            "message" => self.message = Some(value.to_string()),
+            name => {
+                self.fields.insert(name.to_string(), value.into());
+            }
+        }
+    }
+
+    fn record_debug(&mut self, field: &field::Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            "message" => self.message = Some(format!("{:?}", value)),
+            name => {
+                self.fields
+                    .insert(name.to_string(), format!("{:?}", value).into());
+            }
+        }
+    }
+}
+
+impl LogsLayer {
+    /// Instantiates a new layer sending logs through the given client
+    pub fn new(client: client::Client) -> Self {
+        Self {
+            client,
+            flush_interval: DEFAULT_FLUSH_INTERVAL,
+            level: tracing::Level::INFO,
+            source: String::from("rust"),
+            service: None,
+            hostname: None,
+            tags: vec![],
+            max_buffered: DEFAULT_MAX_BUFFERED,
+            buffer: Arc::new(Mutex::new(Buffer::default())),
+        }
+    }
+
+    /// Sets the interval buffered logs are sent at
+    pub fn with_flush_interval(mut self, flush_interval: Duration) -> Self {
+        self.flush_interval = flush_interval.max(MIN_FLUSH_INTERVAL);
+        self
+    }
+
+    /// Sets the most verbose level shipped
+    pub fn with_level(mut self, level: tracing::Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Sets the `ddsource` of every log
+    pub fn with_source(mut self, source: &str) -> Self {
+        self.source = source.to_string();
+        self
+    }
+
+    /// Sets the service of every log
+    pub fn with_service(mut self, service: &str) -> Self {
+        self.service = Some(service.to_string());
+        self
+    }
+
+    /// Sets the host of every log
+    pub fn with_hostname(mut self, hostname: &str) -> Self {
+        self.hostname = Some(hostname.to_string());
+        self
+    }
+
+    /// Adds tags to every log
+    pub fn with_tags(mut self, tags: Vec<&str>) -> Self {
+        self.tags.extend(tags.into_iter().map(String::from));
+        self
+    }
+
+    /// Sets the maximum number of buffered log entries
+    pub fn with_max_buffered(mut self, max_buffered: usize) -> Self {
+        self.max_buffered = max_buffered;
+        self
+    }
+
+    /// Sends the buffered logs
+    pub async fn flush(&self) -> crate::Result<()> {
+        let Buffer { entries, dropped } = std::mem::take(&mut *self.buffer.lock().unwrap());
+        if dropped > 0 {
+            tracing::warn!(target: "layer", "Dropped {} logs while the buffer was full", dropped);
+        }
+        if entries.is_empty() {
+            return Ok(());
+        }
+        tracing::info!(target: "layer", "Flushing {} logs", entries.len());
+        routes::logs::send_logs::SendLogs::new()
+            .with_client(self.client.clone())
+            .logs(entries)
+            .execute()
+            .await
+            .map(|_| ())
+            .map_err(|e| {
+                tracing::error!(target: "layer", "Failed to flush logs with error {}", e);
+                e
+            })
+    }
+
+    /// Spawns a tokio task sending the buffered logs every flush interval
+    ///
+    /// Aborting the returned handle stops the task; call [flush](LogsLayer::flush) afterwards to send the remaining logs.
+    pub fn spawn(&self) -> tokio::task::JoinHandle<()> {
+        let layer = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(layer.flush_interval.max(MIN_FLUSH_INTERVAL));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            // The first tick completes immediately
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                // Errors are logged by flush
+                let _ = layer.flush().await;
+            }
+        })
+    }
+
+    /// Whether events from the module are shipped
+    fn is_shipped(&self, metadata: &tracing::Metadata) -> bool {
+        let module = metadata.module_path().unwrap_or_default();
+        *metadata.level() <= self.level
+            && !IGNORED_MODULES
+                .iter()
+                .any(|ignored| module == *ignored || module.starts_with(&format!("{}::", ignored)))
+    }
+
+    /// The Datadog status of a level
+    fn status(level: &tracing::Level) -> &'static str {
+        match *level {
+            tracing::Level::ERROR => "error",
+            tracing::Level::WARN => "warning",
+            tracing::Level::INFO => "info",
+            _ => "debug",
+        }
+    }
+}
+
+impl<S> tracing_subscriber::Layer<S> for LogsLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: layer::Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            let mut fields = serde_json::Map::new();
+            attrs.record(&mut FieldVisitor {
+                message: None,
+                fields: &mut fields,
+            });
+            span.extensions_mut().insert(SpanFields(fields));
+        }
+    }
+
+    fn on_record(&self, id: &span::Id, values: &span::Record<'_>, ctx: layer::Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(SpanFields(fields)) = span.extensions_mut().get_mut::<SpanFields>() {
+                values.record(&mut FieldVisitor {
+                    message: None,
+                    fields,
+                });
+            }
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: layer::Context<'_, S>) {
+        let metadata = event.metadata();
+        if !self.is_shipped(metadata) {
+            return;
+        }
+
+        let mut attributes = serde_json::Map::new();
+        let mut spans = vec![];
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope.from_root() {
+                spans.push(serde_json::Value::from(span.name()));
+                if let Some(SpanFields(fields)) = span.extensions().get::<SpanFields>() {
+                    attributes.extend(fields.clone());
+                }
+            }
+        }
+        let mut visitor = FieldVisitor {
+            message: None,
+            fields: &mut attributes,
+        };
+        event.record(&mut visitor);
+        let message = visitor.message.unwrap_or_default();
+
+        let mut entry = routes::logs::send_logs::LogEntry::new(&message)
+            .with_source(&self.source)
+            .with_tags(self.tags.iter().map(String::as_str).collect());
+        if let Some(service) = &self.service {
+            entry = entry.with_service(service);
+        }
+        if let Some(hostname) = &self.hostname {
+            entry = entry.with_hostname(hostname);
+        }
+        entry = entry
+            .with_attributes(attributes)
+            .with_attribute("status", Self::status(metadata.level()))
+            .with_attribute("logger.name", metadata.target())
+            .with_attribute("date", chrono::Utc::now().to_rfc3339());
+        if !spans.is_empty() {
+            entry = entry.with_attribute("spans", spans);
+        }
+
+        let mut buffer = self.buffer.lock().unwrap();
+        match buffer.entries.len() < self.max_buffered {
+            true => buffer.entries.push(entry),
+            false => buffer.dropped += 1,
+        }
+    }
+}
@@ -2,7 +2,6 @@
 #![warn(missing_docs)]
 #![warn(unused_extern_crates)]
 #![forbid(unsafe_code)]
-#![forbid(where_clauses_object_safety)]
 
 /// An API Client
 pub mod client;
@@ -16,11 +15,28 @@ pub mod routes;
 /// Api Types
 pub mod types;
 
+/// Error Types
+pub mod error;
+
+/// Buffered Metrics Aggregator
+pub mod aggregator;
+
+/// DogStatsD Client
+pub mod dogstatsd;
+
+/// Tracing Logs Layer
+pub mod layer;
+pub use error::{Error, Result};
+
 /// Re-export prelude modules
 pub mod prelude {
     pub use super::{
-        client::{self, *},
+        aggregator::{self, Aggregator},
         builder::{self, *},
+        client::{self, *},
+        dogstatsd::{self, DogStatsd},
+        error::{self, Error},
+        layer::{self, LogsLayer},
         routes::{self, prelude::*},
         types::{self, prelude::*},
     };
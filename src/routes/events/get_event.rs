@@ -0,0 +1,203 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+
+use crate::{client, types, Error};
+
+use super::post_event::EventResponse;
+
+/// Get Event Endpoint
+///
+/// ## Overview
+///
+/// Get the details of an event. This endpoint requires an application key.
+///
+/// Endpoint Format: `/v1/events/{event_id}` [GET]
+///
+/// ## Arguments
+///
+/// - event_id [required]
+///    - type: `int64`
+///    - description: The ID of the event.
+///
+/// ## Response
+///
+/// One of: [200, 400, 403, 404, 429]
+///
+/// - event [type: object] - The event, see [Event](super::post_event::Event).
+/// - status [type: string] - A status.
+///
+/// #### Example
+///
+/// ```json
+/// {
+///     "event": {
+///         "alert_type": "info",
+///         "date_happened": 1636629071,
+///         "id": 6509751066204996294,
+///         "id_str": "6509751066204996294",
+///         "priority": "normal",
+///         "tags": [ "environment:test" ],
+///         "text": "Deployed v1.2.3",
+///         "title": "Deploy",
+///         "url": "https://app.datadoghq.com/event/event?id=6509751066204996294"
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct GetEvent {
+    /// The event id
+    pub event_id: Option<i64>,
+    /// Version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+impl Default for GetEvent {
+    fn default() -> Self {
+        Self {
+            event_id: None,
+            version: types::version::ApiVersion::V1,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for GetEvent {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V1 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl GetEvent {
+    /// Instantiates a new Event query for the given event id
+    pub fn new(event_id: i64) -> Self {
+        tracing::info!(target: "/v1/events/{event_id}", "Get Event Route Created");
+        Self {
+            event_id: Some(event_id),
+            ..Self::default()
+        }
+    }
+
+    /// Set the Event Id
+    pub fn set_event_id(mut self, event_id: i64) -> Self {
+        tracing::info!(target: "/v1/events/{event_id}", "Event id set to {}", event_id);
+        self.event_id = Some(event_id);
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v1/events/{event_id}")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<EventResponse> for GetEvent {
+    /// The route path
+    fn path(&self) -> String {
+        match self.event_id {
+            Some(event_id) => format!("v1/events/{}", event_id),
+            None => String::from("v1/events/"),
+        }
+    }
+
+    /// Manually sets the route path
+    ///
+    /// ## Usage
+    ///
+    /// It is not recommended to use this method directly, instead use the explicit sub routes functions.
+    ///
+    /// ## Warning
+    ///
+    /// Overrides the event id if the route is a valid id.
+    fn route(mut self, route: String) -> Self {
+        if let Ok(event_id) = route.parse::<i64>() {
+            self.event_id = Some(event_id);
+        }
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<EventResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        // Validate the event id
+        if self.event_id.is_none() {
+            tracing::error!(target: "/v1/events/{event_id}", "Missing event id");
+            return Err(Error::Validation(String::from("missing event id")));
+        }
+        tracing::info!(target: "/v1/events/{event_id}", "Sending Request to {}", self.path());
+        let req_builder = client
+            .request(reqwest::Method::GET, &self.path())
+            .headers(self.headers);
+        client.send(req_builder).await
+    }
+}
@@ -0,0 +1,296 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+use super::post_event::{Event, EventPriority};
+
+/// List Events Endpoint
+///
+/// ## Overview
+///
+/// Query the event stream between two POSIX timestamps. The response is limited to 1000 events, use `page` to retrieve the next events.
+///
+/// Endpoint Format: `/v1/events` [GET]
+///
+/// ## Arguments
+///
+/// **Query Strings**
+///
+/// - start [required] [type: int64] - POSIX timestamp.
+/// - end [required] [type: int64] - POSIX timestamp.
+/// - priority [type: enum] - Priority of your events. Allowed enum values: normal, low
+/// - sources [type: string] - A comma separated string of sources.
+/// - tags [type: string] - A comma separated list indicating what tags, if any, should be used to filter the list of events.
+/// - unaggregated [type: boolean] - Set unaggregated to `true` to return all events within the specified `start`,`end` timeframe.
+/// - exclude_aggregate [type: boolean] - Set `exclude_aggregate` to `true` to only return unaggregated events where `is_aggregate=false` in the response.
+/// - page [type: int32] - By default 1000 results are returned per request. Set page to the number of the page to return with 0 being the first page.
+///
+/// ## Response
+///
+/// One of: [200, 400, 403, 429]
+///
+/// - events [type: object[]] - An array of events, see [Event](super::post_event::Event).
+/// - status [type: string] - A status.
+///
+/// #### Example
+///
+/// ```json
+/// {
+///     "events": [
+///         {
+///             "alert_type": "info",
+///             "date_happened": 1636629071,
+///             "id": 6509751066204996294,
+///             "priority": "normal",
+///             "tags": [ "environment:test" ],
+///             "text": "Deployed v1.2.3",
+///             "title": "Deploy"
+///         }
+///     ],
+///     "status": "ok"
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ListEvents {
+    /// POSIX timestamp of the start of the query
+    pub start: i64,
+    /// POSIX timestamp of the end of the query
+    pub end: i64,
+    /// Only return events with the priority
+    pub priority: Option<EventPriority>,
+    /// Only return events from the sources
+    pub sources: Vec<String>,
+    /// Only return events with the tags
+    pub tags: Vec<String>,
+    /// Return all events within the timeframe instead of aggregates
+    pub unaggregated: Option<bool>,
+    /// Only return unaggregated events
+    pub exclude_aggregate: Option<bool>,
+    /// The page to return, starting at 0
+    pub page: Option<u32>,
+    /// Version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// A List Events Response
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct ListEventsResponse {
+    /// The events
+    #[serde(default)]
+    pub events: Vec<Event>,
+    /// A status
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+impl Default for ListEvents {
+    fn default() -> Self {
+        Self {
+            start: 0,
+            end: 0,
+            priority: None,
+            sources: vec![],
+            tags: vec![],
+            unaggregated: None,
+            exclude_aggregate: None,
+            page: None,
+            version: types::version::ApiVersion::V1,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for ListEvents {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V1 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl ListEvents {
+    /// Instantiates a new Event query between the POSIX timestamps
+    pub fn new(start: i64, end: i64) -> Self {
+        tracing::info!(target: "/v1/events", "List Events Route Created");
+        Self {
+            start,
+            end,
+            ..Self::default()
+        }
+    }
+
+    /// Set the query time range between the POSIX timestamps
+    pub fn set_time_range(mut self, start: i64, end: i64) -> Self {
+        tracing::info!(target: "/v1/events", "Events time range set to {} - {}", start, end);
+        self.start = start;
+        self.end = end;
+        self
+    }
+
+    /// Only return events with the priority
+    pub fn with_priority(mut self, priority: EventPriority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Only return events from the source
+    pub fn with_source(mut self, source: &str) -> Self {
+        self.sources.push(source.to_string());
+        self
+    }
+
+    /// Only return events with the tag
+    pub fn with_tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    /// Return all events within the timeframe instead of aggregates
+    pub fn unaggregated(mut self, unaggregated: bool) -> Self {
+        self.unaggregated = Some(unaggregated);
+        self
+    }
+
+    /// Only return unaggregated events
+    pub fn exclude_aggregate(mut self, exclude_aggregate: bool) -> Self {
+        self.exclude_aggregate = Some(exclude_aggregate);
+        self
+    }
+
+    /// Sets the page to return, starting at 0
+    pub fn with_page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// The query string parameters, omitting unset filters
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![
+            ("start", self.start.to_string()),
+            ("end", self.end.to_string()),
+        ];
+        if let Some(priority) = self.priority {
+            let priority = match priority {
+                EventPriority::Normal => "normal",
+                EventPriority::Low => "low",
+            };
+            query.push(("priority", priority.to_string()));
+        }
+        if !self.sources.is_empty() {
+            query.push(("sources", self.sources.join(",")));
+        }
+        if !self.tags.is_empty() {
+            query.push(("tags", self.tags.join(",")));
+        }
+        if let Some(unaggregated) = self.unaggregated {
+            query.push(("unaggregated", unaggregated.to_string()));
+        }
+        if let Some(exclude_aggregate) = self.exclude_aggregate {
+            query.push(("exclude_aggregate", exclude_aggregate.to_string()));
+        }
+        if let Some(page) = self.page {
+            query.push(("page", page.to_string()));
+        }
+        query
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v1/events")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<ListEventsResponse> for ListEvents {
+    /// The route path
+    fn path(&self) -> String {
+        String::from("v1/events")
+    }
+
+    /// Route does nothing here since the route path is fixed
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<ListEventsResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        let path = self.path();
+        tracing::info!(target: "/v1/events", "Sending Request to {}", path);
+        let req_builder = client
+            .request(reqwest::Method::GET, &path)
+            .query(&self.query())
+            .headers(self.headers);
+        client.send(req_builder).await
+    }
+}
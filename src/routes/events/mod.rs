@@ -0,0 +1,34 @@
+//! Events Endpoints
+//!
+//! ## Overview
+//!
+//! The events endpoints, as described in the [Datadog Documentation](https://docs.datadoghq.com/api/latest/events/):
+//!
+//! ```md
+//!     - Post events to the event stream, such as deploys or configuration changes
+//!     - Get the details of a single event
+//!     - Query the event stream over a time range
+//! ```
+//!
+//! Events are shown in the event explorer and can be overlaid on dashboards, for example as deploy markers.
+
+/// Event to post an event
+/// `v1/events` Endpoint [POST]
+pub mod post_event;
+
+/// Event to get an event's details
+/// `v1/events/{event_id}` Endpoint [GET]
+pub mod get_event;
+
+/// Event to query the event stream
+/// `v1/events` Endpoint [GET]
+pub mod list_events;
+
+/// Re-exported prelude of all events-related endpoints
+pub mod prelude {
+    pub use super::{
+        get_event::{self, *},
+        list_events::{self, *},
+        post_event::{self, *},
+    };
+}
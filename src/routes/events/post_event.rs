@@ -0,0 +1,372 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+/// Post Event Endpoint
+///
+/// ## Overview
+///
+/// The post event end-point allows you to post events to the event stream, for example to mark deploys on dashboards.
+///
+/// Endpoint Format: `/v1/events` [POST]
+///
+/// ## Request
+///
+/// **Body Data (required)**
+///
+/// - title [required] [type: string] - The event title. Limited to 100 characters.
+/// - text [required] [type: string] - The body of the event. Limited to 4000 characters. Supports markdown when prefixed with `%%% \n` and suffixed with `\n %%%`.
+/// - aggregation_key [type: string] - An arbitrary string to use for aggregation. Limited to 100 characters.
+/// - alert_type [type: enum] - If an alert event is enabled, set its type. Allowed enum values: error, warning, info, success, user_update, recommendation, snapshot
+/// - date_happened [type: int64] - POSIX timestamp of the event. Must be sent as an integer, limited to events no older than 18 hours.
+/// - device_name [type: string] - A device name.
+/// - host [type: string] - Host name to associate with the event.
+/// - priority [type: enum] - The priority of the event. Allowed enum values: normal, low
+/// - related_event_id [type: int64] - ID of the parent event.
+/// - source_type_name [type: string] - The type of event being posted, for example `jenkins` or `my_apps`.
+/// - tags [type: string[]] - A list of tags to apply to the event.
+///
+/// ## Response
+///
+/// One of: [202, 400, 403, 429]
+///
+/// - event [type: object] - The created event, including its `id` and `url`.
+/// - status [type: string] - A status.
+///
+/// #### Example
+///
+/// ```json
+/// {
+///     "event": {
+///         "date_happened": 1636629071,
+///         "id": 6509751066204996294,
+///         "id_str": "6509751066204996294",
+///         "priority": "normal",
+///         "tags": [ "environment:test" ],
+///         "text": "Deployed v1.2.3",
+///         "title": "Deploy",
+///         "url": "https://app.datadoghq.com/event/event?id=6509751066204996294"
+///     },
+///     "status": "ok"
+/// }
+/// ```
+#[derive(Debug)]
+pub struct PostEvent {
+    /// Version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The typed event to post, serialized in place of the body when set
+    pub event: Option<Event>,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// An Event Response
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct EventResponse {
+    /// The event
+    pub event: Event,
+    /// A status
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+/// An Event
+///
+/// The same object is used to post events and returned when reading them, the `id`, `id_str` and `url` fields are only set by Datadog.
+///
+/// ```rust
+/// use ddog::prelude::*;
+///
+/// let event = post_event::Event::new("Deploy", "Deployed v1.2.3")
+///     .with_alert_type(post_event::EventAlertType::Success)
+///     .with_tag("service:api");
+/// assert_eq!(
+///     serde_json::to_value(&event).unwrap(),
+///     serde_json::json!({
+///         "title": "Deploy",
+///         "text": "Deployed v1.2.3",
+///         "alert_type": "success",
+///         "tags": [ "service:api" ]
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct Event {
+    /// The event id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    /// The event id as a string, since ids may not fit in a javascript number
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id_str: Option<String>,
+    /// The url of the event in the event explorer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The event title
+    #[serde(default)]
+    pub title: String,
+    /// The body of the event
+    #[serde(default)]
+    pub text: String,
+    /// An arbitrary string to use for aggregation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aggregation_key: Option<String>,
+    /// The alert type of the event
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alert_type: Option<EventAlertType>,
+    /// POSIX timestamp of the event in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_happened: Option<i64>,
+    /// A device name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device_name: Option<String>,
+    /// Host name to associate with the event
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// The priority of the event
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<EventPriority>,
+    /// ID of the parent event
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_event_id: Option<i64>,
+    /// The type of event being posted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_type_name: Option<String>,
+    /// A list of tags to apply to the event
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+/// The Event Priorities
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq, Hash)]
+pub enum EventPriority {
+    /// Normal priority
+    #[serde(rename = "normal")]
+    Normal,
+    /// Low priority
+    #[serde(rename = "low")]
+    Low,
+}
+
+/// The Event Alert Types
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq, Hash)]
+pub enum EventAlertType {
+    /// An error event
+    #[serde(rename = "error")]
+    Error,
+    /// A warning event
+    #[serde(rename = "warning")]
+    Warning,
+    /// An info event
+    #[serde(rename = "info")]
+    Info,
+    /// A success event
+    #[serde(rename = "success")]
+    Success,
+    /// A user update event
+    #[serde(rename = "user_update")]
+    UserUpdate,
+    /// A recommendation event
+    #[serde(rename = "recommendation")]
+    Recommendation,
+    /// A snapshot event
+    #[serde(rename = "snapshot")]
+    Snapshot,
+}
+
+impl Event {
+    /// Instantiates a new event with the given title and text
+    pub fn new(title: &str, text: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            text: text.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the key used to aggregate events
+    pub fn with_aggregation_key(mut self, aggregation_key: &str) -> Self {
+        self.aggregation_key = Some(aggregation_key.to_string());
+        self
+    }
+
+    /// Sets the alert type
+    pub fn with_alert_type(mut self, alert_type: EventAlertType) -> Self {
+        self.alert_type = Some(alert_type);
+        self
+    }
+
+    /// Sets the POSIX timestamp of the event in seconds
+    pub fn with_date_happened(mut self, date_happened: i64) -> Self {
+        self.date_happened = Some(date_happened);
+        self
+    }
+
+    /// Sets the host associated with the event
+    pub fn with_host(mut self, host: &str) -> Self {
+        self.host = Some(host.to_string());
+        self
+    }
+
+    /// Sets the priority
+    pub fn with_priority(mut self, priority: EventPriority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Sets the type of event being posted
+    pub fn with_source_type_name(mut self, source_type_name: &str) -> Self {
+        self.source_type_name = Some(source_type_name.to_string());
+        self
+    }
+
+    /// Adds a tag
+    pub fn with_tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    /// Adds a list of tags
+    pub fn with_tags(mut self, tags: Vec<&str>) -> Self {
+        self.tags.extend(tags.into_iter().map(String::from));
+        self
+    }
+}
+
+impl Default for PostEvent {
+    fn default() -> Self {
+        Self {
+            version: types::version::ApiVersion::V1,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            event: None,
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for PostEvent {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V1 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl PostEvent {
+    /// Instantiates a new Event
+    pub fn new() -> Self {
+        tracing::info!(target: "/v1/events", "Post Event Route Created");
+        Self::default()
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Sets the typed event to post
+    ///
+    /// The event is serialized as the request body when the route is executed, replacing any body set with [body](types::route::Route::body).
+    pub fn event(mut self, event: Event) -> Self {
+        tracing::info!(target: "/v1/events", "Event set with title {}", event.title);
+        self.event = Some(event);
+        self
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v1/events")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<EventResponse> for PostEvent {
+    /// The route path
+    fn path(&self) -> String {
+        String::from("v1/events")
+    }
+
+    /// Route does nothing here since the route path is fixed
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<EventResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        let path = self.path();
+        tracing::info!(target: "/v1/events", "Sending Request to {}", path);
+        let body = match self.event {
+            Some(event) => reqwest::Body::from(
+                serde_json::to_vec(&event).map_err(|e| Error::Validation(e.to_string()))?,
+            ),
+            None => self.body,
+        };
+        let req_builder = client
+            .request(reqwest::Method::POST, &path)
+            .headers(self.headers)
+            .body(body);
+        client.send(req_builder).await
+    }
+}
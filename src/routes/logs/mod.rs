@@ -0,0 +1,28 @@
+//! Logs Endpoints
+//!
+//! ## Overview
+//!
+//! The logs endpoints, as described in the [Datadog Documentation](https://docs.datadoghq.com/api/latest/logs/):
+//!
+//! ```md
+//!     - Send logs to the logs intake, without a Datadog Agent
+//!     - Search logs, following cursors to list every match
+//! ```
+//!
+//! Note that logs are sent to the logs intake host, `http-intake.logs.<site>`, rather than the api host, see [logs_intake_url](crate::types::base::Site::logs_intake_url).
+
+/// Logs to send log entries
+/// `v2/logs` Endpoint [POST]
+pub mod send_logs;
+
+/// Logs to search log entries
+/// `v2/logs/events/search` Endpoint [POST]
+pub mod search_logs;
+
+/// Re-exported prelude of all logs-related endpoints
+pub mod prelude {
+    pub use super::{
+        search_logs::{self, *},
+        send_logs::{self, *},
+    };
+}
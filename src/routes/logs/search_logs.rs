@@ -0,0 +1,536 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use futures_util::{stream, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+/// Search Logs Endpoint
+///
+/// ## Overview
+///
+/// List endpoint returning logs that match a log search query. Results are paginated, use the `meta.page.after` cursor of a response as the `page.cursor` of the next request, or [stream](SearchLogs::stream) every matching log.
+///
+/// This endpoint requires the `logs_read_data` authorization [scope](https://docs.datadoghq.com/api/latest/scopes/#logs).
+///
+/// Endpoint Format: `/v2/logs/events/search` [POST]
+///
+/// ## Request
+///
+/// **Body Data**
+///
+/// - filter [type: object] - The search and filter query settings.
+///    - query [type: string] - The search query, following the log search syntax. Defaults to `*`.
+///    - from [type: string] - The minimum time for the requested logs, supports date math and regular timestamps (milliseconds). Defaults to `now-15m`.
+///    - to [type: string] - The maximum time for the requested logs, supports date math and regular timestamps (milliseconds). Defaults to `now`.
+///    - indexes [type: string[]] - For customers with multiple indexes, the indexes to search. Defaults to `*`.
+/// - page [type: object] - Paging attributes for listing logs.
+///    - cursor [type: string] - List following results with a cursor provided in the previous query.
+///    - limit [type: int32] - Maximum number of logs in the response, up to 1000. Defaults to 10.
+/// - sort [type: enum] - Sort parameters when querying logs. Allowed enum values: timestamp, -timestamp
+///
+/// ## Response
+///
+/// One of: [200, 400, 403, 429]
+///
+/// - data [type: object[]] - Array of logs matching the request.
+/// - links [type: object] - Links attributes, `next` being the link to the next page of results.
+/// - meta [type: object] - The metadata associated with the request, `page.after` being the cursor of the next page.
+///
+/// #### Example
+///
+/// ```json
+/// {
+///     "data": [
+///         {
+///             "id": "AAAAAWgN8Xwgr1vKDQAAAABBV2dOOFh3ZzZobm1mWXJFYTR0OA",
+///             "type": "log",
+///             "attributes": {
+///                 "attributes": { "trace_id": "4186426424873185451" },
+///                 "host": "i-0123",
+///                 "message": "Host connected to remote",
+///                 "service": "agent",
+///                 "status": "INFO",
+///                 "tags": [ "team:A" ],
+///                 "timestamp": "2019-01-02T09:42:36.320Z"
+///             }
+///         }
+///     ],
+///     "links": { "next": "https://app.datadoghq.com/api/v2/logs/event?filter[query]=foo&page[cursor]=eyJzdGFydEF0IjoiQVFBQUFYS2tMS3pPbm40NGV3QUFBQUJCV0V0clRFdDZVbG8zY3pCRmNsbHJiVmxDWlEifQ==" },
+///     "meta": {
+///         "elapsed": 132,
+///         "page": { "after": "eyJzdGFydEF0IjoiQVFBQUFYS2tMS3pPbm40NGV3QUFBQUJCV0V0clRFdDZVbG8zY3pCRmNsbHJiVmxDWlEifQ==" },
+///         "request_id": "MWlFUjVaWGZTTTZPYzM0VXp1OXU2d3xLSVpEMjZKQ0VKUTI0dEYtM3RSOFVR",
+///         "status": "done"
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct SearchLogs {
+    /// Version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The typed search request, serialized in place of the body when set
+    pub request: Option<LogsSearchRequest>,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// A Logs Search Request
+///
+/// ```rust
+/// use ddog::prelude::*;
+///
+/// let request = search_logs::LogsSearchRequest {
+///     filter: search_logs::LogsQueryFilter {
+///         query: Some(String::from("service:web trace_id:4186426424873185451")),
+///         from: Some(String::from("now-1h")),
+///         ..Default::default()
+///     },
+///     sort: Some(search_logs::LogsSort::TimestampAscending),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     serde_json::to_value(&request).unwrap(),
+///     serde_json::json!({
+///         "filter": { "query": "service:web trace_id:4186426424873185451", "from": "now-1h" },
+///         "sort": "timestamp"
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct LogsSearchRequest {
+    /// The search and filter query settings
+    #[serde(default)]
+    pub filter: LogsQueryFilter,
+    /// Paging attributes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page: Option<LogsListRequestPage>,
+    /// The sort order of the logs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<LogsSort>,
+}
+
+/// The search and filter query settings of a logs search
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct LogsQueryFilter {
+    /// The search query, following the log search syntax
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// The minimum time for the requested logs, as date math or a timestamp in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// The maximum time for the requested logs, as date math or a timestamp in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    /// The indexes to search
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub indexes: Vec<String>,
+}
+
+/// Paging attributes of a logs search
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct LogsListRequestPage {
+    /// The cursor of the page to list, from the previous response
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// The maximum number of logs in the response
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// The sort order of a logs search
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq)]
+pub enum LogsSort {
+    /// Oldest logs first
+    #[serde(rename = "timestamp")]
+    TimestampAscending,
+    /// Newest logs first
+    #[serde(rename = "-timestamp")]
+    TimestampDescending,
+}
+
+/// A Search Logs Response
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct SearchLogsResponse {
+    /// The logs matching the request
+    #[serde(default)]
+    pub data: Vec<Log>,
+    /// Links attributes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub links: Option<LogsResponseLinks>,
+    /// The metadata associated with the request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<LogsResponseMetadata>,
+}
+
+impl SearchLogsResponse {
+    /// The cursor of the next page, if there are more results
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.meta.as_ref()?.page.as_ref()?.after.as_deref()
+    }
+}
+
+/// A Log returned by a search
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct Log {
+    /// Unique ID of the log
+    #[serde(default)]
+    pub id: String,
+    /// The type of the object, always `log`
+    #[serde(default, rename = "type")]
+    pub type_: String,
+    /// The content of the log
+    #[serde(default)]
+    pub attributes: LogAttributes,
+}
+
+/// The content of a Log
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct LogAttributes {
+    /// The custom attributes of the log
+    #[serde(default)]
+    pub attributes: serde_json::Map<String, serde_json::Value>,
+    /// The name of the originating host of the log
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// The message of the log
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// The name of the application or service generating the log
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    /// The status of the log
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// The tags of the log
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// The timestamp of the log
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Links attributes of a Search Logs Response
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct LogsResponseLinks {
+    /// Link to the next page of results
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<String>,
+}
+
+/// The metadata of a Search Logs Response
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct LogsResponseMetadata {
+    /// The time elapsed in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub elapsed: Option<i64>,
+    /// Paging attributes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page: Option<LogsResponsePage>,
+    /// The identifier of the request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// The status of the response, `done` or `timeout`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Warnings about the query
+    #[serde(default)]
+    pub warnings: Vec<LogsWarning>,
+}
+
+/// Paging attributes of a Search Logs Response
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct LogsResponsePage {
+    /// The cursor of the next page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+}
+
+/// A warning about a logs search query
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct LogsWarning {
+    /// A unique code for this type of warning
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// A detailed explanation of this specific warning
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// A short human-readable summary of the warning
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+impl Default for SearchLogs {
+    fn default() -> Self {
+        Self {
+            version: types::version::ApiVersion::V2,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            request: None,
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for SearchLogs {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V2 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl SearchLogs {
+    /// Instantiates a new Logs search
+    pub fn new() -> Self {
+        tracing::info!(target: "/v2/logs/events/search", "Search Logs Route Created");
+        Self::default()
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Sets the typed search request
+    ///
+    /// The request is serialized as the request body when the route is executed, replacing any body set with [body](types::route::Route::body).
+    pub fn search(mut self, request: LogsSearchRequest) -> Self {
+        self.request = Some(request);
+        self
+    }
+
+    /// Sets the search query, following the log search syntax
+    pub fn with_query(mut self, query: &str) -> Self {
+        tracing::info!(target: "/v2/logs/events/search", "Logs query set to {}", query);
+        self.request
+            .get_or_insert_with(Default::default)
+            .filter
+            .query = Some(query.to_string());
+        self
+    }
+
+    /// Sets the time range of the requested logs, as date math such as `now-1h` or timestamps in milliseconds
+    pub fn with_time_range(mut self, from: &str, to: &str) -> Self {
+        tracing::info!(target: "/v2/logs/events/search", "Logs time range set to {} - {}", from, to);
+        let filter = &mut self.request.get_or_insert_with(Default::default).filter;
+        filter.from = Some(from.to_string());
+        filter.to = Some(to.to_string());
+        self
+    }
+
+    /// Adds an index to search
+    pub fn with_index(mut self, index: &str) -> Self {
+        self.request
+            .get_or_insert_with(Default::default)
+            .filter
+            .indexes
+            .push(index.to_string());
+        self
+    }
+
+    /// Sets the sort order of the logs
+    pub fn with_sort(mut self, sort: LogsSort) -> Self {
+        self.request.get_or_insert_with(Default::default).sort = Some(sort);
+        self
+    }
+
+    /// Sets the maximum number of logs per page
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.request
+            .get_or_insert_with(Default::default)
+            .page
+            .get_or_insert_with(Default::default)
+            .limit = Some(limit);
+        self
+    }
+
+    /// Sets the cursor of the page to list, from a previous response
+    pub fn with_cursor(mut self, cursor: &str) -> Self {
+        self.request
+            .get_or_insert_with(Default::default)
+            .page
+            .get_or_insert_with(Default::default)
+            .cursor = Some(cursor.to_string());
+        self
+    }
+
+    /// Streams every page of results, following the `meta.page.after` cursor until it is exhausted
+    ///
+    /// Only the typed search request is sent, any body set with [body](types::route::Route::body) is ignored.
+    /// The stream ends after the first failed page.
+    pub fn pages(self) -> impl Stream<Item = Result<SearchLogsResponse, Error>> {
+        let path = types::route::Route::<SearchLogsResponse>::path(&self);
+        let state = (
+            self.client.unwrap_or_else(client::Client::shared),
+            self.headers,
+            self.request.unwrap_or_default(),
+        );
+        stream::unfold(Some(state), move |state| {
+            let path = path.clone();
+            async move {
+                let (client, headers, mut request) = state?;
+                let body = match serde_json::to_vec(&request) {
+                    Ok(body) => body,
+                    Err(e) => return Some((Err(Error::Validation(e.to_string())), None)),
+                };
+                let req_builder = client
+                    .request(reqwest::Method::POST, &path)
+                    .headers(headers.clone())
+                    .body(body);
+                match client
+                    .send_idempotent::<SearchLogsResponse>(req_builder)
+                    .await
+                {
+                    Ok(response) => {
+                        let page = response.into_inner();
+                        let next = match page.data.is_empty() {
+                            true => None,
+                            false => page.next_cursor().map(String::from),
+                        };
+                        let state = next.map(|cursor| {
+                            tracing::info!(target: "/v2/logs/events/search", "Following cursor {}", cursor);
+                            request.page.get_or_insert_with(Default::default).cursor = Some(cursor);
+                            (client, headers, request)
+                        });
+                        Some((Ok(page), state))
+                    }
+                    Err(e) => Some((Err(e), None)),
+                }
+            }
+        })
+    }
+
+    /// Streams every log matching the search, following the `meta.page.after` cursor until it is exhausted
+    ///
+    /// ## Usage
+    ///
+    /// ```rust
+    /// use ddog::prelude::*;
+    /// use futures_util::StreamExt;
+    ///
+    /// async {
+    ///     let client = Client::new()
+    ///         .with_api_key("<api_key>")
+    ///         .with_application_key("<application_key>");
+    ///     let logs = client
+    ///         .search_logs()?
+    ///         .with_query("trace_id:4186426424873185451")
+    ///         .with_time_range("now-1d", "now")
+    ///         .with_sort(search_logs::LogsSort::TimestampAscending)
+    ///         .stream();
+    ///     futures_util::pin_mut!(logs);
+    ///     while let Some(log) = logs.next().await {
+    ///         match log {
+    ///             Ok(log) => println!("{:?}", log.attributes.message),
+    ///             Err(e) => println!("Failed to search logs: {}", e),
+    ///         }
+    ///     }
+    ///     Ok::<(), Error>(())
+    /// };
+    /// ```
+    pub fn stream(self) -> impl Stream<Item = Result<Log, Error>> {
+        self.pages().flat_map(|page| {
+            stream::iter(match page {
+                Ok(page) => page.data.into_iter().map(Ok).collect::<Vec<_>>(),
+                Err(e) => vec![Err(e)],
+            })
+        })
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v2/logs/events/search")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<SearchLogsResponse> for SearchLogs {
+    /// The route path
+    fn path(&self) -> String {
+        String::from("v2/logs/events/search")
+    }
+
+    /// Route does nothing here since the route path is fixed
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request, returning a single page of results
+    async fn execute(self) -> Result<types::response::Response<SearchLogsResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        let path = self.path();
+        tracing::info!(target: "/v2/logs/events/search", "Sending Request to {}", path);
+        let body = match self.request {
+            Some(request) => reqwest::Body::from(
+                serde_json::to_vec(&request).map_err(|e| Error::Validation(e.to_string()))?,
+            ),
+            None => self.body,
+        };
+        let req_builder = client
+            .request(reqwest::Method::POST, &path)
+            .headers(self.headers)
+            .body(body);
+        // Searching is read only, so the request is safe to retry
+        client.send_idempotent(req_builder).await
+    }
+}
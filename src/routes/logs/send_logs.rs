@@ -0,0 +1,470 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+/// The maximum uncompressed size in bytes of a logs payload
+pub const MAX_LOGS_PAYLOAD_SIZE: usize = 5_242_880;
+
+/// The maximum number of log entries in a payload
+pub const MAX_LOGS_PER_PAYLOAD: usize = 1000;
+
+/// The maximum size in bytes of a single log entry
+pub const MAX_LOG_ENTRY_SIZE: usize = 1_048_576;
+
+/// The log entry keys backed by a named [LogEntry] field
+pub const RESERVED_ATTRIBUTES: [&str; 5] = ["message", "ddsource", "ddtags", "hostname", "service"];
+
+/// Send Logs Endpoint
+///
+/// ## Overview
+///
+/// Send your logs to your Datadog platform over HTTP. Limits per HTTP request are:
+///
+/// ```md
+///     - Maximum content size per payload (uncompressed): 5MB
+///     - Maximum size for a single log: 1MB
+///     - Maximum array size if sending multiple logs in an array: 1000 entries
+/// ```
+///
+/// Typed log entries set with [logs](SendLogs::logs) are split into as many requests as needed to stay within these limits, see [batch_logs].
+///
+/// The logs intake is hosted on `http-intake.logs.<site>`, see [logs_intake_url](types::base::Site::logs_intake_url).
+///
+/// Endpoint Format: `/v2/logs` [POST]
+///
+/// ## Arguments
+///
+/// - Content-Encoding [header]
+///    - type: `string`
+///    - description: HTTP header used to compress the media-type. Set automatically when compression is enabled with `with_compression`, Datadog supports `gzip` and `deflate`.
+///
+/// ## Request
+///
+/// **Body Data (required)**
+///
+/// An array of log entries:
+///
+/// - ddsource [type: string] - The integration name associated with your log: the technology from which the log originated.
+/// - ddtags [type: string] - Tags associated with your logs, as a comma separated list.
+/// - hostname [type: string] - The name of the originating host of the log.
+/// - message [type: string] - The message reserved attribute of your log.
+/// - service [type: string] - The name of the application or service generating the log events.
+/// - <any-key> [type: any] - Additional log attributes.
+///
+/// ## Response
+///
+/// One of: [202, 400, 401, 403, 408, 413, 429, 500, 503]
+///
+/// #### Example
+///
+/// ```json
+/// {}
+/// ```
+#[derive(Debug)]
+pub struct SendLogs {
+    /// Version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// Typed log entries to send, serialized in place of the body when set
+    pub logs: Option<Vec<LogEntry>>,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// A Send Logs Response
+///
+/// The logs intake acknowledges accepted logs with an empty object.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct SendLogsResponse {}
+
+/// A Log Entry
+///
+/// ```rust
+/// use ddog::prelude::*;
+///
+/// let entry = send_logs::LogEntry::new("user logged in")
+///     .with_source("rust")
+///     .with_service("auth")
+///     .with_hostname("worker_1")
+///     .with_tags(vec!["env:prod", "team:identity"])
+///     .with_attribute("usr.id", 42);
+/// assert_eq!(
+///     serde_json::to_value(&entry).unwrap(),
+///     serde_json::json!({
+///         "message": "user logged in",
+///         "ddsource": "rust",
+///         "ddtags": "env:prod,team:identity",
+///         "hostname": "worker_1",
+///         "service": "auth",
+///         "usr.id": 42
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct LogEntry {
+    /// The message reserved attribute of the log
+    pub message: String,
+    /// The integration name associated with the log
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ddsource: Option<String>,
+    /// Tags associated with the log, as a comma separated list
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ddtags: Option<String>,
+    /// The name of the originating host of the log
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    /// The name of the application or service generating the log
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    /// Additional log attributes
+    ///
+    /// Keys in [RESERVED_ATTRIBUTES] are not serialized so they cannot duplicate the named fields, set them with [with_attribute](LogEntry::with_attribute) instead.
+    #[serde(flatten, serialize_with = "serialize_attributes")]
+    pub attributes: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Serializes the attributes of a log entry, skipping the reserved keys
+fn serialize_attributes<S: serde::Serializer>(
+    attributes: &serde_json::Map<String, serde_json::Value>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(
+        attributes
+            .iter()
+            .filter(|(key, _)| !RESERVED_ATTRIBUTES.contains(&key.as_str())),
+    )
+}
+
+impl LogEntry {
+    /// Instantiates a new log entry with the given message
+    pub fn new(message: &str) -> Self {
+        Self {
+            message: message.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the integration name associated with the log
+    pub fn with_source(mut self, source: &str) -> Self {
+        self.ddsource = Some(source.to_string());
+        self
+    }
+
+    /// Sets the originating host of the log
+    pub fn with_hostname(mut self, hostname: &str) -> Self {
+        self.hostname = Some(hostname.to_string());
+        self
+    }
+
+    /// Sets the service generating the log
+    pub fn with_service(mut self, service: &str) -> Self {
+        self.service = Some(service.to_string());
+        self
+    }
+
+    /// Adds a tag
+    pub fn with_tag(mut self, tag: &str) -> Self {
+        self.push_tag(tag);
+        self
+    }
+
+    /// Adds a list of tags
+    pub fn with_tags(self, tags: Vec<&str>) -> Self {
+        tags.into_iter()
+            .fold(self, |entry, tag| entry.with_tag(tag))
+    }
+
+    /// Sets an additional attribute, ignoring values that fail to serialize
+    ///
+    /// A key in [RESERVED_ATTRIBUTES] sets the named field instead, `ddtags` adding to the existing tags.
+    pub fn with_attribute<T: Serialize>(mut self, key: &str, value: T) -> Self {
+        if let Ok(value) = serde_json::to_value(value) {
+            self.set_attribute(key.to_string(), value);
+        }
+        self
+    }
+
+    /// Sets additional attributes, keys in [RESERVED_ATTRIBUTES] setting the named fields
+    pub fn with_attributes(
+        mut self,
+        attributes: serde_json::Map<String, serde_json::Value>,
+    ) -> Self {
+        for (key, value) in attributes {
+            self.set_attribute(key, value);
+        }
+        self
+    }
+
+    /// Sets an attribute or the named field it is reserved for
+    fn set_attribute(&mut self, key: String, value: serde_json::Value) {
+        if !RESERVED_ATTRIBUTES.contains(&key.as_str()) {
+            self.attributes.insert(key, value);
+            return;
+        }
+        let value = match value {
+            serde_json::Value::String(value) => value,
+            value => value.to_string(),
+        };
+        match key.as_str() {
+            "message" => self.message = value,
+            "ddsource" => self.ddsource = Some(value),
+            "hostname" => self.hostname = Some(value),
+            "service" => self.service = Some(value),
+            _ => self.push_tag(&value),
+        }
+    }
+
+    /// Appends a tag to the comma separated tags
+    fn push_tag(&mut self, tag: &str) {
+        self.ddtags = match self.ddtags.take() {
+            Some(tags) if !tags.is_empty() => Some(format!("{},{}", tags, tag)),
+            _ => Some(tag.to_string()),
+        };
+    }
+}
+
+impl Default for SendLogs {
+    fn default() -> Self {
+        Self {
+            version: types::version::ApiVersion::V2,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            logs: None,
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for SendLogs {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V2 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl SendLogs {
+    /// Instantiates a new Logs submission
+    pub fn new() -> Self {
+        tracing::info!(target: "/v2/logs", "Send Logs Route Created");
+        Self::default()
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Sets the typed log entries to send
+    ///
+    /// The entries are serialized as the request body when the route is executed, replacing any body set with [body](types::route::Route::body).
+    pub fn logs(mut self, logs: Vec<LogEntry>) -> Self {
+        tracing::info!(target: "/v2/logs", "Logs set with {} entries", logs.len());
+        self.logs = Some(logs);
+        self
+    }
+
+    /// Compresses the request body with the given compression
+    pub fn with_compression(mut self, compression: types::compression::Compression) -> Self {
+        self.client
+            .get_or_insert_with(client::Client::shared)
+            .compression = Some(compression);
+        self
+    }
+
+    /// Executes the request, returning the result of each batch
+    ///
+    /// Typed log entries are split into batches within the intake limits and every batch is sent, even if an earlier batch failed.
+    pub async fn execute_batches(
+        self,
+    ) -> Vec<Result<types::response::Response<SendLogsResponse>, Error>> {
+        let client = client::Client::or_shared(&self.client);
+        let url = format!(
+            "{}/{}",
+            client.site.logs_intake_url(),
+            types::route::Route::<SendLogsResponse>::path(&self)
+        );
+        let logs = match self.logs {
+            Some(logs) => logs,
+            None => {
+                tracing::info!(target: "/v2/logs", "Sending Request to {}", url);
+                let req_builder = client
+                    .request_url(reqwest::Method::POST, &url)
+                    .headers(self.headers);
+                return match client.compressed_body(req_builder, self.body) {
+                    Ok(req_builder) => vec![client.send_idempotent(req_builder).await],
+                    Err(e) => vec![Err(e)],
+                };
+            }
+        };
+        let batches = match batch_logs(&logs) {
+            Ok(batches) => batches,
+            Err(e) => {
+                tracing::error!(target: "/v2/logs", "Failed to batch logs with error {:?}", e);
+                return vec![Err(e)];
+            }
+        };
+        tracing::info!(target: "/v2/logs", "Sending {} batches to {}", batches.len(), url);
+        let mut results = Vec::with_capacity(batches.len());
+        for batch in batches {
+            let req_builder = client
+                .request_url(reqwest::Method::POST, &url)
+                .headers(self.headers.clone());
+            results.push(
+                match client.compressed_body(req_builder, reqwest::Body::from(batch)) {
+                    Ok(req_builder) => client.send_idempotent(req_builder).await,
+                    Err(e) => Err(e),
+                },
+            );
+        }
+        results
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v2/logs")
+    }
+}
+
+/// Splits log entries into json array payloads within the intake limits
+///
+/// Each payload holds at most [MAX_LOGS_PER_PAYLOAD] entries and [MAX_LOGS_PAYLOAD_SIZE] bytes before compression.
+///
+/// A single entry larger than [MAX_LOG_ENTRY_SIZE] is rejected with an [Error::Validation].
+pub fn batch_logs(logs: &[LogEntry]) -> Result<Vec<Vec<u8>>, Error> {
+    let mut batches = vec![];
+    let mut batch = vec![b'['];
+    let mut count = 0;
+    for entry in logs {
+        let encoded = serde_json::to_vec(entry).map_err(|e| Error::Validation(e.to_string()))?;
+        if encoded.len() > MAX_LOG_ENTRY_SIZE {
+            return Err(Error::Validation(format!(
+                "log entry is {} bytes, exceeding the {} byte entry limit",
+                encoded.len(),
+                MAX_LOG_ENTRY_SIZE
+            )));
+        }
+        // The separator and closing bracket
+        if count == MAX_LOGS_PER_PAYLOAD || batch.len() + encoded.len() + 2 > MAX_LOGS_PAYLOAD_SIZE
+        {
+            batch.push(b']');
+            batches.push(std::mem::replace(&mut batch, vec![b'[']));
+            count = 0;
+        }
+        if count > 0 {
+            batch.push(b',');
+        }
+        batch.extend_from_slice(&encoded);
+        count += 1;
+    }
+    if count > 0 {
+        batch.push(b']');
+        batches.push(batch);
+    }
+    Ok(batches)
+}
+
+#[async_trait]
+impl types::route::Route<SendLogsResponse> for SendLogs {
+    /// The route path
+    fn path(&self) -> String {
+        String::from("v2/logs")
+    }
+
+    /// Route does nothing here since the route path is fixed
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    ///
+    /// When typed log entries are split into several batches, the first failed batch is returned as the error.
+    /// Use [execute_batches](SendLogs::execute_batches) to inspect the result of every batch.
+    ///
+    /// An empty list of log entries sends no request and returns an accepted response.
+    async fn execute(self) -> Result<types::response::Response<SendLogsResponse>, Error> {
+        let mut response = None;
+        let mut error = None;
+        for result in self.execute_batches().await {
+            match result {
+                Ok(r) => {
+                    response.get_or_insert(r);
+                }
+                Err(e) => {
+                    tracing::error!(target: "/v2/logs", "Batch failed with error {:?}", e);
+                    error.get_or_insert(e);
+                }
+            }
+        }
+        match (error, response) {
+            (Some(e), _) => Err(e),
+            (None, Some(response)) => Ok(response),
+            // Nothing to send, as if the intake accepted an empty batch
+            (None, None) => Ok(types::response::Response {
+                status: reqwest::StatusCode::ACCEPTED,
+                rate_limit: None,
+                data: SendLogsResponse::default(),
+            }),
+        }
+    }
+}
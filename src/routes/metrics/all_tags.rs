@@ -0,0 +1,248 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+use super::tags::MetricTag;
+
+/// Metric All Tags Endpoint
+///
+/// ## Overview
+///
+/// View indexed and ingested tag key-value pairs for the given metric name over the previous hour, or the given look back window.
+///
+/// Endpoint Format: `/v2/metrics/{metric_name}/all-tags` [GET]
+///
+/// ## Arguments
+///
+/// - metric_name [required]
+///    - type: `string`
+///    - description: The name of the metric.
+///
+/// **Query Strings**
+///
+/// - window[seconds] [type: int64] - The number of seconds of look back (from now). Defaults to 3600 seconds.
+///
+/// ## Response
+///
+/// One of: [200, 400, 403, 404, 429]
+///
+/// - data [type: object] - Object for a single metric's indexed and ingested tags.
+///    - type [type: enum] - Allowed enum values: metrics
+///    - id [type: string] - The metric name for this resource.
+///    - attributes [type: object]
+///       - tags [type: string[]] - List of indexed tag value pairs.
+///       - ingested_tags [type: string[]] - List of ingested tag value pairs.
+///
+/// #### Example
+///
+/// ```json
+/// {
+///     "data": {
+///         "type": "metrics",
+///         "id": "rpc.latency",
+///         "attributes": {
+///             "tags": [ "env:prod" ],
+///             "ingested_tags": [ "env:prod", "host:web-1" ]
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AllTags {
+    /// The metric name
+    pub name: Option<String>,
+    /// The number of seconds of look back (from now)
+    pub window_seconds: Option<i64>,
+    /// The api version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// A Metric All Tags Response
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct AllTagsResponse {
+    /// The metric tags
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<AllTagsData>,
+}
+
+/// The indexed and ingested tags of a metric
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
+pub struct AllTagsData {
+    /// The resource type, always `metrics`
+    #[serde(rename = "type")]
+    pub type_: MetricTag,
+    /// The metric name
+    pub id: String,
+    /// The tags
+    #[serde(default)]
+    pub attributes: AllTagsAttributes,
+}
+
+/// The tag key-value pairs of a metric
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct AllTagsAttributes {
+    /// Indexed tag key-value pairs
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Ingested tag key-value pairs
+    #[serde(default)]
+    pub ingested_tags: Vec<String>,
+}
+
+impl Default for AllTags {
+    fn default() -> Self {
+        Self {
+            name: None,
+            window_seconds: None,
+            version: types::version::ApiVersion::V2,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for AllTags {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V2 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl AllTags {
+    /// Instantiates a new all tags retrieval for the metric
+    pub fn new(metric_name: &str) -> Self {
+        tracing::info!(target: "/v2/metrics/{metric_name}/all-tags", "All Tags Route Created");
+        Self {
+            name: Some(metric_name.to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the Metric Name
+    pub fn set_metric_name(mut self, metric_name: String) -> Self {
+        tracing::info!(target: "/v2/metrics/{metric_name}/all-tags", "All Tags Metric Name Set to {}", metric_name);
+        self.name = Some(metric_name);
+        self
+    }
+
+    /// Sets the number of seconds of look back (from now)
+    pub fn with_window_seconds(mut self, window_seconds: i64) -> Self {
+        self.window_seconds = Some(window_seconds);
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// The query string parameters, omitting an unset look back window
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        self.window_seconds
+            .map(|window_seconds| ("window[seconds]", window_seconds.to_string()))
+            .into_iter()
+            .collect()
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v2/metrics/{metric_name}/all-tags")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<AllTagsResponse> for AllTags {
+    /// The route path
+    fn path(&self) -> String {
+        format!(
+            "v2/metrics/{}/all-tags",
+            self.name.as_deref().unwrap_or_default()
+        )
+    }
+
+    /// Route does nothing here since the route path is set with the metric name
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<AllTagsResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        if self.name.as_deref().unwrap_or_default().is_empty() {
+            tracing::error!(target: "/v2/metrics/{metric_name}/all-tags", "Missing metric name");
+            return Err(Error::Validation(String::from("missing metric name")));
+        }
+        let path = self.path();
+        tracing::info!(target: "/v2/metrics/{metric_name}/all-tags", "Sending Request to {}", path);
+        let req_builder = client
+            .request(reqwest::Method::GET, &path)
+            .query(&self.query())
+            .headers(self.headers);
+        client.send(req_builder).await
+    }
+}
@@ -0,0 +1,464 @@
+use std::{str::FromStr, time::Duration};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+use super::{
+    list_tag_configurations::{
+        ListTagConfigurations, ListTagConfigurationsResponse, TagConfigurationData,
+    },
+    tags::MetricTag,
+};
+
+/// Bulk Tags Metrics Endpoint
+///
+/// ## Overview
+///
+/// Create and define a list of queryable tag keys for a set of existing count, gauge, rate, and distribution metrics.
+/// Metrics are selected by passing a metric name prefix. Results are sent to the provided emails and the
+/// progress of the job can be followed with [poll_status](BulkTagsData::poll_status).
+/// Can only be used with application keys of users with the Manage Tags for Metrics permission.
+///
+/// Endpoint Format: `/v2/metrics/config/bulk-tags` [POST]
+///
+/// ## Request
+///
+/// - Body Data (required)
+///   - data [required] [type: object] - Request object to bulk configure tags for metrics matching the given prefix.
+///     - attributes [type: object] - Optional parameters for bulk creating metric tag configurations.
+///       - emails [type: string[]] - A list of account emails to notify when the configuration is applied.
+///       - exclude_tags_mode [type: boolean] - When set to true, the configuration will exclude the configured tags and include any other submitted tags.
+///       - include_actively_queried_tags_window [type: double] - When provided, all tags that have been actively queried are configured (and, therefore, remain queryable) for each metric that matches the given prefix. Minimum value is 1 second, and maximum value is 7,776,000 seconds (90 days).
+///       - override_existing_configurations [type: boolean] - When set to true, the configuration overrides any existing configurations for the given metric with the new set of tags in this configuration request.
+///       - tags [type: string[]] - A list of tag names to apply to the configuration.
+///     - id [required] [type: string] - A text prefix to match against metric names.
+///     - type [required] [type: enum] - The metric bulk configure tags resource. Allowed enum values: metric_bulk_configure_tags
+///
+/// ## Response
+///
+/// One of: [202, 400, 403, 404, 429]
+///
+/// #### Example
+///
+/// ```json
+/// {
+///     "data": {
+///         "type": "metric_bulk_configure_tags",
+///         "id": "kafka.lag",
+///         "attributes": {
+///             "emails": [ "sue@example.com", "bob@example.com" ],
+///             "tags": [ "host", "pod_name", "is_shadow" ]
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct BulkTags {
+    /// The metric name prefix
+    pub prefix: Option<String>,
+    /// The api version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The typed bulk tag configuration, serialized in place of the body when set
+    pub attributes: Option<BulkTagsAttributes>,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// A Bulk Tags Request or Response
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct BulkTagsResponse {
+    /// The bulk tag configuration
+    pub data: BulkTagsData,
+}
+
+/// A bulk tag configuration of the metrics matching a prefix
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct BulkTagsData {
+    /// The resource type
+    #[serde(rename = "type")]
+    pub type_: BulkTagsType,
+    /// The metric name prefix
+    pub id: String,
+    /// The bulk tag configuration
+    #[serde(default)]
+    pub attributes: BulkTagsAttributes,
+}
+
+/// The attributes of a bulk tag configuration
+///
+/// Tags are only set when configuring metrics, a bulk deletion only carries the emails to notify.
+///
+/// ## Usage
+///
+/// ```rust
+/// use ddog::prelude::*;
+///
+/// let attributes = bulk_tags::BulkTagsAttributes::new(vec!["host", "pod_name"])
+///     .with_emails(vec!["sue@example.com"])
+///     .with_exclude_tags_mode(false);
+/// assert_eq!(
+///     serde_json::to_value(&attributes).unwrap(),
+///     serde_json::json!({
+///         "emails": [ "sue@example.com" ],
+///         "exclude_tags_mode": false,
+///         "tags": [ "host", "pod_name" ]
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct BulkTagsAttributes {
+    /// The account emails to notify when the configuration is applied
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub emails: Vec<String>,
+    /// Whether the configured tags are excluded rather than included
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exclude_tags_mode: Option<bool>,
+    /// The look back window in seconds of actively queried tags to keep queryable
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_actively_queried_tags_window: Option<f64>,
+    /// Whether existing configurations of the matching metrics are overridden
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub override_existing_configurations: Option<bool>,
+    /// The tag keys of the configuration
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+
+/// The Bulk Tags resource type
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkTagsType {
+    /// A bulk tag configuration
+    MetricBulkConfigureTags,
+}
+
+/// The progress of a bulk tag configuration over the metrics matching its prefix
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct BulkTagsStatus {
+    /// The metrics the configuration, or deletion, has been applied to
+    pub applied: Vec<String>,
+    /// The metrics still waiting on the configuration, or deletion
+    pub pending: Vec<String>,
+}
+
+impl BulkTagsStatus {
+    /// Whether the configuration has been applied to every matching metric
+    pub fn is_complete(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+impl BulkTagsAttributes {
+    /// Creates a bulk tag configuration of the tag keys
+    pub fn new(tags: Vec<&str>) -> Self {
+        Self {
+            tags: Some(tags.into_iter().map(String::from).collect()),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the account emails to notify when the configuration is applied
+    pub fn with_emails(mut self, emails: Vec<&str>) -> Self {
+        self.emails = emails.into_iter().map(String::from).collect();
+        self
+    }
+
+    /// Sets whether the configured tags are excluded rather than included
+    pub fn with_exclude_tags_mode(mut self, exclude_tags_mode: bool) -> Self {
+        self.exclude_tags_mode = Some(exclude_tags_mode);
+        self
+    }
+
+    /// Keeps the tags actively queried within the look back window in seconds queryable
+    pub fn with_include_actively_queried_tags_window(mut self, window_seconds: f64) -> Self {
+        self.include_actively_queried_tags_window = Some(window_seconds);
+        self
+    }
+
+    /// Sets whether existing configurations of the matching metrics are overridden
+    pub fn with_override_existing_configurations(mut self, override_existing: bool) -> Self {
+        self.override_existing_configurations = Some(override_existing);
+        self
+    }
+}
+
+impl BulkTagsData {
+    /// Checks which metrics matching the prefix the configuration has been applied to
+    ///
+    /// A configuration is applied once the metric has a tag configuration with the same tag keys and mode,
+    /// a deletion once the metric no longer has a tag configuration.
+    pub fn status(&self, configurations: &[TagConfigurationData]) -> BulkTagsStatus {
+        let mut status = BulkTagsStatus::default();
+        let tags = self.attributes.tags.as_ref().map(|tags| {
+            let mut tags = tags.clone();
+            tags.sort();
+            tags
+        });
+        for configuration in configurations
+            .iter()
+            .filter(|configuration| configuration.id.starts_with(&self.id))
+        {
+            let current = match (&configuration.type_, &configuration.attributes) {
+                (MetricTag::ManageTags, Some(attributes)) => Some(attributes),
+                _ => None,
+            };
+            let applied = match (&tags, current) {
+                (Some(tags), Some(current)) => {
+                    let mut current_tags = current.tags.clone();
+                    current_tags.sort();
+                    &current_tags == tags
+                        && current.exclude_tags_mode.unwrap_or_default()
+                            == self.attributes.exclude_tags_mode.unwrap_or_default()
+                }
+                (Some(_), None) => false,
+                (None, current) => current.is_none(),
+            };
+            match applied {
+                true => status.applied.push(configuration.id.clone()),
+                false => status.pending.push(configuration.id.clone()),
+            }
+        }
+        status
+    }
+
+    /// Polls the tag configurations of the metrics until the configuration is applied to all metrics matching the prefix
+    ///
+    /// The tag configurations are listed with the given route up to `max_attempts` times, waiting `interval` in between,
+    /// and the last status is returned whether or not it is complete.
+    ///
+    /// The filters of the route narrow the listing, and the status only covers the metrics it returns.
+    /// When polling a deletion and `configured` is unset, only configured metrics are listed since the deleted ones are no longer configured.
+    /// Metrics without a configuration are pending while a configuration is applied, so `with_configured(true)` would hide them.
+    ///
+    /// ## Usage
+    ///
+    /// ```rust
+    /// use ddog::prelude::*;
+    ///
+    /// async {
+    ///     let client = Client::new()
+    ///         .with_api_key("<api_key>")
+    ///         .with_application_key("<application_key>");
+    ///     let res = client
+    ///         .bulk_tag_config("kafka.")?
+    ///         .configuration(bulk_tags::BulkTagsAttributes::new(vec!["host", "pod_name"]))
+    ///         .execute()
+    ///         .await;
+    ///     if let Ok(response) = res {
+    ///         let configurations = client
+    ///             .list_tag_configs()?
+    ///             .with_metric_type(list_tag_configurations::MetricTypeFilter::NonDistribution);
+    ///         let status = response
+    ///             .into_inner()
+    ///             .data
+    ///             .poll_status(configurations, std::time::Duration::from_secs(30), 20)
+    ///             .await;
+    ///         println!("{:?}", status);
+    ///     }
+    ///     Ok::<(), Error>(())
+    /// };
+    /// ```
+    pub async fn poll_status(
+        &self,
+        mut configurations: ListTagConfigurations,
+        interval: Duration,
+        max_attempts: u32,
+    ) -> Result<BulkTagsStatus, Error> {
+        if self.attributes.tags.is_none() && configurations.configured.is_none() {
+            configurations = configurations.with_configured(true);
+        }
+        let client = client::Client::or_shared(&configurations.client);
+        let path = types::route::Route::<ListTagConfigurationsResponse>::path(&configurations);
+        let query = configurations.query();
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let req_builder = client
+                .request(reqwest::Method::GET, &path)
+                .query(&query)
+                .headers(configurations.headers.clone());
+            let listing = client
+                .send::<ListTagConfigurationsResponse>(req_builder)
+                .await?
+                .into_inner();
+            let status = self.status(&listing.data);
+            tracing::info!(target: "/v2/metrics/config/bulk-tags", "Bulk tags on {} applied to {} of {} metrics", self.id, status.applied.len(), status.applied.len() + status.pending.len());
+            if status.is_complete() || attempt >= max_attempts {
+                return Ok(status);
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+}
+
+/// Serializes bulk tag attributes as a `metric_bulk_configure_tags` request body for the prefix
+pub(crate) fn bulk_tags_body(
+    prefix: &str,
+    attributes: BulkTagsAttributes,
+) -> Result<reqwest::Body, Error> {
+    let payload = BulkTagsResponse {
+        data: BulkTagsData {
+            type_: BulkTagsType::MetricBulkConfigureTags,
+            id: prefix.to_string(),
+            attributes,
+        },
+    };
+    serde_json::to_vec(&payload)
+        .map(reqwest::Body::from)
+        .map_err(|e| Error::Validation(e.to_string()))
+}
+
+impl Default for BulkTags {
+    fn default() -> Self {
+        Self {
+            prefix: None,
+            version: types::version::ApiVersion::V2,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            attributes: None,
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for BulkTags {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V2 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl BulkTags {
+    /// Instantiates a new bulk tag configuration for the metrics matching the prefix
+    pub fn new(prefix: &str) -> Self {
+        tracing::info!(target: "/v2/metrics/config/bulk-tags", "Bulk Tags Route Created");
+        Self {
+            prefix: Some(prefix.to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the Metric Name Prefix
+    pub fn set_prefix(mut self, prefix: String) -> Self {
+        tracing::info!(target: "/v2/metrics/config/bulk-tags", "Bulk Tags Prefix Set to {}", prefix);
+        self.prefix = Some(prefix);
+        self
+    }
+
+    /// Sets the typed bulk tag configuration
+    ///
+    /// The configuration is serialized as the request body when the route is executed, replacing any body set with [body](types::route::Route::body).
+    pub fn configuration(mut self, attributes: BulkTagsAttributes) -> Self {
+        self.attributes = Some(attributes);
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v2/metrics/config/bulk-tags")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<BulkTagsResponse> for BulkTags {
+    /// The route path
+    fn path(&self) -> String {
+        String::from("v2/metrics/config/bulk-tags")
+    }
+
+    /// Route does nothing here since the route path is fixed
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<BulkTagsResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        let path = self.path();
+        let prefix = match self.prefix {
+            Some(prefix) if !prefix.is_empty() => prefix,
+            _ => {
+                tracing::error!(target: "/v2/metrics/config/bulk-tags", "Missing metric name prefix");
+                return Err(Error::Validation(String::from(
+                    "missing metric name prefix",
+                )));
+            }
+        };
+        tracing::info!(target: "/v2/metrics/config/bulk-tags", "Sending Request to {}", path);
+        let body = match self.attributes {
+            Some(attributes) => bulk_tags_body(&prefix, attributes)?,
+            None => self.body,
+        };
+        let req_builder = client
+            .request(reqwest::Method::POST, &path)
+            .headers(self.headers)
+            .body(body);
+        client.send(req_builder).await
+    }
+}
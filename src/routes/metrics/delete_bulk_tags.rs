@@ -0,0 +1,199 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+
+use crate::{client, types, Error};
+
+use super::bulk_tags::{bulk_tags_body, BulkTagsAttributes, BulkTagsResponse};
+
+/// Delete Bulk Tags Metrics Endpoint
+///
+/// ## Overview
+///
+/// Delete all custom lists of queryable tag keys for a set of existing count, gauge, rate, and distribution metrics.
+/// Metrics are selected by passing a metric name prefix. Results are sent to the provided emails.
+/// Can only be used with application keys of users with the Manage Tags for Metrics permission.
+///
+/// Endpoint Format: `/v2/metrics/config/bulk-tags` [DELETE]
+///
+/// ## Request
+///
+/// - Body Data (required)
+///   - data [required] [type: object] - Request object to bulk delete all tag configurations for metrics matching the given prefix.
+///     - attributes [type: object] - Optional parameters for bulk deleting metric tag configurations.
+///       - emails [type: string[]] - A list of account emails to notify when the configuration is applied.
+///     - id [required] [type: string] - A text prefix to match against metric names.
+///     - type [required] [type: enum] - The metric bulk configure tags resource. Allowed enum values: metric_bulk_configure_tags
+///
+/// ## Response
+///
+/// One of: [202, 400, 403, 404, 429]
+///
+/// The bulk deletion, see [BulkTagsResponse](super::bulk_tags::BulkTagsResponse).
+#[derive(Debug)]
+pub struct DeleteBulkTags {
+    /// The metric name prefix
+    pub prefix: Option<String>,
+    /// The account emails to notify when the deletion is applied
+    pub emails: Vec<String>,
+    /// The api version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+impl Default for DeleteBulkTags {
+    fn default() -> Self {
+        Self {
+            prefix: None,
+            emails: vec![],
+            version: types::version::ApiVersion::V2,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for DeleteBulkTags {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V2 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl DeleteBulkTags {
+    /// Instantiates a new bulk tag configuration deletion for the metrics matching the prefix
+    pub fn new(prefix: &str) -> Self {
+        tracing::info!(target: "/v2/metrics/config/bulk-tags", "Delete Bulk Tags Route Created");
+        Self {
+            prefix: Some(prefix.to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the Metric Name Prefix
+    pub fn set_prefix(mut self, prefix: String) -> Self {
+        tracing::info!(target: "/v2/metrics/config/bulk-tags", "Bulk Tags Prefix Set to {}", prefix);
+        self.prefix = Some(prefix);
+        self
+    }
+
+    /// Sets the account emails to notify when the deletion is applied
+    pub fn with_emails(mut self, emails: Vec<&str>) -> Self {
+        self.emails = emails.into_iter().map(String::from).collect();
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v2/metrics/config/bulk-tags")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<BulkTagsResponse> for DeleteBulkTags {
+    /// The route path
+    fn path(&self) -> String {
+        String::from("v2/metrics/config/bulk-tags")
+    }
+
+    /// Route does nothing here since the route path is fixed
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    ///
+    /// The request body is built from the prefix and emails, replacing any body set with [body](types::route::Route::body).
+    async fn execute(self) -> Result<types::response::Response<BulkTagsResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        let path = self.path();
+        let prefix = match self.prefix {
+            Some(prefix) if !prefix.is_empty() => prefix,
+            _ => {
+                tracing::error!(target: "/v2/metrics/config/bulk-tags", "Missing metric name prefix");
+                return Err(Error::Validation(String::from(
+                    "missing metric name prefix",
+                )));
+            }
+        };
+        tracing::info!(target: "/v2/metrics/config/bulk-tags", "Sending Request to {}", path);
+        let body = bulk_tags_body(
+            &prefix,
+            BulkTagsAttributes {
+                emails: self.emails,
+                ..BulkTagsAttributes::default()
+            },
+        )?;
+        let req_builder = client
+            .request(reqwest::Method::DELETE, &path)
+            .headers(self.headers)
+            .body(body);
+        client.send(req_builder).await
+    }
+}
@@ -0,0 +1,176 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+/// Delete Tags Metrics Endpoint
+///
+/// ## Overview
+///
+/// Deletes a metric's tag configuration. Can only be used with application keys from users with the Manage Tags for Metrics permission.
+///
+/// Endpoint Format: `/v2/metrics/{metric_name}/tags` [DELETE]
+///
+/// ## Arguments
+///
+/// - metric_name [required]
+///    - type: `string`
+///    - description: The name of the metric.
+///
+/// ## Response
+///
+/// One of: [204, 400, 403, 404, 429]
+#[derive(Debug)]
+pub struct DeleteTags {
+    /// The metric name
+    pub name: Option<String>,
+    /// The api version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// A Delete Tags Response
+///
+/// A deleted tag configuration is acknowledged with an empty body.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct DeleteTagsResponse {}
+
+impl Default for DeleteTags {
+    fn default() -> Self {
+        Self {
+            name: None,
+            version: types::version::ApiVersion::V2,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for DeleteTags {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V2 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl DeleteTags {
+    /// Instantiates a new tag configuration deletion for the metric
+    pub fn new(metric_name: &str) -> Self {
+        tracing::info!(target: "/v2/metrics/{metric_name}/tags", "Delete Tags Route Created");
+        Self {
+            name: Some(metric_name.to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the Metric Name
+    pub fn set_metric_name(mut self, metric_name: String) -> Self {
+        tracing::info!(target: "/v2/metrics/{metric_name}/tags", "Tag Metric Name Set to {}", metric_name);
+        self.name = Some(metric_name);
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v2/metrics/{metric_name}/tags")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<DeleteTagsResponse> for DeleteTags {
+    /// The route path
+    fn path(&self) -> String {
+        format!(
+            "v2/metrics/{}/tags",
+            self.name.as_deref().unwrap_or_default()
+        )
+    }
+
+    /// Route does nothing here since the route path is set with the metric name
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<DeleteTagsResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        if self.name.as_deref().unwrap_or_default().is_empty() {
+            tracing::error!(target: "/v2/metrics/{metric_name}/tags", "Missing metric name");
+            return Err(Error::Validation(String::from("missing metric name")));
+        }
+        let path = self.path();
+        tracing::info!(target: "/v2/metrics/{metric_name}/tags", "Sending Request to {}", path);
+        let req_builder = client
+            .request(reqwest::Method::DELETE, &path)
+            .headers(self.headers);
+        client.send(req_builder).await
+    }
+}
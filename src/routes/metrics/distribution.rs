@@ -3,7 +3,7 @@ use std::str::FromStr;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
-use crate::types;
+use crate::{client, types, Error};
 
 /// Distribution Points Metrics Endpoint
 ///
@@ -17,7 +17,7 @@ use crate::types;
 ///
 /// - Content-Encoding [header]
 ///    - type: `string`
-///    - description: HTTP header used to compress the media-type.
+///    - description: HTTP header used to compress the media-type. Set automatically when compression is enabled with `with_compression`.
 ///
 /// ## Request
 ///
@@ -51,6 +51,10 @@ pub struct Distribution {
     pub headers: reqwest::header::HeaderMap,
     /// Request Body
     pub body: reqwest::Body,
+    /// Typed distribution series to submit, serialized in place of the body when set
+    pub series: Option<Vec<DistributionSeries>>,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
 }
 
 /// A Distribution Points Response
@@ -60,12 +64,126 @@ pub struct DistributionResponse {
     pub status: String,
 }
 
+/// A Distribution Points Request Body
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct DistributionPayload {
+    /// A list of distribution points series to submit to Datadog
+    pub series: Vec<DistributionSeries>,
+}
+
+/// A Distribution Points Series
+///
+/// ```rust
+/// use ddog::prelude::*;
+///
+/// let series = distribution::DistributionSeries::new("rpc_latency")
+///     .with_host("my-host")
+///     .with_tag("env:prod")
+///     .with_point(1636629071, vec![1.0, 2.0]);
+/// assert_eq!(
+///     serde_json::to_value(&series).unwrap(),
+///     serde_json::json!({
+///         "host": "my-host",
+///         "metric": "rpc_latency",
+///         "points": [ [ 1636629071, [ 1.0, 2.0 ] ] ],
+///         "tags": [ "env:prod" ],
+///         "type": "distribution"
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct DistributionSeries {
+    /// The name of the host that produced the distribution point metric
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// The name of the distribution points metric
+    pub metric: String,
+    /// Points relating to the distribution point metric
+    pub points: Vec<DistributionPoint>,
+    /// A list of tags associated with the distribution point metric
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// The type of the distribution point
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub type_: Option<DistributionPointsType>,
+}
+
+/// A Distribution Point
+///
+/// Serialized as a `[timestamp, [values...]]` tuple.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(from = "(i64, Vec<f64>)", into = "(i64, Vec<f64>)")]
+pub struct DistributionPoint {
+    /// The timestamp in POSIX seconds
+    pub timestamp: i64,
+    /// The values observed at the timestamp
+    pub values: Vec<f64>,
+}
+
+impl From<(i64, Vec<f64>)> for DistributionPoint {
+    fn from((timestamp, values): (i64, Vec<f64>)) -> Self {
+        Self { timestamp, values }
+    }
+}
+
+impl From<DistributionPoint> for (i64, Vec<f64>) {
+    fn from(point: DistributionPoint) -> Self {
+        (point.timestamp, point.values)
+    }
+}
+
+/// The Distribution Point Types
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub enum DistributionPointsType {
+    /// A distribution point
+    #[default]
+    #[serde(rename = "distribution")]
+    Distribution,
+}
+
+impl DistributionSeries {
+    /// Instantiates a new distribution series for the given metric name
+    pub fn new(metric: &str) -> Self {
+        Self {
+            metric: metric.to_string(),
+            type_: Some(DistributionPointsType::Distribution),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the host that produced the points
+    pub fn with_host(mut self, host: &str) -> Self {
+        self.host = Some(host.to_string());
+        self
+    }
+
+    /// Adds the values observed at the given POSIX timestamp in seconds
+    pub fn with_point(mut self, timestamp: i64, values: Vec<f64>) -> Self {
+        self.points.push(DistributionPoint { timestamp, values });
+        self
+    }
+
+    /// Adds a tag
+    pub fn with_tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    /// Adds a list of tags
+    pub fn with_tags(mut self, tags: Vec<&str>) -> Self {
+        self.tags.extend(tags.into_iter().map(String::from));
+        self
+    }
+}
+
 impl Default for Distribution {
     fn default() -> Self {
         Self {
             version: types::version::ApiVersion::V1,
             headers: reqwest::header::HeaderMap::new(),
             body: reqwest::Body::from(""),
+            series: None,
+            client: None,
         }
     }
 }
@@ -77,6 +195,35 @@ impl Distribution {
         Self::default()
     }
 
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Sets the typed distribution series to submit
+    ///
+    /// The series are serialized as the request body when the route is executed, replacing any body set with [body](types::route::Route::body).
+    pub fn series(mut self, series: Vec<DistributionSeries>) -> Self {
+        tracing::info!(target: "/v1/distribution_points", "Distribution set with {} series", series.len());
+        self.series = Some(series);
+        self
+    }
+
+    /// Compresses the request body with the given compression
+    pub fn with_compression(mut self, compression: types::compression::Compression) -> Self {
+        self.client
+            .get_or_insert_with(client::Client::shared)
+            .compression = Some(compression);
+        self
+    }
+
     /// Creates a target identifier for logging
     pub fn target() -> String {
         String::from("v1/distribution_points")
@@ -84,12 +231,12 @@ impl Distribution {
 }
 
 impl TryFrom<types::version::ApiVersion> for Distribution {
-    type Error = &'static str;
+    type Error = Error;
 
     fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
         match v {
             types::version::ApiVersion::V1 => Ok(Self::default()),
-            _ => Err("Unsupported API Version"),
+            v => Err(Error::UnsupportedVersion(v)),
         }
     }
 }
@@ -151,45 +298,21 @@ impl types::route::Route<DistributionResponse> for Distribution {
     }
 
     /// Executes the api request
-    async fn execute(
-        self,
-    ) -> (
-        reqwest::StatusCode,
-        Result<DistributionResponse, Option<reqwest::Error>>,
-    ) {
-        // Create the request url
-        let url = format!("{}/{}", types::base::BASE_API_URL, self.path());
-        tracing::info!(target: "/v1/distribution_points", "Sending Request to {}", url);
-
-        let mut req_builder: reqwest::RequestBuilder = reqwest::Client::new().post(url);
-        req_builder = req_builder.headers(self.headers.clone());
-        req_builder = req_builder.body(self.body);
-
-        let response = req_builder.send().await;
-
-        match response {
-            Ok(r) => {
-                let status_code = r.status();
-
-                // Try to unwrap the response into json
-                match r.json::<DistributionResponse>().await {
-                    Ok(json_response) => {
-                        tracing::info!(target: "/v1/distribution_points", "Deserialized Response into json: {:?}", json_response);
-                        return (status_code, Ok(json_response));
-                    }
-                    Err(e) => {
-                        tracing::error!(target: "/v1/distribution_points", "Failed to parse error response {:?}", e);
-                        return (status_code, Err(Some(e)));
-                    }
-                }
-            }
-            Err(e) => {
-                tracing::error!(target: "/v1/distribution_points", "Request failed with error {:?}", e);
-                return (
-                    e.status().unwrap_or(reqwest::StatusCode::BAD_REQUEST),
-                    Err(Some(e)),
-                );
-            }
-        }
+    async fn execute(self) -> Result<types::response::Response<DistributionResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        let path = self.path();
+        tracing::info!(target: "/v1/distribution_points", "Sending Request to {}", path);
+        let body = match self.series {
+            Some(series) => reqwest::Body::from(
+                serde_json::to_vec(&DistributionPayload { series })
+                    .map_err(|e| Error::Validation(e.to_string()))?,
+            ),
+            None => self.body,
+        };
+        let req_builder = client
+            .request(reqwest::Method::POST, &path)
+            .headers(self.headers);
+        let req_builder = client.compressed_body(req_builder, body)?;
+        client.send_idempotent(req_builder).await
     }
 }
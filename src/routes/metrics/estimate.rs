@@ -0,0 +1,325 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+/// Metric Estimate Endpoint
+///
+/// ## Overview
+///
+/// Returns the estimated cardinality for a metric with a given tag, percentile and number of aggregations configuration, before creating it with a tag configuration.
+///
+/// Endpoint Format: `/v2/metrics/{metric_name}/estimate` [GET]
+///
+/// ## Arguments
+///
+/// - metric_name [required]
+///    - type: `string`
+///    - description: The name of the metric.
+///
+/// **Query Strings**
+///
+/// - filter[groups] [type: string] - Filtered tag keys that the metric is configured to query with.
+/// - filter[hours_ago] [type: int32] - The number of hours of look back (from now) to estimate cardinality with. Estimates are based on historical data, and unspecified fields default to the minimum 49 hours.
+/// - filter[num_aggregations] [type: int32] - The number of aggregations that a count, rate, or gauge metric is configured to use. Max number of aggregation combos is 9.
+/// - filter[pct] [type: boolean] - A boolean, for distribution metrics only, to estimate cardinality if the metric includes additional percentile aggregators.
+/// - filter[timespan_h] [type: int32] - A window, in hours, from the look back to estimate cardinality with.
+///
+/// ## Response
+///
+/// One of: [200, 400, 403, 404, 429]
+///
+/// - data [type: object] - Object for a metric cardinality estimate.
+///    - type [type: enum] - Allowed enum values: metric_cardinality_estimate
+///    - id [type: string] - The metric name for this resource.
+///    - attributes [type: object]
+///       - estimate_type [type: enum] - Estimate type based on the queried configuration. Allowed enum values: count_or_gauge, distribution, percentile
+///       - estimated_at [type: date-time] - Timestamp when the cardinality estimate was requested.
+///       - estimated_output_series [type: int64] - Estimated cardinality of the metric based on the queried configuration.
+///
+/// #### Example
+///
+/// ```json
+/// {
+///     "data": {
+///         "type": "metric_cardinality_estimate",
+///         "id": "rpc.latency",
+///         "attributes": {
+///             "estimate_type": "count_or_gauge",
+///             "estimated_at": "2022-04-27T09:48:37.463835Z",
+///             "estimated_output_series": 50
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Estimate {
+    /// The metric name
+    pub name: Option<String>,
+    /// The tag keys the metric would be configured to query with
+    pub groups: Vec<String>,
+    /// The number of hours of look back to estimate with
+    pub hours_ago: Option<i32>,
+    /// The number of aggregations a count, rate or gauge metric would be configured to use
+    pub num_aggregations: Option<i32>,
+    /// Whether a distribution metric would include percentile aggregations
+    pub percentiles: Option<bool>,
+    /// The window in hours from the look back to estimate with
+    pub timespan_hours: Option<i32>,
+    /// The api version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// A Metric Estimate Response
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct EstimateResponse {
+    /// The cardinality estimate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<EstimateData>,
+}
+
+/// The cardinality estimate of a metric
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct EstimateData {
+    /// The resource type
+    #[serde(rename = "type")]
+    pub type_: EstimateResourceType,
+    /// The metric name
+    pub id: String,
+    /// The estimate
+    #[serde(default)]
+    pub attributes: EstimateAttributes,
+}
+
+/// The cardinality estimate of a queried configuration
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct EstimateAttributes {
+    /// The estimate type based on the queried configuration
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate_type: Option<EstimateType>,
+    /// When the estimate was requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimated_at: Option<DateTime<Utc>>,
+    /// The estimated cardinality of the metric
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimated_output_series: Option<i64>,
+}
+
+/// The Metric Estimate resource type
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EstimateResourceType {
+    /// A metric cardinality estimate
+    MetricCardinalityEstimate,
+}
+
+/// The estimate types
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EstimateType {
+    /// A count or gauge metric estimate
+    CountOrGauge,
+    /// A distribution metric estimate
+    Distribution,
+    /// A distribution metric estimate with percentile aggregations
+    Percentile,
+}
+
+impl Default for Estimate {
+    fn default() -> Self {
+        Self {
+            name: None,
+            groups: vec![],
+            hours_ago: None,
+            num_aggregations: None,
+            percentiles: None,
+            timespan_hours: None,
+            version: types::version::ApiVersion::V2,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for Estimate {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V2 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl Estimate {
+    /// Instantiates a new estimate retrieval for the metric
+    pub fn new(metric_name: &str) -> Self {
+        tracing::info!(target: "/v2/metrics/{metric_name}/estimate", "Estimate Route Created");
+        Self {
+            name: Some(metric_name.to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the Metric Name
+    pub fn set_metric_name(mut self, metric_name: String) -> Self {
+        tracing::info!(target: "/v2/metrics/{metric_name}/estimate", "Estimate Metric Name Set to {}", metric_name);
+        self.name = Some(metric_name);
+        self
+    }
+
+    /// Sets the tag keys the metric would be configured to query with
+    pub fn with_groups(mut self, groups: Vec<&str>) -> Self {
+        self.groups = groups.into_iter().map(String::from).collect();
+        self
+    }
+
+    /// Sets the number of hours of look back to estimate with
+    pub fn with_hours_ago(mut self, hours_ago: i32) -> Self {
+        self.hours_ago = Some(hours_ago);
+        self
+    }
+
+    /// Sets the number of aggregations a count, rate or gauge metric would be configured to use
+    pub fn with_num_aggregations(mut self, num_aggregations: i32) -> Self {
+        self.num_aggregations = Some(num_aggregations);
+        self
+    }
+
+    /// Sets whether a distribution metric would include percentile aggregations
+    pub fn with_percentiles(mut self, percentiles: bool) -> Self {
+        self.percentiles = Some(percentiles);
+        self
+    }
+
+    /// Sets the window in hours from the look back to estimate with
+    pub fn with_timespan_hours(mut self, timespan_hours: i32) -> Self {
+        self.timespan_hours = Some(timespan_hours);
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// The query string parameters, omitting unset filters
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![];
+        if !self.groups.is_empty() {
+            query.push(("filter[groups]", self.groups.join(",")));
+        }
+        if let Some(hours_ago) = self.hours_ago {
+            query.push(("filter[hours_ago]", hours_ago.to_string()));
+        }
+        if let Some(num_aggregations) = self.num_aggregations {
+            query.push(("filter[num_aggregations]", num_aggregations.to_string()));
+        }
+        if let Some(percentiles) = self.percentiles {
+            query.push(("filter[pct]", percentiles.to_string()));
+        }
+        if let Some(timespan_hours) = self.timespan_hours {
+            query.push(("filter[timespan_h]", timespan_hours.to_string()));
+        }
+        query
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v2/metrics/{metric_name}/estimate")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<EstimateResponse> for Estimate {
+    /// The route path
+    fn path(&self) -> String {
+        format!(
+            "v2/metrics/{}/estimate",
+            self.name.as_deref().unwrap_or_default()
+        )
+    }
+
+    /// Route does nothing here since the route path is set with the metric name
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<EstimateResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        if self.name.as_deref().unwrap_or_default().is_empty() {
+            tracing::error!(target: "/v2/metrics/{metric_name}/estimate", "Missing metric name");
+            return Err(Error::Validation(String::from("missing metric name")));
+        }
+        let path = self.path();
+        tracing::info!(target: "/v2/metrics/{metric_name}/estimate", "Sending Request to {}", path);
+        let req_builder = client
+            .request(reqwest::Method::GET, &path)
+            .query(&self.query())
+            .headers(self.headers);
+        client.send(req_builder).await
+    }
+}
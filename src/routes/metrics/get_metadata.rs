@@ -0,0 +1,283 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+use super::tags::MetricType;
+
+/// Get Metric Metadata Endpoint
+///
+/// ## Overview
+///
+/// Get metadata about a specific metric. This endpoint requires the `metrics_read` authorization [scope](https://docs.datadoghq.com/api/latest/scopes/#metrics).
+///
+/// Endpoint Format: `/v1/metrics/{metric_name}` [GET]
+///
+/// ## Arguments
+///
+/// - metric_name [required]
+///    - type: `string`
+///    - description: Name of the metric for which to get metadata.
+///
+/// ## Response
+///
+/// One of: [200, 400, 403, 404, 429]
+///
+/// - description [type: string] - Metric description.
+/// - integration [type: string] - Name of the integration that sent the metric if applicable.
+/// - per_unit [type: string] - Per unit of the metric such as `second` in `bytes per second`.
+/// - short_name [type: string] - A more human-readable and abbreviated version of the metric name.
+/// - statsd_interval [type: int64] - StatsD flush interval of the metric in seconds if applicable.
+/// - type [type: string] - Metric type such as `gauge` or `rate`.
+/// - unit [type: string] - Primary unit of the metric such as `byte` or `operation`.
+///
+/// #### Example
+///
+/// ```json
+/// {
+///     "description": "The number of bytes sent per second",
+///     "integration": null,
+///     "per_unit": "second",
+///     "short_name": "bytes sent",
+///     "statsd_interval": 10,
+///     "type": "gauge",
+///     "unit": "byte"
+/// }
+/// ```
+#[derive(Debug)]
+pub struct GetMetadata {
+    /// The metric name
+    pub name: Option<String>,
+    /// The api version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// The metadata of a metric
+///
+/// Unset fields are left out of an update, leaving the current metadata in place.
+///
+/// ## Usage
+///
+/// ```rust
+/// use ddog::prelude::*;
+///
+/// let metadata = get_metadata::MetricMetadata::new()
+///     .with_description("The number of bytes sent per second")
+///     .with_unit("byte")
+///     .with_per_unit("second")
+///     .with_metric_type(tags::MetricType::Gauge);
+/// assert_eq!(
+///     serde_json::to_value(&metadata).unwrap(),
+///     serde_json::json!({
+///         "description": "The number of bytes sent per second",
+///         "per_unit": "second",
+///         "type": "gauge",
+///         "unit": "byte"
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct MetricMetadata {
+    /// Metric description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Name of the integration that sent the metric, read only
+    #[serde(default, skip_serializing)]
+    pub integration: Option<String>,
+    /// Per unit of the metric such as `second` in `bytes per second`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub per_unit: Option<String>,
+    /// A more human-readable and abbreviated version of the metric name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_name: Option<String>,
+    /// StatsD flush interval of the metric in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statsd_interval: Option<i64>,
+    /// Metric type
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<MetricType>,
+    /// Primary unit of the metric such as `byte` or `operation`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+}
+
+impl MetricMetadata {
+    /// Creates empty metric metadata
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the description
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Sets the per unit
+    pub fn with_per_unit(mut self, per_unit: &str) -> Self {
+        self.per_unit = Some(per_unit.to_string());
+        self
+    }
+
+    /// Sets the short name
+    pub fn with_short_name(mut self, short_name: &str) -> Self {
+        self.short_name = Some(short_name.to_string());
+        self
+    }
+
+    /// Sets the StatsD flush interval in seconds
+    pub fn with_statsd_interval(mut self, statsd_interval: i64) -> Self {
+        self.statsd_interval = Some(statsd_interval);
+        self
+    }
+
+    /// Sets the metric type
+    pub fn with_metric_type(mut self, metric_type: MetricType) -> Self {
+        self.type_ = Some(metric_type);
+        self
+    }
+
+    /// Sets the unit
+    pub fn with_unit(mut self, unit: &str) -> Self {
+        self.unit = Some(unit.to_string());
+        self
+    }
+}
+
+impl Default for GetMetadata {
+    fn default() -> Self {
+        Self {
+            name: None,
+            version: types::version::ApiVersion::V1,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for GetMetadata {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V1 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl GetMetadata {
+    /// Instantiates a new metadata retrieval for the metric
+    pub fn new(metric_name: &str) -> Self {
+        tracing::info!(target: "/v1/metrics/{metric_name}", "Get Metadata Route Created");
+        Self {
+            name: Some(metric_name.to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the Metric Name
+    pub fn set_metric_name(mut self, metric_name: String) -> Self {
+        tracing::info!(target: "/v1/metrics/{metric_name}", "Metadata Metric Name Set to {}", metric_name);
+        self.name = Some(metric_name);
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v1/metrics/{metric_name}")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<MetricMetadata> for GetMetadata {
+    /// The route path
+    fn path(&self) -> String {
+        format!("v1/metrics/{}", self.name.as_deref().unwrap_or_default())
+    }
+
+    /// Route does nothing here since the route path is set with the metric name
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<MetricMetadata>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        if self.name.as_deref().unwrap_or_default().is_empty() {
+            tracing::error!(target: "/v1/metrics/{metric_name}", "Missing metric name");
+            return Err(Error::Validation(String::from("missing metric name")));
+        }
+        let path = self.path();
+        tracing::info!(target: "/v1/metrics/{metric_name}", "Sending Request to {}", path);
+        let req_builder = client
+            .request(reqwest::Method::GET, &path)
+            .headers(self.headers);
+        client.send(req_builder).await
+    }
+}
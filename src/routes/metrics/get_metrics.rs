@@ -1,9 +1,11 @@
 use std::str::FromStr;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures_util::{stream, Stream, StreamExt};
 use serde::{Deserialize, Serialize};
 
-use crate::types;
+use crate::{client, types, Error};
 
 /// Metrics Get Endpoint
 ///
@@ -22,6 +24,8 @@ use crate::types;
 /// - host [type: string] - Hostname for filtering the list of metrics returned. If set, metrics retrieved are those with the corresponding hostname tag.
 /// - tag_filter [type: string] - Filter metrics that have been submitted with the given tags. Supports boolean and wildcard expressions. Cannot be combined with other filters.
 ///
+/// Unset or empty `host` and `tag_filter` parameters are omitted from the query string.
+///
 ///
 /// ## Response
 ///
@@ -43,20 +47,22 @@ use crate::types;
 /// ```
 #[derive(Debug)]
 pub struct GetMetrics {
-    /// Seconds since the Unix epoch.
-    pub from: usize,
+    /// The time from which metrics are considered active
+    pub from: DateTime<Utc>,
     /// Host name
     /// Used for filtering the list of metrics
     pub host: Option<String>,
     /// Tag filter
     /// Used for filtering the list of metrics
-    pub tag_filter: Option<String>,
+    pub tag_filter: Option<TagFilter>,
     /// The api version
     pub version: types::version::ApiVersion,
     /// Request Headers
     pub headers: reqwest::header::HeaderMap,
     /// Request Body
     pub body: reqwest::Body,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
 }
 
 /// A GetMetrics Response
@@ -68,46 +74,173 @@ pub struct GetMetricsResponse {
     pub metrics: Vec<String>,
 }
 
+/// A tag filter expression
+///
+/// Tags are combined with `AND`, `OR` and `NOT`, nested expressions are parenthesized when rendered.
+///
+/// ## Usage
+///
+/// ```rust
+/// use ddog::prelude::*;
+/// use get_metrics::TagFilter;
+///
+/// let filter = TagFilter::tag("env", "prod")
+///     .and(TagFilter::tag("service", "web").or(TagFilter::tag("service", "api")))
+///     .and(!TagFilter::tag("host", "canary-*"));
+/// assert_eq!(
+///     filter.to_string(),
+///     "env:prod AND (service:web OR service:api) AND NOT host:canary-*"
+/// );
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum TagFilter {
+    /// A `key:value` tag, the value may contain wildcards
+    Tag {
+        /// The tag key
+        key: String,
+        /// The tag value
+        value: String,
+    },
+    /// A raw filter expression, rendered as is and parenthesized when combined with other filters
+    Raw(String),
+    /// Every expression must match
+    And(Vec<TagFilter>),
+    /// Any expression must match
+    Or(Vec<TagFilter>),
+    /// The expression must not match
+    Not(Box<TagFilter>),
+}
+
+impl TagFilter {
+    /// Creates a `key:value` tag filter
+    pub fn tag(key: &str, value: &str) -> Self {
+        TagFilter::Tag {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    /// Combines the filter with another which must also match
+    pub fn and(self, other: TagFilter) -> Self {
+        match self {
+            TagFilter::And(mut filters) => {
+                filters.push(other);
+                TagFilter::And(filters)
+            }
+            filter => TagFilter::And(vec![filter, other]),
+        }
+    }
+
+    /// Combines the filter with another of which either must match
+    pub fn or(self, other: TagFilter) -> Self {
+        match self {
+            TagFilter::Or(mut filters) => {
+                filters.push(other);
+                TagFilter::Or(filters)
+            }
+            filter => TagFilter::Or(vec![filter, other]),
+        }
+    }
+
+    /// Writes the filter as an operand of AND, OR or NOT, parenthesizing compound expressions
+    fn fmt_operand(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TagFilter::And(filters) | TagFilter::Or(filters) if filters.len() > 1 => {
+                write!(f, "({})", self)
+            }
+            // A raw expression may hold its own operators
+            TagFilter::Raw(expression) if expression.trim().contains(char::is_whitespace) => {
+                write!(f, "({})", expression)
+            }
+            filter => write!(f, "{}", filter),
+        }
+    }
+}
+
+impl std::ops::Not for TagFilter {
+    type Output = TagFilter;
+
+    fn not(self) -> Self::Output {
+        TagFilter::Not(Box::new(self))
+    }
+}
+
+impl std::fmt::Display for TagFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (filters, operator) = match self {
+            TagFilter::Tag { key, value } => return write!(f, "{}:{}", key, value),
+            TagFilter::Raw(expression) => return write!(f, "{}", expression),
+            TagFilter::Not(filter) => {
+                write!(f, "NOT ")?;
+                return filter.fmt_operand(f);
+            }
+            TagFilter::And(filters) => (filters, " AND "),
+            TagFilter::Or(filters) => (filters, " OR "),
+        };
+        for (i, filter) in filters.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", operator)?;
+            }
+            filter.fmt_operand(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&str> for TagFilter {
+    fn from(expression: &str) -> Self {
+        TagFilter::Raw(expression.to_string())
+    }
+}
+
+impl From<String> for TagFilter {
+    fn from(expression: String) -> Self {
+        TagFilter::Raw(expression)
+    }
+}
+
 impl Default for GetMetrics {
     fn default() -> Self {
         Self {
-            from: 0,
+            from: DateTime::<Utc>::UNIX_EPOCH,
             host: None,
             tag_filter: None,
             version: types::version::ApiVersion::V2,
             headers: reqwest::header::HeaderMap::new(),
             body: reqwest::Body::from(""),
+            client: None,
         }
     }
 }
 
 impl TryFrom<types::version::ApiVersion> for GetMetrics {
-    type Error = &'static str;
+    type Error = Error;
 
     fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
         match v {
             types::version::ApiVersion::V1 => Ok(Self::default()),
-            _ => Err("Unsupported API Version"),
+            v => Err(Error::UnsupportedVersion(v)),
         }
     }
 }
 
 impl GetMetrics {
     /// Instantiates a new Metric
-    pub fn new(from: usize, host: Option<String>, tag_filter: Option<String>) -> Self {
+    pub fn new(from: DateTime<Utc>) -> Self {
         tracing::info!(target: "/v1/metrics", "Route Created");
         Self {
             from,
-            host,
-            tag_filter,
+            host: None,
+            tag_filter: None,
             version: types::version::ApiVersion::V1,
             headers: reqwest::header::HeaderMap::new(),
             body: reqwest::Body::from(""),
+            client: None,
         }
     }
 
     /// Set the Metrics From
-    pub fn set_from(mut self, from: usize) -> Self {
+    pub fn set_from(mut self, from: DateTime<Utc>) -> Self {
         tracing::info!(target: "/v1/metrics", "Metrics from set to {}", from);
         self.from = from;
         self
@@ -121,12 +254,139 @@ impl GetMetrics {
     }
 
     /// Set the Metrics Tag Filter
-    pub fn set_tag_filter(mut self, tag_filter: String) -> Self {
+    ///
+    /// Accepts a [TagFilter] expression or a raw filter string.
+    pub fn set_tag_filter<F: Into<TagFilter>>(mut self, tag_filter: F) -> Self {
+        let tag_filter = tag_filter.into();
         tracing::info!(target: "/v1/metrics", "Metrics tag filter set to {}", tag_filter);
         self.tag_filter = Some(tag_filter);
         self
     }
 
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// The query string parameters, omitting an unset or empty host and tag filter
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![("from", self.from.timestamp().to_string())];
+        if let Some(host) = self.host.as_deref().filter(|host| !host.is_empty()) {
+            query.push(("host", host.to_string()));
+        }
+        if let Some(tag_filter) = &self.tag_filter {
+            let tag_filter = tag_filter.to_string();
+            if !tag_filter.is_empty() {
+                query.push(("tag_filter", tag_filter));
+            }
+        }
+        query
+    }
+
+    /// Streams the name of every actively reporting metric
+    ///
+    /// The endpoint is not paginated: a single request is sent when the stream is first polled and the metrics of its response are yielded one by one.
+    /// A failed request yields its error and ends the stream.
+    ///
+    /// ## Usage
+    ///
+    /// ```rust
+    /// use ddog::prelude::*;
+    /// use futures_util::StreamExt;
+    ///
+    /// async {
+    ///     let mut client = Client::new()
+    ///         .with_api_key("<api_key>")
+    ///         .with_application_key("<application_key>");
+    ///     let metrics = client
+    ///         .v1()
+    ///         .get_metrics(chrono::Utc::now() - chrono::Duration::hours(1))?
+    ///         .set_tag_filter(get_metrics::TagFilter::tag("env", "prod"))
+    ///         .stream();
+    ///     futures_util::pin_mut!(metrics);
+    ///     while let Some(metric) = metrics.next().await {
+    ///         match metric {
+    ///             Ok(metric) => println!("{}", metric),
+    ///             Err(e) => println!("Failed to get metrics: {}", e),
+    ///         }
+    ///     }
+    ///     Ok::<(), Error>(())
+    /// };
+    /// ```
+    pub fn stream(self) -> impl Stream<Item = Result<String, Error>> {
+        stream::once(types::route::Route::<GetMetricsResponse>::execute(self)).flat_map(|res| {
+            stream::iter(match res {
+                Ok(response) => response.into_inner().metrics.into_iter().map(Ok).collect(),
+                Err(e) => vec![Err(e)],
+            })
+        })
+    }
+
+    /// Streams the actively reporting metrics of each host in turn
+    ///
+    /// One request is sent per host, lazily as the stream is polled, and a failed request does not end the stream.
+    /// Each item pairs the host with its result, so failed hosts can be told apart.
+    /// Any host set on the route is replaced by each of the hosts, the other filters are kept.
+    ///
+    /// ## Usage
+    ///
+    /// ```rust
+    /// use ddog::prelude::*;
+    /// use futures_util::StreamExt;
+    ///
+    /// async {
+    ///     let mut client = Client::new()
+    ///         .with_api_key("<api_key>")
+    ///         .with_application_key("<application_key>");
+    ///     let hosts = client
+    ///         .v1()
+    ///         .get_metrics(chrono::Utc::now() - chrono::Duration::hours(1))?
+    ///         .per_host(vec!["web-1", "web-2"]);
+    ///     futures_util::pin_mut!(hosts);
+    ///     while let Some((host, result)) = hosts.next().await {
+    ///         match result {
+    ///             Ok(response) => println!("{}: {} metrics", host, response.metrics.len()),
+    ///             Err(e) => println!("Failed to get metrics of {}: {}", host, e),
+    ///         }
+    ///     }
+    ///     Ok::<(), Error>(())
+    /// };
+    /// ```
+    pub fn per_host<I, S>(
+        self,
+        hosts: I,
+    ) -> impl Stream<Item = (String, Result<GetMetricsResponse, Error>)>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let hosts = hosts.into_iter().map(Into::into).collect::<Vec<String>>();
+        stream::iter(hosts).then(move |host| {
+            let route = GetMetrics {
+                from: self.from,
+                host: Some(host.clone()),
+                tag_filter: self.tag_filter.clone(),
+                version: self.version,
+                headers: self.headers.clone(),
+                body: reqwest::Body::from(""),
+                client: self.client.clone(),
+            };
+            async move {
+                let result = types::route::Route::<GetMetricsResponse>::execute(route)
+                    .await
+                    .map(types::response::Response::into_inner);
+                (host, result)
+            }
+        })
+    }
+
     /// Creates a target identifier for logging
     pub fn target() -> String {
         String::from("v1/metrics")
@@ -198,54 +458,14 @@ impl types::route::Route<GetMetricsResponse> for GetMetrics {
     }
 
     /// Executes the api request
-    async fn execute(
-        self,
-    ) -> (
-        reqwest::StatusCode,
-        Result<GetMetricsResponse, Option<reqwest::Error>>,
-    ) {
-        let url = format!(
-            "{}/{}?from={}&host={}&tag_filter={}",
-            types::base::BASE_API_URL,
-            self.path(),
-            self.from,
-            self.host.unwrap_or_else(|| "".to_string()),
-            self.tag_filter.unwrap_or_else(|| "".to_string())
-        );
-        tracing::info!(target: "/v1/metrics", "Sending Request to {}", url);
-
-        let mut req_builder: reqwest::RequestBuilder = reqwest::Client::new().get(url);
-        req_builder = req_builder.headers(self.headers.clone());
-
-        let response = req_builder.send().await;
-
-        match response {
-            Ok(r) => {
-                let status_code = r.status();
-                if status_code != reqwest::StatusCode::from_u16(200).unwrap() {
-                    tracing::error!(target: "/v1/metrics", "Received non-ok status code {:?}", status_code);
-                    return (status_code, Err(None));
-                }
-
-                // Parse the response body as Json
-                match r.json::<GetMetricsResponse>().await {
-                    Ok(s) => {
-                        tracing::info!(target: "/v1/metrics", "Succesfully parsed response from tags route {:?}", s);
-                        return (status_code, Ok(s));
-                    }
-                    Err(e) => {
-                        tracing::error!(target: "/v1/metrics", "Failed to parse response as a GetMetricsResponse with error {:?}", e);
-                        return (reqwest::StatusCode::BAD_REQUEST, Err(Some(e)));
-                    }
-                }
-            }
-            Err(e) => {
-                tracing::error!(target: "/v1/metrics", "Request failed with error {:?}", e);
-                return (
-                    e.status().unwrap_or(reqwest::StatusCode::BAD_REQUEST),
-                    Err(Some(e)),
-                );
-            }
-        }
+    async fn execute(self) -> Result<types::response::Response<GetMetricsResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        let path = self.path();
+        tracing::info!(target: "/v1/metrics", "Sending Request to {} with {:?}", path, self.query());
+        let req_builder = client
+            .request(reqwest::Method::GET, &path)
+            .query(&self.query())
+            .headers(self.headers);
+        client.send(req_builder).await
     }
 }
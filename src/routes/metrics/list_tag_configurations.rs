@@ -0,0 +1,312 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+use super::tags::{MetricTag, TagsResponseAttributes};
+
+/// List Tag Configurations Metrics Endpoint
+///
+/// ## Overview
+///
+/// Returns all metrics that can be configured in the Metrics Summary page or with Metrics without Limits, optionally filtered.
+///
+/// Endpoint Format: `/v2/metrics` [GET]
+///
+/// ## Arguments
+///
+/// **Query Strings**
+///
+/// - filter[configured] [type: boolean] - Filter custom metrics that have configured tags.
+/// - filter[tags_configured] [type: string] - Filter tag configurations by configured tags.
+/// - filter[metric_type] [type: enum] - Filter metrics by metric type. Allowed enum values: non_distribution, distribution
+/// - filter[include_percentiles] [type: boolean] - Filter distributions with additional percentile aggregations enabled or disabled.
+/// - filter[queried] [type: boolean] - Filter custom metrics that have or have not been queried in the specified window.
+/// - filter[tags] [type: string] - Filter metrics that have been submitted with the given tags. Supports boolean and wildcard expressions.
+/// - window[seconds] [type: int64] - The number of seconds of look back (from now) to apply to a `filter[tags]` or `filter[queried]` query.
+///
+/// ## Response
+///
+/// One of: [200, 400, 403, 429]
+///
+/// - data [type: object[]] - Array of metrics and metric tag configurations. Metrics without a tag configuration have the `metrics` type and no attributes.
+///
+/// #### Example
+///
+/// ```json
+/// {
+///     "data": [
+///         { "type": "metrics", "id": "system.cpu.idle" },
+///         {
+///             "type": "manage_tags",
+///             "id": "rpc.latency",
+///             "attributes": {
+///                 "created_at": "2020-03-25T09:48:37.463835Z",
+///                 "include_percentiles": false,
+///                 "metric_type": "count",
+///                 "modified_at": "2020-03-25T09:48:37.463835Z",
+///                 "tags": [ "app", "datacenter" ]
+///             }
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ListTagConfigurations {
+    /// Only return custom metrics with or without configured tags
+    pub configured: Option<bool>,
+    /// Only return tag configurations with the configured tags
+    pub tags_configured: Option<String>,
+    /// Only return metrics of the metric type
+    pub metric_type: Option<MetricTypeFilter>,
+    /// Only return distributions with or without percentile aggregations
+    pub include_percentiles: Option<bool>,
+    /// Only return custom metrics that have or have not been queried in the window
+    pub queried: Option<bool>,
+    /// Only return metrics submitted with the tags, as a boolean or wildcard expression
+    pub tags: Option<String>,
+    /// The number of seconds of look back of the tags and queried filters
+    pub window_seconds: Option<i64>,
+    /// The api version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// A List Tag Configurations Response
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct ListTagConfigurationsResponse {
+    /// The metrics and metric tag configurations
+    #[serde(default)]
+    pub data: Vec<TagConfigurationData>,
+}
+
+/// A metric or metric tag configuration
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct TagConfigurationData {
+    /// The resource type, `manage_tags` for a tag configuration and `metrics` otherwise
+    #[serde(rename = "type")]
+    pub type_: MetricTag,
+    /// The metric name
+    pub id: String,
+    /// The tag configuration, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<TagsResponseAttributes>,
+}
+
+/// The metric type filter of a tag configuration listing
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricTypeFilter {
+    /// Count, gauge and rate metrics
+    NonDistribution,
+    /// Distribution metrics
+    Distribution,
+}
+
+impl Default for ListTagConfigurations {
+    fn default() -> Self {
+        Self {
+            configured: None,
+            tags_configured: None,
+            metric_type: None,
+            include_percentiles: None,
+            queried: None,
+            tags: None,
+            window_seconds: None,
+            version: types::version::ApiVersion::V2,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for ListTagConfigurations {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V2 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl ListTagConfigurations {
+    /// Instantiates a new tag configuration listing
+    pub fn new() -> Self {
+        tracing::info!(target: "/v2/metrics", "List Tag Configurations Route Created");
+        Self::default()
+    }
+
+    /// Only return custom metrics with or without configured tags
+    pub fn with_configured(mut self, configured: bool) -> Self {
+        self.configured = Some(configured);
+        self
+    }
+
+    /// Only return tag configurations with the configured tags
+    pub fn with_tags_configured(mut self, tags_configured: &str) -> Self {
+        self.tags_configured = Some(tags_configured.to_string());
+        self
+    }
+
+    /// Only return metrics of the metric type
+    pub fn with_metric_type(mut self, metric_type: MetricTypeFilter) -> Self {
+        self.metric_type = Some(metric_type);
+        self
+    }
+
+    /// Only return distributions with or without percentile aggregations
+    pub fn with_include_percentiles(mut self, include_percentiles: bool) -> Self {
+        self.include_percentiles = Some(include_percentiles);
+        self
+    }
+
+    /// Only return custom metrics that have or have not been queried in the look back window
+    pub fn with_queried(mut self, queried: bool) -> Self {
+        self.queried = Some(queried);
+        self
+    }
+
+    /// Only return metrics submitted with the tags, as a boolean or wildcard expression
+    pub fn with_tags(mut self, tags: &str) -> Self {
+        self.tags = Some(tags.to_string());
+        self
+    }
+
+    /// Sets the number of seconds of look back of the tags and queried filters
+    pub fn with_window_seconds(mut self, window_seconds: i64) -> Self {
+        self.window_seconds = Some(window_seconds);
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// The query string parameters, omitting unset filters
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![];
+        if let Some(configured) = self.configured {
+            query.push(("filter[configured]", configured.to_string()));
+        }
+        if let Some(tags_configured) = &self.tags_configured {
+            query.push(("filter[tags_configured]", tags_configured.clone()));
+        }
+        if let Some(metric_type) = self.metric_type {
+            let metric_type = match metric_type {
+                MetricTypeFilter::NonDistribution => "non_distribution",
+                MetricTypeFilter::Distribution => "distribution",
+            };
+            query.push(("filter[metric_type]", metric_type.to_string()));
+        }
+        if let Some(include_percentiles) = self.include_percentiles {
+            query.push((
+                "filter[include_percentiles]",
+                include_percentiles.to_string(),
+            ));
+        }
+        if let Some(queried) = self.queried {
+            query.push(("filter[queried]", queried.to_string()));
+        }
+        if let Some(tags) = &self.tags {
+            query.push(("filter[tags]", tags.clone()));
+        }
+        if let Some(window_seconds) = self.window_seconds {
+            query.push(("window[seconds]", window_seconds.to_string()));
+        }
+        query
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v2/metrics")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<ListTagConfigurationsResponse> for ListTagConfigurations {
+    /// The route path
+    fn path(&self) -> String {
+        String::from("v2/metrics")
+    }
+
+    /// Route does nothing here since the route path is fixed
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(
+        self,
+    ) -> Result<types::response::Response<ListTagConfigurationsResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        let path = self.path();
+        tracing::info!(target: "/v2/metrics", "Sending Request to {}", path);
+        let req_builder = client
+            .request(reqwest::Method::GET, &path)
+            .query(&self.query())
+            .headers(self.headers);
+        client.send(req_builder).await
+    }
+}
@@ -17,6 +17,46 @@
 /// `v2/metrics/{metric_name}/tags` Endpoint [POST]
 pub mod tags;
 
+/// Metric to get a tag configuration
+/// `v2/metrics/{metric_name}/tags` Endpoint [GET]
+pub mod get_tags;
+
+/// Metric to update a tag configuration
+/// `v2/metrics/{metric_name}/tags` Endpoint [PATCH]
+pub mod update_tags;
+
+/// Metric to delete a tag configuration
+/// `v2/metrics/{metric_name}/tags` Endpoint [DELETE]
+pub mod delete_tags;
+
+/// Metric to list tag configurations
+/// `v2/metrics` Endpoint [GET]
+pub mod list_tag_configurations;
+
+/// Metric to configure tags of all metrics matching a prefix
+/// `v2/metrics/config/bulk-tags` Endpoint [POST]
+pub mod bulk_tags;
+
+/// Metric to delete tag configurations of all metrics matching a prefix
+/// `v2/metrics/config/bulk-tags` Endpoint [DELETE]
+pub mod delete_bulk_tags;
+
+/// Metric to view distinct, ingested and indexed volumes
+/// `v2/metrics/{metric_name}/volumes` Endpoint [GET]
+pub mod volumes;
+
+/// Metric to view indexed and ingested tags
+/// `v2/metrics/{metric_name}/all-tags` Endpoint [GET]
+pub mod all_tags;
+
+/// Metric to view actively queried tags and aggregations
+/// `v2/metrics/{metric_name}/active-configurations` Endpoint [GET]
+pub mod active_configurations;
+
+/// Metric to estimate the cardinality of a tag configuration
+/// `v2/metrics/{metric_name}/estimate` Endpoint [GET]
+pub mod estimate;
+
 /// Metric to post series data
 /// `v2/series` Endpoint [POST]
 pub mod series;
@@ -29,12 +69,47 @@ pub mod distribution;
 /// `v1/metrics` Endpoint [GET]
 pub mod get_metrics;
 
+/// Metric to get metric metadata
+/// `v1/metrics/{metric_name}` Endpoint [GET]
+pub mod get_metadata;
+
+/// Metric to edit metric metadata
+/// `v1/metrics/{metric_name}` Endpoint [PUT]
+pub mod update_metadata;
+
+/// Metric to query timeseries points
+/// `v1/query` Endpoint [GET]
+pub mod query_metrics;
+
+/// Metric to query timeseries with formulas
+/// `v2/query/timeseries` Endpoint [POST]
+pub mod query_timeseries;
+
+/// Metric to query scalar values with formulas
+/// `v2/query/scalar` Endpoint [POST]
+pub mod query_scalar;
+
 /// Re-exported prelude of all metrics-related endpoints
 pub mod prelude {
     pub use super::{
+        active_configurations::{self, *},
+        all_tags::{self, *},
+        bulk_tags::{self, *},
+        delete_bulk_tags::{self, *},
+        delete_tags::{self, *},
         distribution::{self, *},
+        estimate::{self, *},
+        get_metadata::{self, *},
         get_metrics::{self, *},
+        get_tags::{self, *},
+        list_tag_configurations::{self, *},
+        query_metrics::{self, *},
+        query_scalar::{self, *},
+        query_timeseries::{self, *},
         series::{self, *},
         tags::{self, *},
+        update_metadata::{self, *},
+        update_tags::{self, *},
+        volumes::{self, *},
     };
 }
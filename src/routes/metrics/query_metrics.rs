@@ -0,0 +1,328 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+/// Metrics Query Endpoint
+///
+/// ## Overview
+///
+/// Query timeseries points. This endpoint requires the `timeseries_query` authorization [scope](https://docs.datadoghq.com/api/latest/scopes/#metrics).
+///
+/// Endpoint Format: `/v1/query` [GET]
+///
+/// ## Arguments
+///
+/// **Query Strings**
+///
+/// - from [required] [type: int64] - Start of the queried time period, seconds since the Unix epoch.
+/// - to [required] [type: int64] - End of the queried time period, seconds since the Unix epoch.
+/// - query [required] [type: string] - Query string, ie `avg:system.cpu.idle{*}by{host}`.
+///
+/// ## Response
+///
+/// One of: [200, 400, 403, 429]
+///
+/// - error [type: string] - Message indicating the errors if status is not `ok`.
+/// - from_date [type: int64] - Start of requested time window, milliseconds since Unix epoch.
+/// - group_by [type: string[]] - List of tag keys on which to group.
+/// - message [type: string] - Message indicating `success` if status is `ok`.
+/// - query [type: string] - Query string
+/// - res_type [type: string] - Type of response.
+/// - series [type: object[]] - List of timeseries queried.
+/// - status [type: string] - Status of the query.
+/// - to_date [type: int64] - End of requested time window, milliseconds since Unix epoch.
+///
+/// #### Example
+///
+/// Below is an example of a response from the metrics query endpoint.
+///
+/// ```json
+/// {
+///     "from_date": 1575317847000,
+///     "group_by": [ "host" ],
+///     "query": "avg:system.cpu.idle{*}by{host}",
+///     "res_type": "time_series",
+///     "series": [
+///         {
+///             "aggr": "avg",
+///             "display_name": "system.cpu.idle",
+///             "end": 1575317860000,
+///             "expression": "avg:system.cpu.idle{host:web-1}",
+///             "interval": 20,
+///             "length": 2,
+///             "metric": "system.cpu.idle",
+///             "pointlist": [ [ 1575317847000.0, 92.5 ], [ 1575317867000.0, null ] ],
+///             "scope": "host:web-1",
+///             "start": 1575317847000,
+///             "tag_set": [ "host:web-1" ],
+///             "unit": [ { "family": "percentage", "name": "percent", "plural": "percent", "scale_factor": 1.0, "short_name": "%" }, null ]
+///         }
+///     ],
+///     "status": "ok",
+///     "to_date": 1575321447000
+/// }
+/// ```
+#[derive(Debug)]
+pub struct QueryMetrics {
+    /// Start of the queried time period, seconds since the Unix epoch
+    pub from: i64,
+    /// End of the queried time period, seconds since the Unix epoch
+    pub to: i64,
+    /// The metrics query
+    pub query: Option<String>,
+    /// Version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// A QueryMetrics Response
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct QueryMetricsResponse {
+    /// Message indicating the errors if status is not `ok`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Start of requested time window, milliseconds since Unix epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_date: Option<i64>,
+    /// List of tag keys on which to group
+    #[serde(default)]
+    pub group_by: Vec<String>,
+    /// Message indicating `success` if status is `ok`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// The query string
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// Type of response
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub res_type: Option<String>,
+    /// List of timeseries queried
+    #[serde(default)]
+    pub series: Vec<QuerySeries>,
+    /// Status of the query
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// End of requested time window, milliseconds since Unix epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_date: Option<i64>,
+}
+
+/// A queried timeseries
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct QuerySeries {
+    /// Aggregation type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aggr: Option<String>,
+    /// Display name of the metric
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// End of the time window, milliseconds since Unix epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<i64>,
+    /// Metric expression
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expression: Option<String>,
+    /// Number of seconds between data samples
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval: Option<i64>,
+    /// Number of data samples
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<i64>,
+    /// Metric name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metric: Option<String>,
+    /// List of points, as `[timestamp in milliseconds, value]` pairs
+    ///
+    /// Values are `None` where no data was reported.
+    #[serde(default)]
+    pub pointlist: Vec<(f64, Option<f64>)>,
+    /// The index of the series' query within the request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query_index: Option<i64>,
+    /// Metric scope, comma separated list of tags
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// Start of the time window, milliseconds since Unix epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<i64>,
+    /// Unique tags identifying this series
+    #[serde(default)]
+    pub tag_set: Vec<String>,
+    /// The units of the metric and of its per unit, if any
+    #[serde(default)]
+    pub unit: Vec<Option<QueryUnit>>,
+}
+
+/// A metric unit
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct QueryUnit {
+    /// Unit family, ie `time` or `bytes`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub family: Option<String>,
+    /// Unit name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Plural form of the unit name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plural: Option<String>,
+    /// Factor for scaling between units of the same family
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale_factor: Option<f64>,
+    /// Abbreviation of the unit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_name: Option<String>,
+}
+
+impl Default for QueryMetrics {
+    fn default() -> Self {
+        Self {
+            from: 0,
+            to: 0,
+            query: None,
+            version: types::version::ApiVersion::V1,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for QueryMetrics {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V1 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl QueryMetrics {
+    /// Instantiates a new Metrics query between the timestamps, in seconds since the Unix epoch
+    pub fn new(from: i64, to: i64, query: &str) -> Self {
+        tracing::info!(target: "/v1/query", "Query Metrics Route Created");
+        Self {
+            from,
+            to,
+            query: Some(query.to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the query time range, in seconds since the Unix epoch
+    pub fn set_time_range(mut self, from: i64, to: i64) -> Self {
+        tracing::info!(target: "/v1/query", "Query time range set to {} - {}", from, to);
+        self.from = from;
+        self.to = to;
+        self
+    }
+
+    /// Set the metrics query, ie `avg:system.cpu.idle{*}by{host}`
+    pub fn set_query(mut self, query: &str) -> Self {
+        tracing::info!(target: "/v1/query", "Query set to {}", query);
+        self.query = Some(query.to_string());
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v1/query")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<QueryMetricsResponse> for QueryMetrics {
+    /// The route path
+    fn path(&self) -> String {
+        String::from("v1/query")
+    }
+
+    /// Route does nothing here since the route path is fixed
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<QueryMetricsResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        let path = self.path();
+        let query = self
+            .query
+            .ok_or_else(|| Error::Validation(String::from("missing metrics query")))?;
+        tracing::info!(target: "/v1/query", "Sending Request to {}", path);
+        let req_builder = client
+            .request(reqwest::Method::GET, &path)
+            .query(&[
+                ("from", self.from.to_string()),
+                ("to", self.to.to_string()),
+                ("query", query),
+            ])
+            .headers(self.headers);
+        client.send(req_builder).await
+    }
+}
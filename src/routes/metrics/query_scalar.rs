@@ -0,0 +1,327 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+use super::{
+    query_metrics::QueryUnit,
+    query_timeseries::{FormulaQuery, FormulaRequestData, FormulaRequestPayload, QueryFormula},
+};
+
+/// Scalar Formula Query Endpoint
+///
+/// ## Overview
+///
+/// Query scalar values, as seen on query value, table and toplist widgets, across various data sources and process the data by applying formulas and functions.
+/// Each query is reduced to a single value per group, using the `aggregator` of metrics queries.
+///
+/// This endpoint requires the `timeseries_query` authorization [scope](https://docs.datadoghq.com/api/latest/scopes/#metrics).
+///
+/// Endpoint Format: `/v2/query/scalar` [POST]
+///
+/// ## Request
+///
+/// **Body Data (required)**
+///
+/// - data [required] [type: object] - A single scalar query to be executed.
+///    - type [required] [type: string] - The type of the request, `scalar_request`.
+///    - attributes [required] [type: object] - The object describing a scalar formula request.
+///       - from [required] [type: int64] - Start date (inclusive) of the query in milliseconds since the Unix epoch.
+///       - to [required] [type: int64] - End date (exclusive) of the query in milliseconds since the Unix epoch.
+///       - queries [required] [type: object[]] - List of queries to be run and used as inputs to the formulas, see [FormulaQuery].
+///       - formulas [type: object[]] - List of formulas to be calculated and returned as responses, see [QueryFormula].
+///
+/// ## Response
+///
+/// One of: [200, 400, 401, 403, 429]
+///
+/// - data [type: object] - A message containing the response to a scalar query.
+///    - attributes [type: object] - The object describing a scalar response.
+///       - columns [type: object[]] - List of response columns, each corresponding to an individual formula or query in the request, or to the groups of the results.
+/// - errors [type: string] - The error generated by the request.
+///
+/// #### Example
+///
+/// ```json
+/// {
+///     "data": {
+///         "type": "scalar_response",
+///         "attributes": {
+///             "columns": [
+///                 { "name": "env", "type": "group", "values": [ [ "prod" ], [ "staging" ] ] },
+///                 { "name": "a", "type": "number", "values": [ 99.5, 97.25 ], "meta": { "unit": [ null, null ] } }
+///             ]
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct QueryScalar {
+    /// Version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The typed scalar request, serialized in place of the body when set
+    pub request: Option<ScalarQueryRequest>,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// A Scalar Formula Query Request
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct ScalarQueryRequest {
+    /// Start date (inclusive) of the query in milliseconds since the Unix epoch
+    pub from: i64,
+    /// End date (exclusive) of the query in milliseconds since the Unix epoch
+    pub to: i64,
+    /// The named queries used as inputs to the formulas
+    pub queries: Vec<FormulaQuery>,
+    /// The formulas to calculate, returning the queries themselves when empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub formulas: Vec<QueryFormula>,
+}
+
+/// A Scalar Formula Query Response
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct ScalarQueryResponse {
+    /// The scalar response data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<ScalarResponseData>,
+    /// The error generated by the request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub errors: Option<String>,
+}
+
+/// The Scalar Response Data
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct ScalarResponseData {
+    /// The type of the response, `scalar_response`
+    #[serde(default, rename = "type")]
+    pub type_: String,
+    /// The scalar response attributes
+    #[serde(default)]
+    pub attributes: ScalarResponseAttributes,
+}
+
+/// The Scalar Response Attributes
+///
+/// The response is columnar: the i-th row is made of the i-th value of every column.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct ScalarResponseAttributes {
+    /// The columns of the response
+    #[serde(default)]
+    pub columns: Vec<ScalarColumn>,
+}
+
+/// A column of a scalar response
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScalarColumn {
+    /// The groups of the results, one list of tag values per row
+    Group {
+        /// The name of the column
+        name: String,
+        /// The tag values of each group
+        #[serde(default)]
+        values: Vec<Vec<String>>,
+    },
+    /// The values of a formula or query, one per row
+    Number {
+        /// The name of the formula or query
+        name: String,
+        /// The value of each group, `None` where there is no data
+        #[serde(default)]
+        values: Vec<Option<f64>>,
+        /// Metadata of the column
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        meta: Option<ScalarColumnMeta>,
+    },
+}
+
+impl ScalarColumn {
+    /// The name of the column
+    pub fn name(&self) -> &str {
+        match self {
+            ScalarColumn::Group { name, .. } | ScalarColumn::Number { name, .. } => name,
+        }
+    }
+}
+
+/// Metadata of a scalar response column
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct ScalarColumnMeta {
+    /// The units of the column and of its per unit, if any
+    #[serde(default)]
+    pub unit: Vec<Option<QueryUnit>>,
+}
+
+impl Default for QueryScalar {
+    fn default() -> Self {
+        Self {
+            version: types::version::ApiVersion::V2,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            request: None,
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for QueryScalar {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V2 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl QueryScalar {
+    /// Instantiates a new Scalar formula query
+    pub fn new() -> Self {
+        tracing::info!(target: "/v2/query/scalar", "Query Scalar Route Created");
+        Self::default()
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Sets the typed scalar request
+    ///
+    /// The request is serialized as the request body when the route is executed, replacing any body set with [body](types::route::Route::body).
+    pub fn scalar(mut self, request: ScalarQueryRequest) -> Self {
+        self.request = Some(request);
+        self
+    }
+
+    /// Set the query time range
+    pub fn set_time_range(mut self, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        tracing::info!(target: "/v2/query/scalar", "Query time range set to {} - {}", from, to);
+        let request = self.request.get_or_insert_with(Default::default);
+        request.from = from.timestamp_millis();
+        request.to = to.timestamp_millis();
+        self
+    }
+
+    /// Adds a named query, referenced by formulas
+    pub fn with_query<Q: Into<FormulaQuery>>(mut self, query: Q) -> Self {
+        self.request
+            .get_or_insert_with(Default::default)
+            .queries
+            .push(query.into());
+        self
+    }
+
+    /// Adds a formula computed from the named queries
+    pub fn with_formula(mut self, formula: QueryFormula) -> Self {
+        tracing::info!(target: "/v2/query/scalar", "Formula {} added", formula.formula);
+        self.request
+            .get_or_insert_with(Default::default)
+            .formulas
+            .push(formula);
+        self
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v2/query/scalar")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<ScalarQueryResponse> for QueryScalar {
+    /// The route path
+    fn path(&self) -> String {
+        String::from("v2/query/scalar")
+    }
+
+    /// Route does nothing here since the route path is fixed
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<ScalarQueryResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        let path = self.path();
+        tracing::info!(target: "/v2/query/scalar", "Sending Request to {}", path);
+        let body = match self.request {
+            Some(request) => reqwest::Body::from(
+                serde_json::to_vec(&FormulaRequestPayload {
+                    data: FormulaRequestData {
+                        type_: "scalar_request",
+                        attributes: &request,
+                    },
+                })
+                .map_err(|e| Error::Validation(e.to_string()))?,
+            ),
+            None => self.body,
+        };
+        let req_builder = client
+            .request(reqwest::Method::POST, &path)
+            .headers(self.headers)
+            .body(body);
+        // Querying is read only, so the request is safe to retry
+        client.send_idempotent(req_builder).await
+    }
+}
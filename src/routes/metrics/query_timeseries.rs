@@ -0,0 +1,682 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+use super::query_metrics::QueryUnit;
+
+/// Timeseries Formula Query Endpoint
+///
+/// ## Overview
+///
+/// Query timeseries data across various data sources and process the data by applying formulas and functions.
+/// Each query is given a name, which formulas such as `a / b * 100` reference.
+///
+/// This endpoint requires the `timeseries_query` authorization [scope](https://docs.datadoghq.com/api/latest/scopes/#metrics).
+///
+/// Endpoint Format: `/v2/query/timeseries` [POST]
+///
+/// ## Request
+///
+/// **Body Data (required)**
+///
+/// - data [required] [type: object] - A single timeseries query to be executed.
+///    - type [required] [type: string] - The type of the request, `timeseries_request`.
+///    - attributes [required] [type: object] - The object describing a timeseries formula request.
+///       - from [required] [type: int64] - Start date (inclusive) of the query in milliseconds since the Unix epoch.
+///       - to [required] [type: int64] - End date (exclusive) of the query in milliseconds since the Unix epoch.
+///       - interval [type: int64] - A time interval in milliseconds.
+///       - queries [required] [type: object[]] - List of queries to be run and used as inputs to the formulas, see [FormulaQuery].
+///       - formulas [type: object[]] - List of formulas to be calculated and returned as responses, see [QueryFormula].
+///
+/// ## Response
+///
+/// One of: [200, 400, 401, 403, 429]
+///
+/// - data [type: object] - A message containing the response to a timeseries query.
+///    - attributes [type: object] - The object describing a timeseries response.
+///       - series [type: object[]] - Details of each series returned, such as its group tags and unit.
+///       - times [type: int64[]] - Array of times, 1-1 match with individual values arrays.
+///       - values [type: number[][]] - Array of value-arrays. The index here corresponds to the index in the `formulas` or `queries` array from the request.
+/// - errors [type: string] - The error generated by the request.
+///
+/// #### Example
+///
+/// ```json
+/// {
+///     "data": {
+///         "type": "timeseries_response",
+///         "attributes": {
+///             "series": [ { "group_tags": [ "env:prod" ], "query_index": 0, "unit": [ { "family": "percentage", "name": "percent", "plural": "percent", "scale_factor": 1.0, "short_name": "%" }, null ] } ],
+///             "times": [ 1568899800000, 1568899805000 ],
+///             "values": [ [ 99.5, null ] ]
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct QueryTimeseries {
+    /// Version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The typed timeseries request, serialized in place of the body when set
+    pub request: Option<TimeseriesQueryRequest>,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// A Timeseries Formula Query Request
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct TimeseriesQueryRequest {
+    /// Start date (inclusive) of the query in milliseconds since the Unix epoch
+    pub from: i64,
+    /// End date (exclusive) of the query in milliseconds since the Unix epoch
+    pub to: i64,
+    /// A time interval in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval: Option<i64>,
+    /// The named queries used as inputs to the formulas
+    pub queries: Vec<FormulaQuery>,
+    /// The formulas to calculate, returning the queries themselves when empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub formulas: Vec<QueryFormula>,
+}
+
+/// The request envelope of the formula query endpoints
+#[derive(Serialize)]
+pub(crate) struct FormulaRequestPayload<'a, T> {
+    pub(crate) data: FormulaRequestData<'a, T>,
+}
+
+/// The data of a formula query request
+#[derive(Serialize)]
+pub(crate) struct FormulaRequestData<'a, T> {
+    #[serde(rename = "type")]
+    pub(crate) type_: &'static str,
+    pub(crate) attributes: &'a T,
+}
+
+/// A named query used as an input to formulas
+///
+/// ```rust
+/// use ddog::prelude::*;
+/// use query_timeseries::*;
+///
+/// let query: FormulaQuery = MetricsQuery::new("a", "sum:requests.errors{service:web}.as_count()").into();
+/// assert_eq!(
+///     serde_json::to_value(&query).unwrap(),
+///     serde_json::json!({
+///         "data_source": "metrics",
+///         "name": "a",
+///         "query": "sum:requests.errors{service:web}.as_count()"
+///     })
+/// );
+///
+/// let query: FormulaQuery = EventsQuery::new("b", EventsDataSource::Logs, EventsAggregation::Count)
+///     .with_search("service:web status:error")
+///     .with_group_by("env", 10)
+///     .into();
+/// assert_eq!(
+///     serde_json::to_value(&query).unwrap(),
+///     serde_json::json!({
+///         "data_source": "logs",
+///         "name": "b",
+///         "compute": { "aggregation": "count" },
+///         "search": { "query": "service:web status:error" },
+///         "group_by": [ { "facet": "env", "limit": 10 } ]
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum FormulaQuery {
+    /// A query on the metrics data sources
+    Metrics(MetricsQuery),
+    /// A query on the events data sources, such as logs
+    Events(EventsQuery),
+}
+
+impl From<MetricsQuery> for FormulaQuery {
+    fn from(query: MetricsQuery) -> Self {
+        FormulaQuery::Metrics(query)
+    }
+}
+
+impl From<EventsQuery> for FormulaQuery {
+    fn from(query: EventsQuery) -> Self {
+        FormulaQuery::Events(query)
+    }
+}
+
+/// A query on the metrics data sources
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct MetricsQuery {
+    /// The data source of the query
+    pub data_source: MetricsDataSource,
+    /// The name of the query, referenced by formulas
+    pub name: String,
+    /// The metrics query definition
+    pub query: String,
+    /// The reduction of the points into a single value, for scalar queries
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aggregator: Option<MetricsAggregator>,
+}
+
+impl MetricsQuery {
+    /// Instantiates a new metrics query with the given name
+    pub fn new(name: &str, query: &str) -> Self {
+        Self {
+            data_source: MetricsDataSource::Metrics,
+            name: name.to_string(),
+            query: query.to_string(),
+            aggregator: None,
+        }
+    }
+
+    /// Sets the data source of the query
+    pub fn with_data_source(mut self, data_source: MetricsDataSource) -> Self {
+        self.data_source = data_source;
+        self
+    }
+
+    /// Sets the reduction of the points into a single value, for scalar queries
+    pub fn with_aggregator(mut self, aggregator: MetricsAggregator) -> Self {
+        self.aggregator = Some(aggregator);
+        self
+    }
+}
+
+/// A metrics data source
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricsDataSource {
+    /// Metrics
+    Metrics,
+    /// Cloud cost metrics
+    CloudCost,
+}
+
+/// The reduction of a metrics query into a single value
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricsAggregator {
+    /// Average
+    Avg,
+    /// Minimum
+    Min,
+    /// Maximum
+    Max,
+    /// Sum
+    Sum,
+    /// Last value
+    Last,
+    /// Percentile, for distribution metrics
+    Percentile,
+    /// Mean
+    Mean,
+    /// L2 norm
+    L2norm,
+    /// Area under the curve
+    Area,
+}
+
+/// A query on the events data sources
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct EventsQuery {
+    /// The data source of the query
+    pub data_source: EventsDataSource,
+    /// The name of the query, referenced by formulas
+    pub name: String,
+    /// The computation applied to the matching events
+    pub compute: EventsCompute,
+    /// The search filter of the events
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search: Option<EventsSearch>,
+    /// The facets to group the events by
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub group_by: Vec<EventsGroupBy>,
+    /// The indexes to search
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub indexes: Vec<String>,
+}
+
+impl EventsQuery {
+    /// Instantiates a new events query with the given name, computing the aggregation of the matching events
+    pub fn new(name: &str, data_source: EventsDataSource, aggregation: EventsAggregation) -> Self {
+        Self {
+            data_source,
+            name: name.to_string(),
+            compute: EventsCompute {
+                aggregation,
+                metric: None,
+                interval: None,
+            },
+            search: None,
+            group_by: vec![],
+            indexes: vec![],
+        }
+    }
+
+    /// Sets the search query of the events
+    pub fn with_search(mut self, query: &str) -> Self {
+        self.search = Some(EventsSearch {
+            query: query.to_string(),
+        });
+        self
+    }
+
+    /// Sets the measure the aggregation is computed on, ie `@duration`
+    pub fn with_metric(mut self, metric: &str) -> Self {
+        self.compute.metric = Some(metric.to_string());
+        self
+    }
+
+    /// Groups the events by a facet, keeping at most `limit` groups
+    pub fn with_group_by(mut self, facet: &str, limit: i64) -> Self {
+        self.group_by.push(EventsGroupBy {
+            facet: facet.to_string(),
+            limit: Some(limit),
+            sort: None,
+        });
+        self
+    }
+
+    /// Adds an index to search
+    pub fn with_index(mut self, index: &str) -> Self {
+        self.indexes.push(index.to_string());
+        self
+    }
+}
+
+/// An events data source
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EventsDataSource {
+    /// Logs
+    Logs,
+    /// Real User Monitoring events
+    Rum,
+}
+
+/// The computation of an events query
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct EventsCompute {
+    /// The aggregation method
+    pub aggregation: EventsAggregation,
+    /// The measure the aggregation is computed on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metric: Option<String>,
+    /// A time interval in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval: Option<i64>,
+}
+
+/// The aggregation method of an events query
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EventsAggregation {
+    /// Number of events
+    Count,
+    /// Number of unique values
+    Cardinality,
+    /// 75th percentile
+    Pc75,
+    /// 90th percentile
+    Pc90,
+    /// 95th percentile
+    Pc95,
+    /// 98th percentile
+    Pc98,
+    /// 99th percentile
+    Pc99,
+    /// Sum
+    Sum,
+    /// Minimum
+    Min,
+    /// Maximum
+    Max,
+    /// Average
+    Avg,
+    /// Median
+    Median,
+}
+
+/// The search filter of an events query
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
+pub struct EventsSearch {
+    /// The search query, following the events search syntax
+    pub query: String,
+}
+
+/// A facet to group an events query by
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct EventsGroupBy {
+    /// The facet to group by
+    pub facet: String,
+    /// The maximum number of groups to return
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// The sort order of the groups
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<EventsGroupBySort>,
+}
+
+/// The sort order of events groups
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct EventsGroupBySort {
+    /// The aggregation the groups are sorted by
+    pub aggregation: EventsAggregation,
+    /// The measure the aggregation is computed on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metric: Option<String>,
+    /// The direction of the sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<QuerySortOrder>,
+}
+
+/// A formula computed from named queries
+///
+/// ```rust
+/// use ddog::prelude::*;
+/// use query_timeseries::*;
+///
+/// let formula = QueryFormula::new("a / b * 100").with_limit(10, QuerySortOrder::Desc);
+/// assert_eq!(
+///     serde_json::to_value(&formula).unwrap(),
+///     serde_json::json!({ "formula": "a / b * 100", "limit": { "count": 10, "order": "desc" } })
+/// );
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct QueryFormula {
+    /// The formula, referencing queries by name
+    pub formula: String,
+    /// Limits the number of series returned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<FormulaLimit>,
+}
+
+impl QueryFormula {
+    /// Instantiates a new formula
+    pub fn new(formula: &str) -> Self {
+        Self {
+            formula: formula.to_string(),
+            limit: None,
+        }
+    }
+
+    /// Keeps the first `count` series in the given order
+    pub fn with_limit(mut self, count: i64, order: QuerySortOrder) -> Self {
+        self.limit = Some(FormulaLimit {
+            count: Some(count),
+            order: Some(order),
+        });
+        self
+    }
+}
+
+/// Limits the number of series returned by a formula
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
+pub struct FormulaLimit {
+    /// The number of series to return
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    /// The direction of the sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<QuerySortOrder>,
+}
+
+/// The direction of a sort
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum QuerySortOrder {
+    /// Ascending
+    Asc,
+    /// Descending
+    Desc,
+}
+
+/// A Timeseries Formula Query Response
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct TimeseriesQueryResponse {
+    /// The timeseries response data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<TimeseriesResponseData>,
+    /// The error generated by the request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub errors: Option<String>,
+}
+
+/// The Timeseries Response Data
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct TimeseriesResponseData {
+    /// The type of the response, `timeseries_response`
+    #[serde(default, rename = "type")]
+    pub type_: String,
+    /// The timeseries response attributes
+    #[serde(default)]
+    pub attributes: TimeseriesResponseAttributes,
+}
+
+/// The Timeseries Response Attributes
+///
+/// The response is columnar: `times` holds the timestamps shared by every series and `values[i]` the values of `series[i]`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct TimeseriesResponseAttributes {
+    /// Details of each series returned
+    #[serde(default)]
+    pub series: Vec<TimeseriesResponseSeries>,
+    /// The timestamps of the values, in milliseconds since the Unix epoch
+    #[serde(default)]
+    pub times: Vec<i64>,
+    /// The values of each series, `None` where there is no data
+    #[serde(default)]
+    pub values: Vec<Vec<Option<f64>>>,
+}
+
+impl TimeseriesResponseAttributes {
+    /// The `(timestamp in milliseconds, value)` points of the series at the given index
+    pub fn points(&self, index: usize) -> Vec<(i64, Option<f64>)> {
+        self.values
+            .get(index)
+            .map(|values| {
+                self.times
+                    .iter()
+                    .copied()
+                    .zip(values.iter().copied())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// A series of a timeseries response
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct TimeseriesResponseSeries {
+    /// The tags of the group the series belongs to
+    #[serde(default)]
+    pub group_tags: Vec<String>,
+    /// The index of the query or formula the series was computed by
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query_index: Option<i64>,
+    /// The units of the series and of its per unit, if any
+    #[serde(default)]
+    pub unit: Vec<Option<QueryUnit>>,
+}
+
+impl Default for QueryTimeseries {
+    fn default() -> Self {
+        Self {
+            version: types::version::ApiVersion::V2,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            request: None,
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for QueryTimeseries {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V2 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl QueryTimeseries {
+    /// Instantiates a new Timeseries formula query
+    pub fn new() -> Self {
+        tracing::info!(target: "/v2/query/timeseries", "Query Timeseries Route Created");
+        Self::default()
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Sets the typed timeseries request
+    ///
+    /// The request is serialized as the request body when the route is executed, replacing any body set with [body](types::route::Route::body).
+    pub fn timeseries(mut self, request: TimeseriesQueryRequest) -> Self {
+        self.request = Some(request);
+        self
+    }
+
+    /// Set the query time range
+    pub fn set_time_range(mut self, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        tracing::info!(target: "/v2/query/timeseries", "Query time range set to {} - {}", from, to);
+        let request = self.request.get_or_insert_with(Default::default);
+        request.from = from.timestamp_millis();
+        request.to = to.timestamp_millis();
+        self
+    }
+
+    /// Sets the interval between points, in milliseconds
+    pub fn with_interval(mut self, interval: i64) -> Self {
+        self.request.get_or_insert_with(Default::default).interval = Some(interval);
+        self
+    }
+
+    /// Adds a named query, referenced by formulas
+    pub fn with_query<Q: Into<FormulaQuery>>(mut self, query: Q) -> Self {
+        self.request
+            .get_or_insert_with(Default::default)
+            .queries
+            .push(query.into());
+        self
+    }
+
+    /// Adds a formula computed from the named queries
+    pub fn with_formula(mut self, formula: QueryFormula) -> Self {
+        tracing::info!(target: "/v2/query/timeseries", "Formula {} added", formula.formula);
+        self.request
+            .get_or_insert_with(Default::default)
+            .formulas
+            .push(formula);
+        self
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v2/query/timeseries")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<TimeseriesQueryResponse> for QueryTimeseries {
+    /// The route path
+    fn path(&self) -> String {
+        String::from("v2/query/timeseries")
+    }
+
+    /// Route does nothing here since the route path is fixed
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<TimeseriesQueryResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        let path = self.path();
+        tracing::info!(target: "/v2/query/timeseries", "Sending Request to {}", path);
+        let body = match self.request {
+            Some(request) => reqwest::Body::from(
+                serde_json::to_vec(&FormulaRequestPayload {
+                    data: FormulaRequestData {
+                        type_: "timeseries_request",
+                        attributes: &request,
+                    },
+                })
+                .map_err(|e| Error::Validation(e.to_string()))?,
+            ),
+            None => self.body,
+        };
+        let req_builder = client
+            .request(reqwest::Method::POST, &path)
+            .headers(self.headers)
+            .body(body);
+        // Querying is read only, so the request is safe to retry
+        client.send_idempotent(req_builder).await
+    }
+}
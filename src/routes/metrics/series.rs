@@ -3,7 +3,13 @@ use std::str::FromStr;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
-use crate::types;
+use crate::{client, types, Error};
+
+/// The maximum size in bytes of a series payload sent over the wire
+pub const MAX_PAYLOAD_SIZE: usize = 512_000;
+
+/// The maximum decompressed size in bytes of a compressed series payload
+pub const MAX_DECOMPRESSED_PAYLOAD_SIZE: usize = 5_242_880;
 
 /// Series Metrics Endpoint
 ///
@@ -13,13 +19,15 @@ use crate::types;
 ///
 /// Host name is one of the resources in the Resources field.
 ///
+/// Typed series set with [series](Series::series) are split into as many requests as needed to stay within these limits, see [batch_series].
+///
 /// Endpoint Format: `/v2/series` [POST]
 ///
 /// ## Arguments
 ///
 /// - Content-Encoding [header]
 ///    - type: `string`
-///    - description: HTTP header used to compress the media-type.
+///    - description: HTTP header used to compress the media-type. Set automatically when compression is enabled with `with_compression`.
 ///
 /// ## Request
 ///
@@ -62,6 +70,10 @@ pub struct Series {
     pub headers: reqwest::header::HeaderMap,
     /// Request Body
     pub body: reqwest::Body,
+    /// Typed series to submit, serialized in place of the body when set
+    pub series: Option<Vec<MetricSeries>>,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
 }
 
 /// A Series Response
@@ -71,23 +83,234 @@ pub struct SeriesResponse {
     pub errors: Vec<serde_json::value::Value>,
 }
 
+/// A Series Request Body
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct SeriesPayload {
+    /// A list of time series to submit to Datadog
+    pub series: Vec<MetricSeries>,
+}
+
+/// A Metric Time Series
+///
+/// ```rust
+/// use ddog::prelude::*;
+///
+/// let series = series::MetricSeries::new("my.metric.name", series::MetricIntakeType::Count)
+///     .with_point(1660157680, 10.0)
+///     .with_tag("env:prod")
+///     .with_interval(10)
+///     .with_resource("my-host", "host");
+/// assert_eq!(
+///     serde_json::to_value(&series).unwrap(),
+///     serde_json::json!({
+///         "metric": "my.metric.name",
+///         "type": 1,
+///         "interval": 10,
+///         "tags": [ "env:prod" ],
+///         "points": [ { "timestamp": 1660157680, "value": 10.0 } ],
+///         "resources": [ { "name": "my-host", "type": "host" } ]
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct MetricSeries {
+    /// The name of the timeseries
+    pub metric: String,
+    /// The type of metric
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub type_: Option<MetricIntakeType>,
+    /// If the type of the metric is rate or count, the corresponding interval in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval: Option<i64>,
+    /// The unit of point value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    /// A list of tags associated with the metric
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// The source type name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_type_name: Option<String>,
+    /// Points relating to the metric
+    pub points: Vec<MetricPoint>,
+    /// A list of resources to associate with this metric
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resources: Vec<MetricResource>,
+    /// Metadata for the metric
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<MetricSeriesMetadata>,
+}
+
+/// A Metric Point
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq)]
+pub struct MetricPoint {
+    /// The timestamp in POSIX seconds
+    pub timestamp: i64,
+    /// The numeric point value
+    pub value: f64,
+}
+
+/// A Metric Resource
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct MetricResource {
+    /// The name of the resource
+    pub name: String,
+    /// The type of the resource
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+/// Metric Series Metadata
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct MetricSeriesMetadata {
+    /// Metric origin information
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<MetricOrigin>,
+}
+
+/// Metric Origin Information
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct MetricOrigin {
+    /// The origin metric type code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metric_type: Option<i32>,
+    /// The origin product code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product: Option<i32>,
+    /// The origin service code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<i32>,
+}
+
+/// The Metric Intake Types
+///
+/// Serialized as the numeric codes expected by the `/v2/series` intake.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub enum MetricIntakeType {
+    /// An unspecified metric (0)
+    #[default]
+    Unspecified,
+    /// A count metric (1)
+    Count,
+    /// A rate metric (2)
+    Rate,
+    /// A gauge metric (3)
+    Gauge,
+}
+
+impl MetricIntakeType {
+    /// The numeric intake code
+    pub fn code(&self) -> u8 {
+        match self {
+            MetricIntakeType::Unspecified => 0,
+            MetricIntakeType::Count => 1,
+            MetricIntakeType::Rate => 2,
+            MetricIntakeType::Gauge => 3,
+        }
+    }
+}
+
+impl TryFrom<u8> for MetricIntakeType {
+    type Error = String;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        match code {
+            0 => Ok(MetricIntakeType::Unspecified),
+            1 => Ok(MetricIntakeType::Count),
+            2 => Ok(MetricIntakeType::Rate),
+            3 => Ok(MetricIntakeType::Gauge),
+            c => Err(format!("invalid metric intake type {}", c)),
+        }
+    }
+}
+
+impl Serialize for MetricIntakeType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for MetricIntakeType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        MetricIntakeType::try_from(u8::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+impl MetricSeries {
+    /// Instantiates a new series for the given metric name and type
+    pub fn new(metric: &str, type_: MetricIntakeType) -> Self {
+        Self {
+            metric: metric.to_string(),
+            type_: Some(type_),
+            ..Self::default()
+        }
+    }
+
+    /// Adds a point at the given POSIX timestamp in seconds
+    pub fn with_point(mut self, timestamp: i64, value: f64) -> Self {
+        self.points.push(MetricPoint { timestamp, value });
+        self
+    }
+
+    /// Adds a tag
+    pub fn with_tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    /// Adds a list of tags
+    pub fn with_tags(mut self, tags: Vec<&str>) -> Self {
+        self.tags.extend(tags.into_iter().map(String::from));
+        self
+    }
+
+    /// Sets the interval in seconds of a rate or count metric
+    pub fn with_interval(mut self, interval: i64) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Sets the unit of the point values
+    pub fn with_unit(mut self, unit: &str) -> Self {
+        self.unit = Some(unit.to_string());
+        self
+    }
+
+    /// Sets the source type name
+    pub fn with_source_type_name(mut self, source_type_name: &str) -> Self {
+        self.source_type_name = Some(source_type_name.to_string());
+        self
+    }
+
+    /// Adds a resource, for example `("my-host", "host")`
+    pub fn with_resource(mut self, name: &str, type_: &str) -> Self {
+        self.resources.push(MetricResource {
+            name: name.to_string(),
+            type_: type_.to_string(),
+        });
+        self
+    }
+}
+
 impl Default for Series {
     fn default() -> Self {
         Self {
             version: types::version::ApiVersion::V2,
             headers: reqwest::header::HeaderMap::new(),
             body: reqwest::Body::from(""),
+            series: None,
+            client: None,
         }
     }
 }
 
 impl TryFrom<types::version::ApiVersion> for Series {
-    type Error = &'static str;
+    type Error = Error;
 
     fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
         match v {
             types::version::ApiVersion::V2 => Ok(Self::default()),
-            _ => Err("Unsupported API Version"),
+            v => Err(Error::UnsupportedVersion(v)),
         }
     }
 }
@@ -99,12 +322,230 @@ impl Series {
         Self::default()
     }
 
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Sets the typed series to submit
+    ///
+    /// The series are serialized as the request body when the route is executed, replacing any body set with [body](types::route::Route::body).
+    pub fn series(mut self, series: Vec<MetricSeries>) -> Self {
+        tracing::info!(target: "/v2/series", "Series set with {} series", series.len());
+        self.series = Some(series);
+        self
+    }
+
+    /// Compresses the request body with the given compression
+    pub fn with_compression(mut self, compression: types::compression::Compression) -> Self {
+        self.client
+            .get_or_insert_with(client::Client::shared)
+            .compression = Some(compression);
+        self
+    }
+
+    /// Executes the request, returning the result of each batch
+    ///
+    /// Typed series are split into batches within the intake payload limits and every batch is sent, even if an earlier batch failed.
+    /// A series that does not fit in a payload is returned as a failed result of its own.
+    pub async fn execute_batches(
+        self,
+    ) -> Vec<Result<types::response::Response<SeriesResponse>, Error>> {
+        let client = client::Client::or_shared(&self.client);
+        let path = types::route::Route::<SeriesResponse>::path(&self);
+        let series = match self.series {
+            Some(series) => series,
+            None => {
+                tracing::info!(target: "/v2/series", "Sending Request to {}", path);
+                let req_builder = client
+                    .request(reqwest::Method::POST, &path)
+                    .headers(self.headers);
+                return match client.compressed_body(req_builder, self.body) {
+                    Ok(req_builder) => vec![client.send_idempotent(req_builder).await],
+                    Err(e) => vec![Err(e)],
+                };
+            }
+        };
+        let batches = batch_series(series, client.compression.as_ref());
+        tracing::info!(target: "/v2/series", "Sending {} batches to {}", batches.len(), path);
+        let mut results = Vec::with_capacity(batches.len());
+        for batch in batches {
+            let SeriesBatch { body, encoding } = match batch {
+                Ok(batch) => batch,
+                Err(e) => {
+                    tracing::error!(target: "/v2/series", "Failed to batch series with error {:?}", e);
+                    results.push(Err(e));
+                    continue;
+                }
+            };
+            let mut req_builder = client
+                .request(reqwest::Method::POST, &path)
+                .headers(self.headers.clone());
+            if let Some(encoding) = encoding {
+                req_builder =
+                    req_builder.header(reqwest::header::CONTENT_ENCODING, encoding.to_string());
+            }
+            results.push(client.send_idempotent(req_builder.body(body)).await);
+        }
+        results
+    }
+
     /// Creates a target identifier for logging
     pub fn target() -> String {
         String::from("v2/series")
     }
 }
 
+/// A Series Request Body within the intake payload limits
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SeriesBatch {
+    /// The serialized, possibly compressed, request body
+    pub body: Vec<u8>,
+    /// The content encoding applied to the body
+    pub encoding: Option<types::compression::ContentEncoding>,
+}
+
+/// Splits series into request bodies within the intake payload limits
+///
+/// Without compression each body is at most [MAX_PAYLOAD_SIZE] bytes.
+/// With compression each body is at most [MAX_DECOMPRESSED_PAYLOAD_SIZE] bytes before and [MAX_PAYLOAD_SIZE] bytes after compression.
+///
+/// A series too large to fit in a payload is split by its points.
+/// A single point series that still does not fit is returned as an [Error::Validation] in place of its batch, the other series are batched as usual.
+pub fn batch_series(
+    series: Vec<MetricSeries>,
+    compression: Option<&types::compression::Compression>,
+) -> Vec<Result<SeriesBatch, Error>> {
+    let limit = match compression {
+        Some(_) => MAX_DECOMPRESSED_PAYLOAD_SIZE,
+        None => MAX_PAYLOAD_SIZE,
+    };
+    let overhead = series_payload(&[]).len();
+
+    // Greedily group the serialized series under the uncompressed limit
+    let mut batches = vec![];
+    let mut groups: Vec<Vec<Vec<u8>>> = vec![vec![]];
+    let mut group_size = overhead;
+    for s in series {
+        for encoded in encode_series(s, limit - overhead) {
+            let encoded = match encoded {
+                Ok(encoded) => encoded,
+                Err(e) => {
+                    batches.push(Err(e));
+                    continue;
+                }
+            };
+            let group = groups.last_mut().unwrap();
+            let separator = usize::from(!group.is_empty());
+            if group_size + separator + encoded.len() > limit {
+                group_size = overhead + encoded.len();
+                groups.push(vec![encoded]);
+            } else {
+                group_size += separator + encoded.len();
+                group.push(encoded);
+            }
+        }
+    }
+
+    for group in groups {
+        // Only an empty submission sends an empty payload
+        if group.is_empty() && !batches.is_empty() {
+            continue;
+        }
+        encode_series_group(&group, compression, &mut batches);
+    }
+    batches
+}
+
+/// Serializes a series, halving its points until each part is at most `limit` bytes
+fn encode_series(series: MetricSeries, limit: usize) -> Vec<Result<Vec<u8>, Error>> {
+    let encoded = match serde_json::to_vec(&series) {
+        Ok(encoded) => encoded,
+        Err(e) => return vec![Err(Error::Validation(e.to_string()))],
+    };
+    if encoded.len() <= limit {
+        return vec![Ok(encoded)];
+    }
+    if series.points.len() < 2 {
+        return vec![Err(Error::Validation(format!(
+            "series {} is {} bytes, exceeding the {} byte payload limit",
+            series.metric,
+            encoded.len(),
+            limit
+        )))];
+    }
+    let mut left = series;
+    let mut right = left.clone();
+    right.points = left.points.split_off(left.points.len() / 2);
+    let mut parts = encode_series(left, limit);
+    parts.extend(encode_series(right, limit));
+    parts
+}
+
+/// Joins serialized series into a `{"series":[...]}` payload
+fn series_payload(series: &[Vec<u8>]) -> Vec<u8> {
+    let mut payload = br#"{"series":["#.to_vec();
+    for (i, s) in series.iter().enumerate() {
+        if i > 0 {
+            payload.push(b',');
+        }
+        payload.extend_from_slice(s);
+    }
+    payload.extend_from_slice(b"]}");
+    payload
+}
+
+/// Encodes a group of serialized series, halving it until each compressed body fits the wire limit
+fn encode_series_group(
+    group: &[Vec<u8>],
+    compression: Option<&types::compression::Compression>,
+    batches: &mut Vec<Result<SeriesBatch, Error>>,
+) {
+    let payload = series_payload(group);
+    let compression = match compression {
+        Some(compression) => compression,
+        None => {
+            batches.push(Ok(SeriesBatch {
+                body: payload,
+                encoding: None,
+            }));
+            return;
+        }
+    };
+    let (body, encoding) = match compression.compress(payload) {
+        Ok(compressed) => compressed,
+        Err(e) => {
+            batches.push(Err(Error::Validation(format!(
+                "failed to compress body: {}",
+                e
+            ))));
+            return;
+        }
+    };
+    if body.len() <= MAX_PAYLOAD_SIZE {
+        batches.push(Ok(SeriesBatch { body, encoding }));
+        return;
+    }
+    if group.len() == 1 {
+        batches.push(Err(Error::Validation(format!(
+            "series is {} bytes compressed, exceeding the {} byte payload limit",
+            body.len(),
+            MAX_PAYLOAD_SIZE
+        ))));
+        return;
+    }
+    let (left, right) = group.split_at(group.len() / 2);
+    encode_series_group(left, Some(compression), batches);
+    encode_series_group(right, Some(compression), batches);
+}
+
 #[async_trait]
 impl types::route::Route<SeriesResponse> for Series {
     /// The route path
@@ -162,45 +603,30 @@ impl types::route::Route<SeriesResponse> for Series {
     }
 
     /// Executes the api request
-    async fn execute(
-        self,
-    ) -> (
-        reqwest::StatusCode,
-        Result<SeriesResponse, Option<reqwest::Error>>,
-    ) {
-        // Create the request url
-        let url = format!("{}/{}", types::base::BASE_API_URL, self.path());
-        tracing::info!(target: "/v2/series", "Sending Request to {}", url);
-
-        let mut req_builder: reqwest::RequestBuilder = reqwest::Client::new().post(url);
-        req_builder = req_builder.headers(self.headers.clone());
-        req_builder = req_builder.body(self.body);
-
-        let response = req_builder.send().await;
-
-        match response {
-            Ok(r) => {
-                let status_code = r.status();
-
-                // Try to unwrap the response into json
-                match r.json::<SeriesResponse>().await {
-                    Ok(json_response) => {
-                        tracing::info!(target: "/v2/series", "Deserialized Response into json: {:?}", json_response);
-                        return (status_code, Ok(json_response));
-                    }
-                    Err(e) => {
-                        tracing::error!(target: "/v2/series", "Failed to parse error response {:?}", e);
-                        return (status_code, Err(Some(e)));
-                    }
+    ///
+    /// When typed series are split into several batches, the batch responses are aggregated and the first failed batch is returned as the error.
+    /// Use [execute_batches](Series::execute_batches) to inspect the result of every batch.
+    async fn execute(self) -> Result<types::response::Response<SeriesResponse>, Error> {
+        let mut aggregated: Option<types::response::Response<SeriesResponse>> = None;
+        let mut error = None;
+        for result in self.execute_batches().await {
+            match (result, &mut aggregated) {
+                (Ok(response), Some(aggregated)) => {
+                    aggregated.data.errors.extend(response.data.errors)
+                }
+                (Ok(response), None) => aggregated = Some(response),
+                (Err(e), _) => {
+                    tracing::error!(target: "/v2/series", "Batch failed with error {:?}", e);
+                    error.get_or_insert(e);
                 }
             }
-            Err(e) => {
-                tracing::error!(target: "/v2/series", "Request failed with error {:?}", e);
-                return (
-                    e.status().unwrap_or(reqwest::StatusCode::BAD_REQUEST),
-                    Err(Some(e)),
-                );
-            }
+        }
+        match (error, aggregated) {
+            (Some(e), _) => Err(e),
+            (None, Some(response)) => Ok(response),
+            (None, None) => Err(Error::Validation(String::from(
+                "no series batches were sent",
+            ))),
         }
     }
 }
@@ -1,11 +1,10 @@
 use std::str::FromStr;
 
 use async_trait::async_trait;
-use chrono::serde::ts_seconds_option;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::types;
+use crate::{client, types, Error};
 
 /// Tags Metrics Endpoint
 ///
@@ -78,17 +77,23 @@ pub struct Tags {
     pub headers: reqwest::header::HeaderMap,
     /// Request Body
     pub body: reqwest::Body,
+    /// The typed tag configuration, serialized in place of the body when set
+    pub attributes: Option<TagsResponseAttributes>,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
 }
 
 /// A Tags Response
-#[derive(Debug, Deserialize, Serialize)]
+///
+/// The same envelope is sent as the typed request body of the create and update routes.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct TagsResponse {
     /// Tags Response Data
     pub data: TagsResponseData,
 }
 
 /// The Tags Response Data
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct TagsResponseData {
     /// The metric type
     #[serde(rename = "type")]
@@ -100,27 +105,93 @@ pub struct TagsResponseData {
 }
 
 /// The Tags Response Attributes
-#[derive(Debug, Deserialize, Serialize)]
+///
+/// ```rust
+/// use ddog::prelude::*;
+///
+/// let attributes = tags::TagsResponseAttributes::new(vec!["app", "datacenter"])
+///     .with_metric_type(tags::MetricType::Count)
+///     .with_aggregation(tags::SpaceEnum::Sum, tags::TimeEnum::Sum);
+/// assert_eq!(
+///     serde_json::to_value(&attributes).unwrap(),
+///     serde_json::json!({
+///         "metric_type": "count",
+///         "tags": [ "app", "datacenter" ],
+///         "aggregations": [ { "space": "sum", "time": "sum" } ]
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
 pub struct TagsResponseAttributes {
     /// The time of creation
-    #[serde(with = "ts_seconds_option")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub created_at: Option<DateTime<Utc>>,
     /// The included percentiles
-    pub included_percentiles: bool,
+    #[serde(
+        default,
+        rename = "include_percentiles",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub included_percentiles: Option<bool>,
     /// The Metric's Type
-    #[serde(rename = "type")]
-    pub type_: MetricType,
+    ///
+    /// Required to create a tag configuration, the type of an existing configuration cannot be updated.
+    #[serde(
+        default,
+        rename = "metric_type",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub type_: Option<MetricType>,
     /// The time it was previously modified
-    #[serde(with = "ts_seconds_option")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub modified_at: Option<DateTime<Utc>>,
     /// Tags
+    #[serde(default)]
     pub tags: Vec<String>,
     /// Aggregations
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub aggregations: Vec<Aggregation>,
+    /// When set, the tags are excluded from queries instead of being the only queryable tags
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exclude_tags_mode: Option<bool>,
+}
+
+impl TagsResponseAttributes {
+    /// Instantiates new attributes with the queryable tag keys
+    pub fn new(tags: Vec<&str>) -> Self {
+        Self {
+            tags: tags.into_iter().map(String::from).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the Metric's Type
+    pub fn with_metric_type(mut self, type_: MetricType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Toggles percentiles for a distribution metric
+    pub fn with_included_percentiles(mut self, included_percentiles: bool) -> Self {
+        self.included_percentiles = Some(included_percentiles);
+        self
+    }
+
+    /// Adds a queryable aggregation for a count, rate or gauge metric
+    pub fn with_aggregation(mut self, space: SpaceEnum, time: TimeEnum) -> Self {
+        self.aggregations.push(Aggregation { space, time });
+        self
+    }
+
+    /// Excludes the tags from queries instead of keeping only them queryable
+    pub fn with_exclude_tags_mode(mut self, exclude_tags_mode: bool) -> Self {
+        self.exclude_tags_mode = Some(exclude_tags_mode);
+        self
+    }
 }
 
 /// A Metric Aggregation
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq)]
 pub struct Aggregation {
     /// A space aggregation for use in query.
     pub space: SpaceEnum,
@@ -129,7 +200,7 @@ pub struct Aggregation {
 }
 
 /// A Space Enum
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq)]
 pub enum SpaceEnum {
     /// Average Metric
     #[serde(rename = "avg")]
@@ -146,7 +217,7 @@ pub enum SpaceEnum {
 }
 
 /// A Time Enum
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq)]
 pub enum TimeEnum {
     /// Average Metric
     #[serde(rename = "avg")]
@@ -166,7 +237,7 @@ pub enum TimeEnum {
 }
 
 /// The Metric Types
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq)]
 pub enum MetricType {
     /// A guage metric
     #[serde(rename = "gauge")]
@@ -183,11 +254,31 @@ pub enum MetricType {
 }
 
 /// A Metric Tag
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq)]
 pub enum MetricTag {
     /// Manage Tags Response
     #[serde(rename = "manage_tags")]
     ManageTags,
+    /// A metric without a tag configuration, only returned when listing
+    #[serde(rename = "metrics")]
+    Metrics,
+}
+
+/// Serializes a tag configuration as a `manage_tags` request body for the metric
+pub(crate) fn configuration_body(
+    metric_name: &str,
+    attributes: TagsResponseAttributes,
+) -> Result<reqwest::Body, Error> {
+    let payload = TagsResponse {
+        data: TagsResponseData {
+            type_: MetricTag::ManageTags,
+            id: metric_name.to_string(),
+            attributes,
+        },
+    };
+    serde_json::to_vec(&payload)
+        .map(reqwest::Body::from)
+        .map_err(|e| Error::Validation(e.to_string()))
 }
 
 impl Default for Tags {
@@ -197,17 +288,19 @@ impl Default for Tags {
             version: types::version::ApiVersion::V2,
             headers: reqwest::header::HeaderMap::new(),
             body: reqwest::Body::from(""),
+            attributes: None,
+            client: None,
         }
     }
 }
 
 impl TryFrom<types::version::ApiVersion> for Tags {
-    type Error = &'static str;
+    type Error = Error;
 
     fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
         match v {
             types::version::ApiVersion::V2 => Ok(Self::default()),
-            _ => Err("Unsupported API Version"),
+            v => Err(Error::UnsupportedVersion(v)),
         }
     }
 }
@@ -221,6 +314,8 @@ impl Tags {
             version: types::version::ApiVersion::V2,
             headers: reqwest::header::HeaderMap::new(),
             body: reqwest::Body::from(""),
+            attributes: None,
+            client: None,
         }
     }
 
@@ -231,6 +326,27 @@ impl Tags {
         self
     }
 
+    /// Sets the typed tag configuration
+    ///
+    /// The configuration is sent as a `manage_tags` resource identified by the metric name when the route is executed, replacing any body set with [body](types::route::Route::body).
+    pub fn configuration(mut self, attributes: TagsResponseAttributes) -> Self {
+        tracing::info!(target: "/v2/metrics/{metric_name}/tags", "Tag configuration set with {} tags", attributes.tags.len());
+        self.attributes = Some(attributes);
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
     /// Creates a target identifier for logging
     pub fn target() -> String {
         String::from("v2/metrics/{metric_name}/tags")
@@ -306,52 +422,23 @@ impl types::route::Route<TagsResponse> for Tags {
     }
 
     /// Executes the api request
-    async fn execute(
-        self,
-    ) -> (
-        reqwest::StatusCode,
-        Result<TagsResponse, Option<reqwest::Error>>,
-    ) {
+    async fn execute(self) -> Result<types::response::Response<TagsResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
         // Validate the metric name
-        if self.name.is_none() {
-            return (reqwest::StatusCode::BAD_REQUEST, Err(None));
+        if self.name.as_deref().unwrap_or_default().is_empty() {
+            tracing::error!(target: "/v2/metrics/{metric_name}/tags", "Missing metric name");
+            return Err(Error::Validation(String::from("missing metric name")));
         };
-        let url = format!("{}/{}", types::base::BASE_API_URL, self.path());
-        tracing::info!(target: "/v2/metrics/{metric_name}/tags", "Sending Request to {}", url);
-
-        let mut req_builder: reqwest::RequestBuilder = reqwest::Client::new().post(url);
-        req_builder = req_builder.headers(self.headers.clone());
-        req_builder = req_builder.body(self.body);
-
-        let response = req_builder.send().await;
-
-        match response {
-            Ok(r) => {
-                let status_code = r.status();
-                if status_code != reqwest::StatusCode::from_u16(201).unwrap() {
-                    tracing::error!(target: "/v2/metrics/{metric_name}/tags", "Received non-ok status code {:?}", status_code);
-                    return (status_code, Err(None));
-                }
-
-                // Parse the response body as Json
-                match r.json::<TagsResponse>().await {
-                    Ok(s) => {
-                        tracing::info!(target: "/v2/metrics/{metric_name}/tags", "Succesfully parsed response from tags route {:?}", s);
-                        return (status_code, Ok(s));
-                    }
-                    Err(e) => {
-                        tracing::error!(target: "/v2/metrics/{metric_name}/tags", "Failed to parse response as a TagsResponse with error {:?}", e);
-                        return (reqwest::StatusCode::BAD_REQUEST, Err(Some(e)));
-                    }
-                }
-            }
-            Err(e) => {
-                tracing::error!(target: "/v2/metrics/{metric_name}/tags", "Request failed with error {:?}", e);
-                return (
-                    e.status().unwrap_or(reqwest::StatusCode::BAD_REQUEST),
-                    Err(Some(e)),
-                );
-            }
-        }
+        tracing::info!(target: "/v2/metrics/{metric_name}/tags", "Sending Request to {}", self.path());
+        let path = self.path();
+        let body = match (self.attributes, self.name) {
+            (Some(attributes), Some(name)) => configuration_body(&name, attributes)?,
+            _ => self.body,
+        };
+        let req_builder = client
+            .request(reqwest::Method::POST, &path)
+            .headers(self.headers)
+            .body(body);
+        client.send(req_builder).await
     }
 }
@@ -0,0 +1,198 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+
+use crate::{client, types, Error};
+
+use super::get_metadata::MetricMetadata;
+
+/// Update Metric Metadata Endpoint
+///
+/// ## Overview
+///
+/// Edit metadata of a specific metric. This endpoint requires the `metrics_metadata_write` authorization [scope](https://docs.datadoghq.com/api/latest/scopes/#metrics).
+///
+/// Endpoint Format: `/v1/metrics/{metric_name}` [PUT]
+///
+/// ## Arguments
+///
+/// - metric_name [required]
+///    - type: `string`
+///    - description: Name of the metric for which to edit metadata.
+///
+/// ## Request
+///
+/// - Body Data (required)
+///   - description [type: string] - Metric description.
+///   - per_unit [type: string] - Per unit of the metric such as `second` in `bytes per second`.
+///   - short_name [type: string] - A more human-readable and abbreviated version of the metric name.
+///   - statsd_interval [type: int64] - StatsD flush interval of the metric in seconds if applicable.
+///   - type [type: string] - Metric type such as `gauge` or `rate`.
+///   - unit [type: string] - Primary unit of the metric such as `byte` or `operation`.
+///
+/// ## Response
+///
+/// One of: [200, 400, 403, 404, 429]
+///
+/// The updated metadata, see [MetricMetadata](super::get_metadata::MetricMetadata).
+#[derive(Debug)]
+pub struct UpdateMetadata {
+    /// The metric name
+    pub name: Option<String>,
+    /// The api version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The typed metadata, serialized in place of the body when set
+    pub metadata: Option<MetricMetadata>,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+impl Default for UpdateMetadata {
+    fn default() -> Self {
+        Self {
+            name: None,
+            version: types::version::ApiVersion::V1,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            metadata: None,
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for UpdateMetadata {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V1 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl UpdateMetadata {
+    /// Instantiates a new metadata update for the metric
+    pub fn new(metric_name: &str) -> Self {
+        tracing::info!(target: "/v1/metrics/{metric_name}", "Update Metadata Route Created");
+        Self {
+            name: Some(metric_name.to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the Metric Name
+    pub fn set_metric_name(mut self, metric_name: String) -> Self {
+        tracing::info!(target: "/v1/metrics/{metric_name}", "Metadata Metric Name Set to {}", metric_name);
+        self.name = Some(metric_name);
+        self
+    }
+
+    /// Sets the typed metadata
+    ///
+    /// The metadata is serialized as the request body when the route is executed, replacing any body set with [body](types::route::Route::body).
+    pub fn metadata(mut self, metadata: MetricMetadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v1/metrics/{metric_name}")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<MetricMetadata> for UpdateMetadata {
+    /// The route path
+    fn path(&self) -> String {
+        format!("v1/metrics/{}", self.name.as_deref().unwrap_or_default())
+    }
+
+    /// Route does nothing here since the route path is set with the metric name
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<MetricMetadata>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        if self.name.as_deref().unwrap_or_default().is_empty() {
+            tracing::error!(target: "/v1/metrics/{metric_name}", "Missing metric name");
+            return Err(Error::Validation(String::from("missing metric name")));
+        }
+        let path = self.path();
+        tracing::info!(target: "/v1/metrics/{metric_name}", "Sending Request to {}", path);
+        let body = match self.metadata {
+            Some(metadata) => reqwest::Body::from(
+                serde_json::to_vec(&metadata).map_err(|e| Error::Validation(e.to_string()))?,
+            ),
+            None => self.body,
+        };
+        let req_builder = client
+            .request(reqwest::Method::PUT, &path)
+            .headers(self.headers)
+            .body(body);
+        client.send(req_builder).await
+    }
+}
@@ -0,0 +1,207 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+
+use crate::{client, types, Error};
+
+use super::tags::{configuration_body, TagsResponse, TagsResponseAttributes};
+
+/// Update Tags Metrics Endpoint
+///
+/// ## Overview
+///
+/// Update the tag configuration of a metric or percentile aggregations of a distribution metric or custom aggregations of a count, rate, or gauge metric.
+/// Can only be used with application keys from users with the Manage Tags for Metrics permission.
+///
+/// Endpoint Format: `/v2/metrics/{metric_name}/tags` [PATCH]
+///
+/// ## Arguments
+///
+/// - metric_name [required]
+///    - type: `string`
+///    - description: The name of the metric.
+///
+/// ## Request
+///
+/// - Body Data (required)
+///   - data [required] [type: object] - Object for a single tag configuration to be edited.
+///     - attributes [type: object] - Object containing the definition of a metric tag configuration to be updated.
+///       - tags [type: string[]] - A list of tag keys that will be queryable for your metric.
+///       - include_percentiles [type: boolean] - Toggle to include/exclude percentiles for a distribution metric.
+///       - aggregations [type: object[]] - A list of queryable aggregation combinations for a count, rate, or gauge metric.
+///       - exclude_tags_mode [type: boolean] - When set to true, the configuration will exclude the configured tags and include any other submitted tags.
+///     - id [required] [type: string] - The metric name for this resource.
+///     - type [required] [type: enum] - The metric tag configuration resource type. Allowed enum values: manage_tags
+///
+/// ## Response
+///
+/// One of: [200, 400, 403, 422, 429]
+///
+/// The updated tag configuration, see [TagsResponse](super::tags::TagsResponse).
+#[derive(Debug)]
+pub struct UpdateTags {
+    /// The metric name
+    pub name: Option<String>,
+    /// The api version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The typed tag configuration, serialized in place of the body when set
+    pub attributes: Option<TagsResponseAttributes>,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+impl Default for UpdateTags {
+    fn default() -> Self {
+        Self {
+            name: None,
+            version: types::version::ApiVersion::V2,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            attributes: None,
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for UpdateTags {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V2 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl UpdateTags {
+    /// Instantiates a new tag configuration update for the metric
+    pub fn new(metric_name: &str) -> Self {
+        tracing::info!(target: "/v2/metrics/{metric_name}/tags", "Update Tags Route Created");
+        Self {
+            name: Some(metric_name.to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the Metric Name
+    pub fn set_metric_name(mut self, metric_name: String) -> Self {
+        tracing::info!(target: "/v2/metrics/{metric_name}/tags", "Tag Metric Name Set to {}", metric_name);
+        self.name = Some(metric_name);
+        self
+    }
+
+    /// Sets the typed tag configuration
+    ///
+    /// The metric type of an existing configuration cannot be updated and should be left unset.
+    /// The configuration is serialized as the request body when the route is executed, replacing any body set with [body](types::route::Route::body).
+    pub fn configuration(mut self, attributes: TagsResponseAttributes) -> Self {
+        tracing::info!(target: "/v2/metrics/{metric_name}/tags", "Tag configuration set with {} tags", attributes.tags.len());
+        self.attributes = Some(attributes);
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v2/metrics/{metric_name}/tags")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<TagsResponse> for UpdateTags {
+    /// The route path
+    fn path(&self) -> String {
+        format!(
+            "v2/metrics/{}/tags",
+            self.name.as_deref().unwrap_or_default()
+        )
+    }
+
+    /// Route does nothing here since the route path is set with the metric name
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<TagsResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        let path = self.path();
+        let name = match self.name {
+            Some(name) if !name.is_empty() => name,
+            _ => {
+                tracing::error!(target: "/v2/metrics/{metric_name}/tags", "Missing metric name");
+                return Err(Error::Validation(String::from("missing metric name")));
+            }
+        };
+        tracing::info!(target: "/v2/metrics/{metric_name}/tags", "Sending Request to {}", path);
+        let body = match self.attributes {
+            Some(attributes) => configuration_body(&name, attributes)?,
+            None => self.body,
+        };
+        let req_builder = client
+            .request(reqwest::Method::PATCH, &path)
+            .headers(self.headers)
+            .body(body);
+        client.send(req_builder).await
+    }
+}
@@ -0,0 +1,237 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+/// Metric Volumes Endpoint
+///
+/// ## Overview
+///
+/// View distinct metrics volumes for the given metric name.
+///
+/// Custom metrics generated in-app from other products will return `null` for ingested volumes.
+///
+/// Endpoint Format: `/v2/metrics/{metric_name}/volumes` [GET]
+///
+/// ## Arguments
+///
+/// - metric_name [required]
+///    - type: `string`
+///    - description: The name of the metric.
+///
+/// ## Response
+///
+/// One of: [200, 400, 403, 404, 429]
+///
+/// - data [type: object] - One of the distinct volume of a distribution metric or the ingested and indexed volumes of other metrics.
+///    - type [type: enum] - Allowed enum values: distinct_metric_volumes, metric_volumes
+///    - id [type: string] - The metric name for this resource.
+///    - attributes [type: object]
+///       - distinct_volume [type: int64] - Distinct volume for the given metric.
+///       - indexed_volume [type: int64] - Indexed volume for the given metric.
+///       - ingested_volume [type: int64] - Ingested volume for the given metric.
+///
+/// #### Example
+///
+/// ```json
+/// {
+///     "data": {
+///         "type": "metric_volumes",
+///         "id": "rpc.latency",
+///         "attributes": { "indexed_volume": 10, "ingested_volume": 20 }
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Volumes {
+    /// The metric name
+    pub name: Option<String>,
+    /// The api version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// A Metric Volumes Response
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct MetricVolumesResponse {
+    /// The metric volumes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<MetricVolumes>,
+}
+
+/// The volumes of a metric
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
+pub struct MetricVolumes {
+    /// The volumes resource type
+    #[serde(rename = "type")]
+    pub type_: MetricVolumesType,
+    /// The metric name
+    pub id: String,
+    /// The volumes
+    #[serde(default)]
+    pub attributes: MetricVolumesAttributes,
+}
+
+/// The volumes of a metric, distinct for distributions and ingested and indexed otherwise
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct MetricVolumesAttributes {
+    /// Distinct volume of a distribution metric
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distinct_volume: Option<i64>,
+    /// Indexed volume of a count, gauge or rate metric
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indexed_volume: Option<i64>,
+    /// Ingested volume of a count, gauge or rate metric
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ingested_volume: Option<i64>,
+}
+
+/// The Metric Volumes resource types
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricVolumesType {
+    /// The distinct volume of a distribution metric
+    DistinctMetricVolumes,
+    /// The ingested and indexed volumes of a count, gauge or rate metric
+    MetricVolumes,
+}
+
+impl Default for Volumes {
+    fn default() -> Self {
+        Self {
+            name: None,
+            version: types::version::ApiVersion::V2,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for Volumes {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V2 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl Volumes {
+    /// Instantiates a new volumes retrieval for the metric
+    pub fn new(metric_name: &str) -> Self {
+        tracing::info!(target: "/v2/metrics/{metric_name}/volumes", "Volumes Route Created");
+        Self {
+            name: Some(metric_name.to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the Metric Name
+    pub fn set_metric_name(mut self, metric_name: String) -> Self {
+        tracing::info!(target: "/v2/metrics/{metric_name}/volumes", "Volumes Metric Name Set to {}", metric_name);
+        self.name = Some(metric_name);
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v2/metrics/{metric_name}/volumes")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<MetricVolumesResponse> for Volumes {
+    /// The route path
+    fn path(&self) -> String {
+        format!(
+            "v2/metrics/{}/volumes",
+            self.name.as_deref().unwrap_or_default()
+        )
+    }
+
+    /// Route does nothing here since the route path is set with the metric name
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<MetricVolumesResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        if self.name.as_deref().unwrap_or_default().is_empty() {
+            tracing::error!(target: "/v2/metrics/{metric_name}/volumes", "Missing metric name");
+            return Err(Error::Validation(String::from("missing metric name")));
+        }
+        let path = self.path();
+        tracing::info!(target: "/v2/metrics/{metric_name}/volumes", "Sending Request to {}", path);
+        let req_builder = client
+            .request(reqwest::Method::GET, &path)
+            .headers(self.headers);
+        client.send(req_builder).await
+    }
+}
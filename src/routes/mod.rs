@@ -3,7 +3,24 @@
 /// Metrics endpoints
 pub mod metrics;
 
+/// Events endpoints
+pub mod events;
+
+/// Logs endpoints
+pub mod logs;
+
+/// Monitors endpoints
+pub mod monitors;
+
 /// Prelude to re-export common types
 pub mod prelude {
-    pub use super::metrics::{self, *};
+    pub use super::{
+        events::{self, get_event, list_events, post_event},
+        logs::{self, search_logs, send_logs},
+        metrics::{self, *},
+        monitors::{
+            self, create_monitor, delete_monitor, get_monitor, list_monitors, mute_monitor,
+            search_monitors, unmute_monitor, update_monitor, validate_monitor,
+        },
+    };
 }
@@ -0,0 +1,625 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+/// Read-only monitor fields not modeled by this crate, kept in [Monitor::extra] when read but never sent back
+pub const READ_ONLY_FIELDS: [&str; 5] = [
+    "deleted",
+    "matching_downtimes",
+    "org_id",
+    "overall_state_modified",
+    "state",
+];
+
+/// Create Monitor Endpoint
+///
+/// ## Overview
+///
+/// Create a monitor using the specified options. This endpoint requires the `monitors_write` authorization [scope](https://docs.datadoghq.com/api/latest/scopes/#monitors).
+///
+/// Endpoint Format: `/v1/monitor` [POST]
+///
+/// ## Request
+///
+/// - Body Data (required), see [Monitor]
+///   - type [required] [type: enum] - The type of the monitor, such as `metric alert`, `query alert`, `log alert` or `service check`.
+///   - query [required] [type: string] - The monitor query.
+///   - name [type: string] - The monitor name.
+///   - message [type: string] - A message to include with notifications for this monitor.
+///   - tags [type: string[]] - Tags associated to your monitor.
+///   - priority [type: int64] - Integer from 1 (high) to 5 (low) indicating alert severity.
+///   - options [type: object] - List of options associated with your monitor, such as `thresholds`, `notify_no_data` and `renotify_interval`.
+///
+/// ## Response
+///
+/// One of: [200, 400, 403, 429]
+///
+/// The created monitor, see [Monitor].
+///
+/// #### Example
+///
+/// ```json
+/// {
+///     "id": 1234567,
+///     "type": "metric alert",
+///     "name": "Disk usage is high",
+///     "query": "avg(last_5m):avg:system.disk.in_use{service:web} by {host} > 0.9",
+///     "message": "Disk usage is high on {{host.name}} @slack-ops",
+///     "tags": [ "service:web" ],
+///     "priority": 2,
+///     "options": { "thresholds": { "critical": 0.9, "warning": 0.8 }, "notify_no_data": true },
+///     "created": "2023-11-14T22:13:20.000000+00:00",
+///     "modified": "2023-11-14T22:13:20.000000+00:00",
+///     "creator": { "email": "sue@example.com", "handle": "sue@example.com", "name": "Sue" },
+///     "multi": true,
+///     "overall_state": "No Data"
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CreateMonitor {
+    /// The api version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The typed monitor to create, serialized in place of the body when set
+    pub monitor: Option<Monitor>,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// A Monitor
+///
+/// The same object is used to create and update monitors and returned when reading them, the `id`, `created`, `modified`, `creator`, `multi` and `overall_state` fields
+/// and the unmodeled [READ_ONLY_FIELDS] are only set by Datadog.
+///
+/// ```rust
+/// use ddog::prelude::*;
+/// use create_monitor::*;
+///
+/// let monitor = Monitor::new(
+///     MonitorType::MetricAlert,
+///     "Disk usage is high",
+///     "avg(last_5m):avg:system.disk.in_use{service:web} by {host} > 0.9",
+/// )
+/// .with_message("Disk usage is high on {{host.name}} @slack-ops")
+/// .with_tag("service:web")
+/// .with_priority(2)
+/// .with_options(
+///     MonitorOptions::new()
+///         .with_thresholds(MonitorThresholds::critical(0.9).with_warning(0.8))
+///         .with_notify_no_data(true)
+///         .with_renotify_interval(60),
+/// );
+/// assert_eq!(
+///     serde_json::to_value(&monitor).unwrap(),
+///     serde_json::json!({
+///         "type": "metric alert",
+///         "name": "Disk usage is high",
+///         "query": "avg(last_5m):avg:system.disk.in_use{service:web} by {host} > 0.9",
+///         "message": "Disk usage is high on {{host.name}} @slack-ops",
+///         "tags": [ "service:web" ],
+///         "priority": 2,
+///         "options": {
+///             "thresholds": { "critical": 0.9, "warning": 0.8 },
+///             "notify_no_data": true,
+///             "renotify_interval": 60
+///         }
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct Monitor {
+    /// The monitor id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    /// The monitor type
+    #[serde(rename = "type")]
+    pub type_: MonitorType,
+    /// The monitor name
+    #[serde(default)]
+    pub name: String,
+    /// The monitor query
+    #[serde(default)]
+    pub query: String,
+    /// The notification message of the monitor
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// The tags of the monitor
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// The priority of the monitor, from 1 to 5
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    /// The monitor options
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<MonitorOptions>,
+    /// When the monitor was created
+    #[serde(default, skip_serializing)]
+    pub created: Option<DateTime<Utc>>,
+    /// When the monitor was last modified
+    #[serde(default, skip_serializing)]
+    pub modified: Option<DateTime<Utc>>,
+    /// The creator of the monitor
+    #[serde(default, skip_serializing)]
+    pub creator: Option<MonitorCreator>,
+    /// Whether the monitor is a multi alert, evaluating each group of the query separately
+    #[serde(default, skip_serializing)]
+    pub multi: Option<bool>,
+    /// The current state of the monitor
+    #[serde(default, skip_serializing)]
+    pub overall_state: Option<MonitorOverallState>,
+    /// Fields not modeled by this crate, sent back as is on update except the [READ_ONLY_FIELDS]
+    #[serde(flatten, serialize_with = "serialize_extra")]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+/// Serializes the unmodeled fields of a monitor, skipping the read-only ones
+fn serialize_extra<S: serde::Serializer>(
+    extra: &BTreeMap<String, serde_json::Value>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(
+        extra
+            .iter()
+            .filter(|(key, _)| !READ_ONLY_FIELDS.contains(&key.as_str())),
+    )
+}
+
+/// The options of a monitor
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct MonitorOptions {
+    /// The alert thresholds of the monitor
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thresholds: Option<MonitorThresholds>,
+    /// Whether the monitor notifies when data stops reporting
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notify_no_data: Option<bool>,
+    /// The number of minutes without data before the monitor notifies
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_data_timeframe: Option<i64>,
+    /// The number of minutes after the last notification before the monitor re-notifies
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub renotify_interval: Option<i64>,
+    /// The message included with re-notifications
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub escalation_message: Option<String>,
+    /// Whether tagged users are notified of changes to the monitor
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notify_audit: Option<bool>,
+    /// Whether the triggering tags are included in the notification title
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_tags: Option<bool>,
+    /// Whether the monitor needs a full window of data before it is evaluated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_full_window: Option<bool>,
+    /// The number of seconds to delay evaluation by
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evaluation_delay: Option<i64>,
+    /// The number of seconds new groups are ignored for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_group_delay: Option<i64>,
+    /// The number of hours after which a triggered monitor without data resolves
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_h: Option<i64>,
+    /// The muted scopes of the monitor, with the optional POSIX timestamp at which each is unmuted
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub silenced: BTreeMap<String, Option<i64>>,
+    /// Options not modeled by this crate, such as `notify_by`, `on_missing_data` or `scheduling_options`, sent back as is on update
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+/// The alert thresholds of a monitor
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct MonitorThresholds {
+    /// The critical threshold
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub critical: Option<f64>,
+    /// The critical recovery threshold
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub critical_recovery: Option<f64>,
+    /// The ok threshold of service checks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ok: Option<f64>,
+    /// The unknown threshold of service checks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unknown: Option<f64>,
+    /// The warning threshold
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<f64>,
+    /// The warning recovery threshold
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning_recovery: Option<f64>,
+}
+
+/// The creator of a monitor
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct MonitorCreator {
+    /// Email of the creator
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Handle of the creator
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+    /// Name of the creator
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The Monitor Types
+///
+/// Types this crate does not know yet, such as `network-performance alert` or `cost alert`, are kept as [Other](MonitorType::Other) so they round-trip on update.
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Hash)]
+pub enum MonitorType {
+    /// A composite monitor
+    #[serde(rename = "composite")]
+    Composite,
+    /// An event monitor
+    #[serde(rename = "event alert")]
+    EventAlert,
+    /// An event monitor using the events search
+    #[serde(rename = "event-v2 alert")]
+    EventV2Alert,
+    /// A log monitor
+    #[serde(rename = "log alert")]
+    LogAlert,
+    /// A metric monitor
+    #[serde(rename = "metric alert")]
+    MetricAlert,
+    /// A process monitor
+    #[serde(rename = "process alert")]
+    ProcessAlert,
+    /// A metric query monitor
+    #[serde(rename = "query alert")]
+    QueryAlert,
+    /// A real user monitoring monitor
+    #[serde(rename = "rum alert")]
+    RumAlert,
+    /// A service check monitor
+    #[serde(rename = "service check")]
+    ServiceCheck,
+    /// A synthetics monitor
+    #[serde(rename = "synthetics alert")]
+    SyntheticsAlert,
+    /// An APM trace analytics monitor
+    #[serde(rename = "trace-analytics alert")]
+    TraceAnalyticsAlert,
+    /// A service level objective monitor
+    #[serde(rename = "slo alert")]
+    SloAlert,
+    /// An audit trail monitor
+    #[serde(rename = "audit alert")]
+    AuditAlert,
+    /// A CI pipelines monitor
+    #[serde(rename = "ci-pipelines alert")]
+    CiPipelinesAlert,
+    /// A CI tests monitor
+    #[serde(rename = "ci-tests alert")]
+    CiTestsAlert,
+    /// An error tracking monitor
+    #[serde(rename = "error-tracking alert")]
+    ErrorTrackingAlert,
+    /// A database monitoring monitor
+    #[serde(rename = "database-monitoring alert")]
+    DatabaseMonitoringAlert,
+    /// Any other monitor type
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// The Monitor States
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq, Hash)]
+pub enum MonitorOverallState {
+    /// The monitor is alerting
+    Alert,
+    /// The monitor is ignored
+    Ignored,
+    /// The monitor has no data
+    #[serde(rename = "No Data")]
+    NoData,
+    /// The monitor is ok
+    OK,
+    /// The monitor evaluation was skipped
+    Skipped,
+    /// The monitor state is unknown
+    Unknown,
+    /// The monitor is warning
+    Warn,
+}
+
+impl Monitor {
+    /// Instantiates a new monitor of the given type, name and query
+    pub fn new(type_: MonitorType, name: &str, query: &str) -> Self {
+        Self {
+            id: None,
+            type_,
+            name: name.to_string(),
+            query: query.to_string(),
+            message: None,
+            tags: vec![],
+            priority: None,
+            options: None,
+            created: None,
+            modified: None,
+            creator: None,
+            multi: None,
+            overall_state: None,
+            extra: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the notification message
+    pub fn with_message(mut self, message: &str) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+
+    /// Adds a tag
+    pub fn with_tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    /// Adds a list of tags
+    pub fn with_tags(mut self, tags: Vec<&str>) -> Self {
+        self.tags.extend(tags.into_iter().map(String::from));
+        self
+    }
+
+    /// Sets the priority, from 1 to 5
+    pub fn with_priority(mut self, priority: i64) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Sets the options
+    pub fn with_options(mut self, options: MonitorOptions) -> Self {
+        self.options = Some(options);
+        self
+    }
+}
+
+impl MonitorOptions {
+    /// Instantiates empty monitor options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the alert thresholds
+    pub fn with_thresholds(mut self, thresholds: MonitorThresholds) -> Self {
+        self.thresholds = Some(thresholds);
+        self
+    }
+
+    /// Sets whether the monitor notifies when data stops reporting
+    pub fn with_notify_no_data(mut self, notify_no_data: bool) -> Self {
+        self.notify_no_data = Some(notify_no_data);
+        self
+    }
+
+    /// Sets the number of minutes without data before the monitor notifies
+    pub fn with_no_data_timeframe(mut self, no_data_timeframe: i64) -> Self {
+        self.no_data_timeframe = Some(no_data_timeframe);
+        self
+    }
+
+    /// Sets the number of minutes after the last notification before the monitor re-notifies
+    pub fn with_renotify_interval(mut self, renotify_interval: i64) -> Self {
+        self.renotify_interval = Some(renotify_interval);
+        self
+    }
+
+    /// Sets the message included with re-notifications
+    pub fn with_escalation_message(mut self, escalation_message: &str) -> Self {
+        self.escalation_message = Some(escalation_message.to_string());
+        self
+    }
+
+    /// Sets whether the triggering tags are included in the notification title
+    pub fn with_include_tags(mut self, include_tags: bool) -> Self {
+        self.include_tags = Some(include_tags);
+        self
+    }
+
+    /// Sets whether the monitor needs a full window of data before it is evaluated
+    pub fn with_require_full_window(mut self, require_full_window: bool) -> Self {
+        self.require_full_window = Some(require_full_window);
+        self
+    }
+
+    /// Sets the number of seconds to delay evaluation by
+    pub fn with_evaluation_delay(mut self, evaluation_delay: i64) -> Self {
+        self.evaluation_delay = Some(evaluation_delay);
+        self
+    }
+
+    /// Sets the number of seconds new groups are ignored for
+    pub fn with_new_group_delay(mut self, new_group_delay: i64) -> Self {
+        self.new_group_delay = Some(new_group_delay);
+        self
+    }
+
+    /// Sets the number of hours after which a triggered monitor without data resolves
+    pub fn with_timeout_h(mut self, timeout_h: i64) -> Self {
+        self.timeout_h = Some(timeout_h);
+        self
+    }
+}
+
+impl MonitorThresholds {
+    /// Instantiates thresholds with the critical threshold
+    pub fn critical(critical: f64) -> Self {
+        Self {
+            critical: Some(critical),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the critical recovery threshold
+    pub fn with_critical_recovery(mut self, critical_recovery: f64) -> Self {
+        self.critical_recovery = Some(critical_recovery);
+        self
+    }
+
+    /// Sets the warning threshold
+    pub fn with_warning(mut self, warning: f64) -> Self {
+        self.warning = Some(warning);
+        self
+    }
+
+    /// Sets the warning recovery threshold
+    pub fn with_warning_recovery(mut self, warning_recovery: f64) -> Self {
+        self.warning_recovery = Some(warning_recovery);
+        self
+    }
+
+    /// Sets the ok threshold of service checks
+    pub fn with_ok(mut self, ok: f64) -> Self {
+        self.ok = Some(ok);
+        self
+    }
+
+    /// Sets the unknown threshold of service checks
+    pub fn with_unknown(mut self, unknown: f64) -> Self {
+        self.unknown = Some(unknown);
+        self
+    }
+}
+
+impl Default for CreateMonitor {
+    fn default() -> Self {
+        Self {
+            version: types::version::ApiVersion::V1,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            monitor: None,
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for CreateMonitor {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V1 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl CreateMonitor {
+    /// Instantiates a new monitor creation
+    pub fn new() -> Self {
+        tracing::info!(target: "/v1/monitor", "Create Monitor Route Created");
+        Self::default()
+    }
+
+    /// Sets the typed monitor to create
+    ///
+    /// The monitor is serialized as the request body when the route is executed, replacing any body set with [body](types::route::Route::body).
+    pub fn monitor(mut self, monitor: Monitor) -> Self {
+        tracing::info!(target: "/v1/monitor", "Monitor set with name {}", monitor.name);
+        self.monitor = Some(monitor);
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v1/monitor")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<Monitor> for CreateMonitor {
+    /// The route path
+    fn path(&self) -> String {
+        String::from("v1/monitor")
+    }
+
+    /// Route does nothing here since the route path is fixed
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<Monitor>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        let path = self.path();
+        tracing::info!(target: "/v1/monitor", "Sending Request to {}", path);
+        let body = match self.monitor {
+            Some(monitor) => reqwest::Body::from(
+                serde_json::to_vec(&monitor).map_err(|e| Error::Validation(e.to_string()))?,
+            ),
+            None => self.body,
+        };
+        let req_builder = client
+            .request(reqwest::Method::POST, &path)
+            .headers(self.headers)
+            .body(body);
+        client.send(req_builder).await
+    }
+}
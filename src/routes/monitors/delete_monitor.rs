@@ -0,0 +1,202 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+/// Delete Monitor Endpoint
+///
+/// ## Overview
+///
+/// Delete the specified monitor. This endpoint requires the `monitors_write` authorization [scope](https://docs.datadoghq.com/api/latest/scopes/#monitors).
+///
+/// Endpoint Format: `/v1/monitor/{monitor_id}` [DELETE]
+///
+/// ## Arguments
+///
+/// - monitor_id [required]
+///    - type: `int64`
+///    - description: The id of the monitor.
+///
+/// **Query Strings**
+///
+/// - force [type: string] - Delete the monitor even if it's referenced by other resources (for example SLO, composite monitor).
+///
+/// ## Response
+///
+/// One of: [200, 400, 401, 403, 404, 429]
+///
+/// - deleted_monitor_id [type: int64] - The id of the deleted monitor.
+#[derive(Debug)]
+pub struct DeleteMonitor {
+    /// The monitor id
+    pub monitor_id: Option<i64>,
+    /// Whether the monitor is deleted even if it is referenced by other resources
+    pub force: Option<bool>,
+    /// The api version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// A Delete Monitor Response
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct DeleteMonitorResponse {
+    /// The id of the deleted monitor
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_monitor_id: Option<i64>,
+}
+
+impl Default for DeleteMonitor {
+    fn default() -> Self {
+        Self {
+            monitor_id: None,
+            force: None,
+            version: types::version::ApiVersion::V1,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for DeleteMonitor {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V1 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl DeleteMonitor {
+    /// Instantiates a new monitor deletion for the given monitor id
+    pub fn new(monitor_id: i64) -> Self {
+        tracing::info!(target: "/v1/monitor/{monitor_id}", "Delete Monitor Route Created");
+        Self {
+            monitor_id: Some(monitor_id),
+            ..Self::default()
+        }
+    }
+
+    /// Set the Monitor Id
+    pub fn set_monitor_id(mut self, monitor_id: i64) -> Self {
+        tracing::info!(target: "/v1/monitor/{monitor_id}", "Monitor id set to {}", monitor_id);
+        self.monitor_id = Some(monitor_id);
+        self
+    }
+
+    /// Deletes the monitor even if it is referenced by other resources, such as composite monitors or service level objectives
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = Some(force);
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// The query string parameters, omitting an unset force
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        self.force
+            .map(|force| ("force", force.to_string()))
+            .into_iter()
+            .collect()
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v1/monitor/{monitor_id}")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<DeleteMonitorResponse> for DeleteMonitor {
+    /// The route path
+    fn path(&self) -> String {
+        match self.monitor_id {
+            Some(monitor_id) => format!("v1/monitor/{}", monitor_id),
+            None => String::from("v1/monitor"),
+        }
+    }
+
+    /// Route does nothing here since the route path is set with the monitor id
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<DeleteMonitorResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        if self.monitor_id.is_none() {
+            tracing::error!(target: "/v1/monitor/{monitor_id}", "Missing monitor id");
+            return Err(Error::Validation(String::from("missing monitor id")));
+        }
+        let path = self.path();
+        tracing::info!(target: "/v1/monitor/{monitor_id}", "Sending Request to {}", path);
+        let req_builder = client
+            .request(reqwest::Method::DELETE, &path)
+            .query(&self.query())
+            .headers(self.headers);
+        client.send(req_builder).await
+    }
+}
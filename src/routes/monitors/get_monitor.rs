@@ -0,0 +1,209 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+
+use crate::{client, types, Error};
+
+use super::create_monitor::Monitor;
+
+/// Get Monitor Endpoint
+///
+/// ## Overview
+///
+/// Get details about the specified monitor. This endpoint requires the `monitors_read` authorization [scope](https://docs.datadoghq.com/api/latest/scopes/#monitors).
+///
+/// Endpoint Format: `/v1/monitor/{monitor_id}` [GET]
+///
+/// ## Arguments
+///
+/// - monitor_id [required]
+///    - type: `int64`
+///    - description: The id of the monitor.
+///
+/// **Query Strings**
+///
+/// - group_states [type: string] - When specified, shows additional information about the group states. Choose one or more from `all`, `alert`, `warn`, and `no data`.
+/// - with_downtimes [type: boolean] - If this argument is set to true, then the returned data includes all current active downtimes for the monitor.
+///
+/// ## Response
+///
+/// One of: [200, 400, 403, 404, 429]
+///
+/// The monitor, see [Monitor](super::create_monitor::Monitor).
+#[derive(Debug)]
+pub struct GetMonitor {
+    /// The monitor id
+    pub monitor_id: Option<i64>,
+    /// The group states to include in the response
+    pub group_states: Vec<String>,
+    /// Whether downtimes are included in the response
+    pub with_downtimes: Option<bool>,
+    /// The api version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+impl Default for GetMonitor {
+    fn default() -> Self {
+        Self {
+            monitor_id: None,
+            group_states: vec![],
+            with_downtimes: None,
+            version: types::version::ApiVersion::V1,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for GetMonitor {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V1 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl GetMonitor {
+    /// Instantiates a new monitor retrieval for the given monitor id
+    pub fn new(monitor_id: i64) -> Self {
+        tracing::info!(target: "/v1/monitor/{monitor_id}", "Get Monitor Route Created");
+        Self {
+            monitor_id: Some(monitor_id),
+            ..Self::default()
+        }
+    }
+
+    /// Set the Monitor Id
+    pub fn set_monitor_id(mut self, monitor_id: i64) -> Self {
+        tracing::info!(target: "/v1/monitor/{monitor_id}", "Monitor id set to {}", monitor_id);
+        self.monitor_id = Some(monitor_id);
+        self
+    }
+
+    /// Sets the group states to include in the response, such as `alert`, `warn`, `no data` or `all`
+    pub fn with_group_states(mut self, group_states: Vec<&str>) -> Self {
+        self.group_states = group_states.into_iter().map(String::from).collect();
+        self
+    }
+
+    /// Sets whether the downtimes of the monitors are included in the response
+    pub fn with_downtimes(mut self, with_downtimes: bool) -> Self {
+        self.with_downtimes = Some(with_downtimes);
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// The query string parameters, omitting unset filters
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![];
+        if !self.group_states.is_empty() {
+            query.push(("group_states", self.group_states.join(",")));
+        }
+        if let Some(with_downtimes) = self.with_downtimes {
+            query.push(("with_downtimes", with_downtimes.to_string()));
+        }
+        query
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v1/monitor/{monitor_id}")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<Monitor> for GetMonitor {
+    /// The route path
+    fn path(&self) -> String {
+        match self.monitor_id {
+            Some(monitor_id) => format!("v1/monitor/{}", monitor_id),
+            None => String::from("v1/monitor"),
+        }
+    }
+
+    /// Route does nothing here since the route path is set with the monitor id
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<Monitor>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        if self.monitor_id.is_none() {
+            tracing::error!(target: "/v1/monitor/{monitor_id}", "Missing monitor id");
+            return Err(Error::Validation(String::from("missing monitor id")));
+        }
+        let path = self.path();
+        tracing::info!(target: "/v1/monitor/{monitor_id}", "Sending Request to {}", path);
+        let req_builder = client
+            .request(reqwest::Method::GET, &path)
+            .query(&self.query())
+            .headers(self.headers);
+        client.send(req_builder).await
+    }
+}
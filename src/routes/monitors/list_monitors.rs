@@ -0,0 +1,258 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+
+use crate::{client, types, Error};
+
+use super::create_monitor::Monitor;
+
+/// List Monitors Endpoint
+///
+/// ## Overview
+///
+/// Get details about the monitors, optionally filtered. This endpoint requires the `monitors_read` authorization [scope](https://docs.datadoghq.com/api/latest/scopes/#monitors).
+///
+/// Endpoint Format: `/v1/monitor` [GET]
+///
+/// ## Arguments
+///
+/// **Query Strings**
+///
+/// - group_states [type: string] - When specified, shows additional information about the group states. Choose one or more from `all`, `alert`, `warn`, and `no data`.
+/// - name [type: string] - A string to filter monitors by name.
+/// - tags [type: string] - A comma separated list indicating what tags, if any, should be used to filter the list of monitors by scope. For example, `host:host0`.
+/// - monitor_tags [type: string] - A comma separated list indicating what service and/or custom tags, if any, should be used to filter the list of monitors.
+/// - with_downtimes [type: boolean] - If this argument is set to true, then the returned data includes all current active downtimes for each monitor.
+/// - id_offset [type: int64] - Use this parameter for paginating through large sets of monitors. Start with a value of zero, make a request, set the value to the last ID of result set, and then repeat until the response is empty.
+/// - page [type: int64] - The page to start paginating from. If this argument is not specified, the request returns all monitors without pagination.
+/// - page_size [type: int32] - The number of monitors to return per page. If the page argument is not specified, the default behavior returns all monitors without a page_size limit.
+///
+/// ## Response
+///
+/// One of: [200, 400, 403, 429]
+///
+/// An array of monitors, see [Monitor](super::create_monitor::Monitor).
+#[derive(Debug)]
+pub struct ListMonitors {
+    /// The group states to include in the response
+    pub group_states: Vec<String>,
+    /// Whether downtimes are included in the response
+    pub with_downtimes: Option<bool>,
+    /// Only return monitors whose name contains the string
+    pub name: Option<String>,
+    /// Only return monitors with the scope tags
+    pub tags: Vec<String>,
+    /// Only return monitors with the service or custom tags
+    pub monitor_tags: Vec<String>,
+    /// Only return monitors with an id greater than the offset
+    pub id_offset: Option<i64>,
+    /// The page to return, starting at 0
+    pub page: Option<i64>,
+    /// The number of monitors per page
+    pub page_size: Option<i32>,
+    /// The api version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+impl Default for ListMonitors {
+    fn default() -> Self {
+        Self {
+            group_states: vec![],
+            with_downtimes: None,
+            name: None,
+            tags: vec![],
+            monitor_tags: vec![],
+            id_offset: None,
+            page: None,
+            page_size: None,
+            version: types::version::ApiVersion::V1,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for ListMonitors {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V1 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl ListMonitors {
+    /// Instantiates a new monitor listing
+    pub fn new() -> Self {
+        tracing::info!(target: "/v1/monitor", "List Monitors Route Created");
+        Self::default()
+    }
+
+    /// Sets the group states to include in the response, such as `alert`, `warn`, `no data` or `all`
+    pub fn with_group_states(mut self, group_states: Vec<&str>) -> Self {
+        self.group_states = group_states.into_iter().map(String::from).collect();
+        self
+    }
+
+    /// Sets whether the downtimes of the monitors are included in the response
+    pub fn with_downtimes(mut self, with_downtimes: bool) -> Self {
+        self.with_downtimes = Some(with_downtimes);
+        self
+    }
+
+    /// Only return monitors whose name contains the string
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Only return monitors with the scope tags, such as `host:host0`
+    pub fn with_tags(mut self, tags: Vec<&str>) -> Self {
+        self.tags = tags.into_iter().map(String::from).collect();
+        self
+    }
+
+    /// Only return monitors with the service or custom tags, such as `service:web`
+    pub fn with_monitor_tags(mut self, monitor_tags: Vec<&str>) -> Self {
+        self.monitor_tags = monitor_tags.into_iter().map(String::from).collect();
+        self
+    }
+
+    /// Only return monitors with an id greater than the offset
+    pub fn with_id_offset(mut self, id_offset: i64) -> Self {
+        self.id_offset = Some(id_offset);
+        self
+    }
+
+    /// Sets the page to return, starting at 0, and the number of monitors per page
+    pub fn with_page(mut self, page: i64, page_size: i32) -> Self {
+        self.page = Some(page);
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// The query string parameters, omitting unset filters
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![];
+        if !self.group_states.is_empty() {
+            query.push(("group_states", self.group_states.join(",")));
+        }
+        if let Some(name) = &self.name {
+            query.push(("name", name.clone()));
+        }
+        if !self.tags.is_empty() {
+            query.push(("tags", self.tags.join(",")));
+        }
+        if !self.monitor_tags.is_empty() {
+            query.push(("monitor_tags", self.monitor_tags.join(",")));
+        }
+        if let Some(with_downtimes) = self.with_downtimes {
+            query.push(("with_downtimes", with_downtimes.to_string()));
+        }
+        if let Some(id_offset) = self.id_offset {
+            query.push(("id_offset", id_offset.to_string()));
+        }
+        if let Some(page) = self.page {
+            query.push(("page", page.to_string()));
+        }
+        if let Some(page_size) = self.page_size {
+            query.push(("page_size", page_size.to_string()));
+        }
+        query
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v1/monitor")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<Vec<Monitor>> for ListMonitors {
+    /// The route path
+    fn path(&self) -> String {
+        String::from("v1/monitor")
+    }
+
+    /// Route does nothing here since the route path is fixed
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<Vec<Monitor>>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        let path = self.path();
+        tracing::info!(target: "/v1/monitor", "Sending Request to {}", path);
+        let req_builder = client
+            .request(reqwest::Method::GET, &path)
+            .query(&self.query())
+            .headers(self.headers);
+        client.send(req_builder).await
+    }
+}
@@ -0,0 +1,65 @@
+//! Monitors Endpoints
+//!
+//! ## Overview
+//!
+//! The monitors endpoints, as described in the [Datadog Documentation](https://docs.datadoghq.com/api/latest/monitors/):
+//!
+//! ```md
+//!     - Create, update and delete monitors
+//!     - Get the details of a monitor, list or search monitors
+//!     - Validate a monitor definition before creating it
+//!     - Mute and unmute monitors
+//! ```
+//!
+//! Monitors alert your team when a metric, log, trace or check goes past a threshold, so they can be defined alongside the services they watch and reconciled on deploy.
+
+/// Monitor to create a monitor
+/// `v1/monitor` Endpoint [POST]
+pub mod create_monitor;
+
+/// Monitor to get a monitor's details
+/// `v1/monitor/{monitor_id}` Endpoint [GET]
+pub mod get_monitor;
+
+/// Monitor to edit a monitor
+/// `v1/monitor/{monitor_id}` Endpoint [PUT]
+pub mod update_monitor;
+
+/// Monitor to delete a monitor
+/// `v1/monitor/{monitor_id}` Endpoint [DELETE]
+pub mod delete_monitor;
+
+/// Monitor to list monitors
+/// `v1/monitor` Endpoint [GET]
+pub mod list_monitors;
+
+/// Monitor to validate a monitor definition
+/// `v1/monitor/validate` Endpoint [POST]
+pub mod validate_monitor;
+
+/// Monitor to search monitors
+/// `v1/monitor/search` Endpoint [GET]
+pub mod search_monitors;
+
+/// Monitor to mute a monitor
+/// `v1/monitor/{monitor_id}/mute` Endpoint [POST]
+pub mod mute_monitor;
+
+/// Monitor to unmute a monitor
+/// `v1/monitor/{monitor_id}/unmute` Endpoint [POST]
+pub mod unmute_monitor;
+
+/// Re-exported prelude of all monitors-related endpoints
+pub mod prelude {
+    pub use super::{
+        create_monitor::{self, *},
+        delete_monitor::{self, *},
+        get_monitor::{self, *},
+        list_monitors::{self, *},
+        mute_monitor::{self, *},
+        search_monitors::{self, *},
+        unmute_monitor::{self, *},
+        update_monitor::{self, *},
+        validate_monitor::{self, *},
+    };
+}
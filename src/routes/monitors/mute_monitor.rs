@@ -0,0 +1,221 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+use super::create_monitor::Monitor;
+
+/// Mute Monitor Endpoint
+///
+/// ## Overview
+///
+/// Mute the specified monitor, or a scope of it, until the given end or until it is unmuted.
+/// Muted scopes are listed in the `options.silenced` of the returned monitor.
+/// Datadog recommends downtimes to schedule mutes, this endpoint mutes the monitor right away.
+///
+/// Endpoint Format: `/v1/monitor/{monitor_id}/mute` [POST]
+///
+/// ## Arguments
+///
+/// - monitor_id [required]
+///    - type: `int64`
+///    - description: The id of the monitor.
+///
+/// ## Request
+///
+/// **Body Data**
+///
+/// - scope [type: string] - The scope to apply the mute to, such as `role:db`. All scopes are muted when unset.
+/// - end [type: int64] - A POSIX timestamp for when the mute should end.
+///
+/// ## Response
+///
+/// One of: [200, 400, 403, 404, 429]
+///
+/// The muted monitor, see [Monitor](super::create_monitor::Monitor).
+#[derive(Debug)]
+pub struct MuteMonitor {
+    /// The monitor id
+    pub monitor_id: Option<i64>,
+    /// The scope to mute, such as `role:db`, all scopes when unset
+    pub scope: Option<String>,
+    /// When the mute ends, never when unset
+    pub end: Option<DateTime<Utc>>,
+    /// The api version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// The request body of a monitor mute
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct MuteMonitorRequest {
+    /// The scope to mute, all scopes when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// POSIX timestamp of the end of the mute, never when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<i64>,
+}
+
+impl Default for MuteMonitor {
+    fn default() -> Self {
+        Self {
+            monitor_id: None,
+            scope: None,
+            end: None,
+            version: types::version::ApiVersion::V1,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for MuteMonitor {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V1 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl MuteMonitor {
+    /// Instantiates a new monitor mute for the given monitor id
+    pub fn new(monitor_id: i64) -> Self {
+        tracing::info!(target: "/v1/monitor/{monitor_id}/mute", "Mute Monitor Route Created");
+        Self {
+            monitor_id: Some(monitor_id),
+            ..Self::default()
+        }
+    }
+
+    /// Set the Monitor Id
+    pub fn set_monitor_id(mut self, monitor_id: i64) -> Self {
+        tracing::info!(target: "/v1/monitor/{monitor_id}/mute", "Monitor id set to {}", monitor_id);
+        self.monitor_id = Some(monitor_id);
+        self
+    }
+
+    /// Only mutes the scope, such as `role:db`
+    pub fn with_scope(mut self, scope: &str) -> Self {
+        self.scope = Some(scope.to_string());
+        self
+    }
+
+    /// Ends the mute at the given time
+    pub fn with_end(mut self, end: DateTime<Utc>) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v1/monitor/{monitor_id}/mute")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<Monitor> for MuteMonitor {
+    /// The route path
+    fn path(&self) -> String {
+        match self.monitor_id {
+            Some(monitor_id) => format!("v1/monitor/{}/mute", monitor_id),
+            None => String::from("v1/monitor/mute"),
+        }
+    }
+
+    /// Route does nothing here since the route path is set with the monitor id
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    ///
+    /// The request body is built from the scope settings, replacing any body set with [body](types::route::Route::body).
+    async fn execute(self) -> Result<types::response::Response<Monitor>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        if self.monitor_id.is_none() {
+            tracing::error!(target: "/v1/monitor/{monitor_id}/mute", "Missing monitor id");
+            return Err(Error::Validation(String::from("missing monitor id")));
+        }
+        let path = self.path();
+        tracing::info!(target: "/v1/monitor/{monitor_id}/mute", "Sending Request to {}", path);
+        let body = serde_json::to_vec(&MuteMonitorRequest {
+            scope: self.scope,
+            end: self.end.map(|end| end.timestamp()),
+        })
+        .map_err(|e| Error::Validation(e.to_string()))?;
+        let req_builder = client
+            .request(reqwest::Method::POST, &path)
+            .headers(self.headers)
+            .body(body);
+        client.send(req_builder).await
+    }
+}
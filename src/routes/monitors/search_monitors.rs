@@ -0,0 +1,326 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+use super::create_monitor::{MonitorCreator, MonitorOverallState, MonitorType};
+
+/// Search Monitors Endpoint
+///
+/// ## Overview
+///
+/// Search and filter your monitors details. This endpoint requires the `monitors_read` authorization [scope](https://docs.datadoghq.com/api/latest/scopes/#monitors).
+///
+/// Endpoint Format: `/v1/monitor/search` [GET]
+///
+/// ## Arguments
+///
+/// **Query Strings**
+///
+/// - query [type: string] - After entering a search query in your Manage Monitor page use the query parameter value in the URL of the page as value for this parameter, such as `type:metric status:alert`.
+/// - page [type: int64] - Page to start paginating from. Defaults to 0.
+/// - per_page [type: int64] - Number of monitors to return per page. Defaults to 30.
+/// - sort [type: string] - String for sort order, composed of field and sort order separate by a comma, for example `name,asc`.
+///
+/// ## Response
+///
+/// One of: [200, 400, 403, 429]
+///
+/// - counts [type: object] - The counts of monitors per different criteria.
+/// - metadata [type: object] - Metadata about the response.
+/// - monitors [type: object[]] - The list of found monitors.
+///
+/// #### Example
+///
+/// ```json
+/// {
+///     "counts": {
+///         "muted": [ { "count": 3, "name": false } ],
+///         "status": [ { "count": 3, "name": "No Data" } ],
+///         "tag": [ { "count": 3, "name": "service:web" } ],
+///         "type": [ { "count": 3, "name": "metric" } ]
+///     },
+///     "metadata": { "page": 0, "page_count": 3, "per_page": 30, "total_count": 3 },
+///     "monitors": [
+///         {
+///             "id": 1234567,
+///             "name": "Disk usage is high",
+///             "query": "avg(last_5m):avg:system.disk.in_use{service:web} by {host} > 0.9",
+///             "type": "metric alert",
+///             "status": "No Data",
+///             "tags": [ "service:web" ],
+///             "classification": "metric",
+///             "metrics": [ "system.disk.in_use" ]
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Debug)]
+pub struct SearchMonitors {
+    /// The search query
+    pub query: Option<String>,
+    /// The page to return, starting at 0
+    pub page: Option<i64>,
+    /// The number of monitors per page
+    pub per_page: Option<i64>,
+    /// The sort order of the monitors, such as `name,asc`
+    pub sort: Option<String>,
+    /// The api version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// A Search Monitors Response
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct SearchMonitorsResponse {
+    /// The number of matching monitors per facet
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub counts: Option<MonitorSearchCounts>,
+    /// The paging metadata
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<MonitorSearchMetadata>,
+    /// The matching monitors
+    #[serde(default)]
+    pub monitors: Vec<MonitorSearchResult>,
+}
+
+/// The number of matching monitors per facet value
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct MonitorSearchCounts {
+    /// Counts per muted state
+    #[serde(default)]
+    pub muted: Vec<MonitorSearchCount>,
+    /// Counts per status
+    #[serde(default)]
+    pub status: Vec<MonitorSearchCount>,
+    /// Counts per tag
+    #[serde(default)]
+    pub tag: Vec<MonitorSearchCount>,
+    /// Counts per monitor type
+    #[serde(default, rename = "type")]
+    pub type_: Vec<MonitorSearchCount>,
+}
+
+/// The number of matching monitors of a facet value
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct MonitorSearchCount {
+    /// The facet value, a string or a boolean
+    pub name: serde_json::Value,
+    /// The number of monitors
+    pub count: i64,
+}
+
+/// The paging metadata of a monitor search
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct MonitorSearchMetadata {
+    /// The page index
+    #[serde(default)]
+    pub page: i64,
+    /// The number of pages
+    #[serde(default)]
+    pub page_count: i64,
+    /// The number of monitors per page
+    #[serde(default)]
+    pub per_page: i64,
+    /// The total number of matching monitors
+    #[serde(default)]
+    pub total_count: i64,
+}
+
+/// A monitor matching a search
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct MonitorSearchResult {
+    /// The monitor id
+    pub id: i64,
+    /// The monitor name
+    #[serde(default)]
+    pub name: String,
+    /// The monitor query
+    #[serde(default)]
+    pub query: String,
+    /// The monitor type
+    #[serde(rename = "type")]
+    pub type_: MonitorType,
+    /// The current state of the monitor
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<MonitorOverallState>,
+    /// The tags of the monitor
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// The classification of the monitor, such as `metric` or `log`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub classification: Option<String>,
+    /// The creator of the monitor
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<MonitorCreator>,
+    /// POSIX timestamp of the last time the monitor triggered
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_triggered_ts: Option<i64>,
+    /// The metrics used by the monitor
+    #[serde(default)]
+    pub metrics: Vec<String>,
+}
+
+impl Default for SearchMonitors {
+    fn default() -> Self {
+        Self {
+            query: None,
+            page: None,
+            per_page: None,
+            sort: None,
+            version: types::version::ApiVersion::V1,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for SearchMonitors {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V1 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl SearchMonitors {
+    /// Instantiates a new monitor search
+    pub fn new() -> Self {
+        tracing::info!(target: "/v1/monitor/search", "Search Monitors Route Created");
+        Self::default()
+    }
+
+    /// Sets the search query, such as `type:metric status:alert tag:service:web`
+    pub fn with_query(mut self, query: &str) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+
+    /// Sets the page to return, starting at 0, and the number of monitors per page
+    pub fn with_page(mut self, page: i64, per_page: i64) -> Self {
+        self.page = Some(page);
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the sort order as a field and direction, such as `name,asc`
+    pub fn with_sort(mut self, sort: &str) -> Self {
+        self.sort = Some(sort.to_string());
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// The query string parameters, omitting unset filters
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![];
+        if let Some(search) = &self.query {
+            query.push(("query", search.clone()));
+        }
+        if let Some(page) = self.page {
+            query.push(("page", page.to_string()));
+        }
+        if let Some(per_page) = self.per_page {
+            query.push(("per_page", per_page.to_string()));
+        }
+        if let Some(sort) = &self.sort {
+            query.push(("sort", sort.clone()));
+        }
+        query
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v1/monitor/search")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<SearchMonitorsResponse> for SearchMonitors {
+    /// The route path
+    fn path(&self) -> String {
+        String::from("v1/monitor/search")
+    }
+
+    /// Route does nothing here since the route path is fixed
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<SearchMonitorsResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        let path = self.path();
+        tracing::info!(target: "/v1/monitor/search", "Sending Request to {}", path);
+        let req_builder = client
+            .request(reqwest::Method::GET, &path)
+            .query(&self.query())
+            .headers(self.headers);
+        client.send(req_builder).await
+    }
+}
@@ -0,0 +1,182 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{client, types, Error};
+
+use super::create_monitor::Monitor;
+
+/// Validate Monitor Endpoint
+///
+/// ## Overview
+///
+/// Validate the monitor provided in the request, without creating it. This endpoint requires the `monitors_write` authorization [scope](https://docs.datadoghq.com/api/latest/scopes/#monitors).
+///
+/// Endpoint Format: `/v1/monitor/validate` [POST]
+///
+/// ## Request
+///
+/// - Body Data (required), see [Monitor](super::create_monitor::Monitor)
+///   - type [required] [type: enum] - The type of the monitor, such as `metric alert`, `query alert`, `log alert` or `service check`.
+///   - query [required] [type: string] - The monitor query.
+///   - name [type: string] - The monitor name.
+///   - message [type: string] - A message to include with notifications for this monitor.
+///   - tags [type: string[]] - Tags associated to your monitor.
+///   - priority [type: int64] - Integer from 1 (high) to 5 (low) indicating alert severity.
+///   - options [type: object] - List of options associated with your monitor, such as `thresholds`, `notify_no_data` and `renotify_interval`.
+///
+/// ## Response
+///
+/// One of: [200, 400, 403, 429]
+#[derive(Debug)]
+pub struct ValidateMonitor {
+    /// The api version
+    pub version: types::version::ApiVersion,
+    /// Request Headers
+    pub headers: reqwest::header::HeaderMap,
+    /// Request Body
+    pub body: reqwest::Body,
+    /// The typed monitor to validate, serialized in place of the body when set
+    pub monitor: Option<Monitor>,
+    /// The api client used to execute the request, the shared default client when unset
+    pub client: Option<client::Client>,
+}
+
+/// A Validate Monitor Response
+///
+/// A valid monitor is acknowledged with an empty body, an invalid one with a 400 status and the list of errors.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct ValidateMonitorResponse {}
+
+impl Default for ValidateMonitor {
+    fn default() -> Self {
+        Self {
+            version: types::version::ApiVersion::V1,
+            headers: reqwest::header::HeaderMap::new(),
+            body: reqwest::Body::from(""),
+            monitor: None,
+            client: None,
+        }
+    }
+}
+
+impl TryFrom<types::version::ApiVersion> for ValidateMonitor {
+    type Error = Error;
+
+    fn try_from(v: types::version::ApiVersion) -> Result<Self, Self::Error> {
+        match v {
+            types::version::ApiVersion::V1 => Ok(Self::default()),
+            v => Err(Error::UnsupportedVersion(v)),
+        }
+    }
+}
+
+impl ValidateMonitor {
+    /// Instantiates a new monitor validation
+    pub fn new() -> Self {
+        tracing::info!(target: "/v1/monitor/validate", "Validate Monitor Route Created");
+        Self::default()
+    }
+
+    /// Sets the typed monitor to validate
+    ///
+    /// The monitor is serialized as the request body when the route is executed, replacing any body set with [body](types::route::Route::body).
+    pub fn monitor(mut self, monitor: Monitor) -> Self {
+        tracing::info!(target: "/v1/monitor/validate", "Monitor set with name {}", monitor.name);
+        self.monitor = Some(monitor);
+        self
+    }
+
+    /// Sets the api client used to execute the request
+    pub fn with_client(mut self, client: client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the Datadog site the request is sent to
+    pub fn with_site(mut self, site: types::base::Site) -> Self {
+        self.client.get_or_insert_with(client::Client::shared).site = site;
+        self
+    }
+
+    /// Creates a target identifier for logging
+    pub fn target() -> String {
+        String::from("v1/monitor/validate")
+    }
+}
+
+#[async_trait]
+impl types::route::Route<ValidateMonitorResponse> for ValidateMonitor {
+    /// The route path
+    fn path(&self) -> String {
+        String::from("v1/monitor/validate")
+    }
+
+    /// Route does nothing here since the route path is fixed
+    fn route(self, _: std::string::String) -> Self {
+        self
+    }
+
+    /// Add a header to the request
+    fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str(key).unwrap(),
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// Add a list of headers to the request
+    fn headers(mut self, headers: Vec<(&str, &str)>) -> Self {
+        for (key, value) in headers {
+            self.headers.insert(
+                reqwest::header::HeaderName::from_str(key).unwrap(),
+                reqwest::header::HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        self
+    }
+
+    /// Adds an api key to the request
+    fn with_api_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-API-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds an application key to the request
+    fn with_application_key(mut self, key: &str) -> Self {
+        self.headers.insert(
+            reqwest::header::HeaderName::from_str("DD-APPLICATION-KEY").unwrap(),
+            reqwest::header::HeaderValue::from_str(key).unwrap(),
+        );
+        self
+    }
+
+    /// Adds a body to the request
+    fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Executes the api request
+    async fn execute(self) -> Result<types::response::Response<ValidateMonitorResponse>, Error> {
+        let client = client::Client::or_shared(&self.client);
+        let path = self.path();
+        tracing::info!(target: "/v1/monitor/validate", "Sending Request to {}", path);
+        let body = match self.monitor {
+            Some(monitor) => reqwest::Body::from(
+                serde_json::to_vec(&monitor).map_err(|e| Error::Validation(e.to_string()))?,
+            ),
+            None => self.body,
+        };
+        let req_builder = client
+            .request(reqwest::Method::POST, &path)
+            .headers(self.headers)
+            .body(body);
+        client.send_idempotent(req_builder).await
+    }
+}
@@ -1,2 +1,97 @@
+use std::{fmt, str::FromStr};
+
 /// The base datadog api url
 pub const BASE_API_URL: &str = "https://api.datadoghq.com/api";
+
+/// A Datadog Site
+///
+/// Each Datadog site is hosted on its own domain, see the [Datadog Documentation](https://docs.datadoghq.com/getting_started/site/).
+///
+/// A [Custom](Site::Custom) site points every route at an arbitrary base api url, for example a proxy or a local mock server.
+///
+/// ```rust
+/// use ddog::types::base::*;
+///
+/// assert_eq!(Site::default().api_url(), BASE_API_URL);
+/// assert_eq!(Site::EU1.api_url(), "https://api.datadoghq.eu/api");
+/// assert_eq!(Site::EU1.logs_intake_url(), "https://http-intake.logs.datadoghq.eu/api");
+/// assert_eq!("us3.datadoghq.com".parse::<Site>().unwrap(), Site::US3);
+/// assert_eq!(
+///     Site::Custom(String::from("http://localhost:8080/api")).api_url(),
+///     "http://localhost:8080/api"
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Site {
+    /// US1 - `datadoghq.com`
+    #[default]
+    US1,
+    /// US3 - `us3.datadoghq.com`
+    US3,
+    /// US5 - `us5.datadoghq.com`
+    US5,
+    /// EU1 - `datadoghq.eu`
+    EU1,
+    /// AP1 - `ap1.datadoghq.com`
+    AP1,
+    /// US1-FED - `ddog-gov.com`
+    US1Fed,
+    /// A custom base api url, used in place of `https://api.<site>/api`
+    Custom(String),
+}
+
+impl Site {
+    /// The site domain, or the custom base api url
+    pub fn domain(&self) -> &str {
+        match self {
+            Site::US1 => "datadoghq.com",
+            Site::US3 => "us3.datadoghq.com",
+            Site::US5 => "us5.datadoghq.com",
+            Site::EU1 => "datadoghq.eu",
+            Site::AP1 => "ap1.datadoghq.com",
+            Site::US1Fed => "ddog-gov.com",
+            Site::Custom(url) => url,
+        }
+    }
+
+    /// The base api url routes are appended to
+    pub fn api_url(&self) -> String {
+        match self {
+            Site::Custom(url) => url.trim_end_matches('/').to_string(),
+            _ => format!("https://api.{}/api", self.domain()),
+        }
+    }
+
+    /// The base url of the logs intake, hosted on `http-intake.logs.<site>`
+    ///
+    /// A [Custom](Site::Custom) site sends logs to its base api url.
+    pub fn logs_intake_url(&self) -> String {
+        match self {
+            Site::Custom(url) => url.trim_end_matches('/').to_string(),
+            _ => format!("https://http-intake.logs.{}/api", self.domain()),
+        }
+    }
+}
+
+impl fmt::Display for Site {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.domain())
+    }
+}
+
+/// Parses a site from its domain (the `DD_SITE` format), falling back to a [Custom](Site::Custom) url.
+impl FromStr for Site {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "datadoghq.com" | "us1" | "US1" => Site::US1,
+            "us3.datadoghq.com" | "us3" | "US3" => Site::US3,
+            "us5.datadoghq.com" | "us5" | "US5" => Site::US5,
+            "datadoghq.eu" | "eu1" | "EU1" | "eu" | "EU" => Site::EU1,
+            "ap1.datadoghq.com" | "ap1" | "AP1" => Site::AP1,
+            "ddog-gov.com" | "us1-fed" | "US1-FED" => Site::US1Fed,
+            url => Site::Custom(url.to_string()),
+        })
+    }
+}
@@ -26,9 +26,13 @@ impl EnvConfig {
 /// ```rust
 /// use ddog::types::env::*;
 ///
-/// let env_config = EnvConfig::from(Vec<("DD_API_KEY", "<api_key>"), ("DD_APPLICATION_KEY", "<application_key>")>);
+/// let env_config = EnvConfig::from(vec![
+///     ("DD_API_KEY".to_string(), "<api_key>".to_string()),
+///     ("DD_APPLICATION_KEY".to_string(), "<application_key>".to_string()),
+/// ]);
 /// assert_eq!(env_config.api_key, Some("<api_key>".to_string()));
 /// assert_eq!(env_config.application_key, Some("<application_key>".to_string()));
+/// ```
 impl From<Vec<(String, String)>> for EnvConfig {
     fn from(headers: Vec<(String, String)>) -> Self {
         let mut api_key = None;
@@ -56,19 +60,37 @@ impl From<Vec<(String, String)>> for EnvConfig {
 /// ```rust
 /// use ddog::types::env::*;
 ///
-/// let env_config = EnvConfig::from(Vec<"DD_API_KEY=<api_key>", "DD_APPLICATION_KEY=<application_key>">);
+/// let env_config = EnvConfig::from(vec![
+///     "DD_API_KEY=<api_key>".to_string(),
+///     "DD_APPLICATION_KEY=<application_key>".to_string(),
+/// ]);
 /// assert_eq!(env_config.api_key, Some("<api_key>".to_string()));
 /// assert_eq!(env_config.application_key, Some("<application_key>".to_string()));
+/// ```
 impl From<Vec<String>> for EnvConfig {
     fn from(headers: Vec<String>) -> Self {
         let mut api_key = None;
         let mut application_key = None;
         for potential_kv in headers {
             let split_kv = potential_kv.trim().split('=').collect::<Vec<&str>>();
-            let key = *split_kv.get(0).unwrap_or(&"");
+            let key = *split_kv.first().unwrap_or(&"");
             match key {
-                "DD_API_KEY" => api_key = Some(split_kv.get(1).map(|s| String::from(*s)).unwrap_or("".to_string())),
-                "DD_APPLICATION_KEY" => application_key = Some(split_kv.get(1).map(|s| String::from(*s)).unwrap_or("".to_string())),
+                "DD_API_KEY" => {
+                    api_key = Some(
+                        split_kv
+                            .get(1)
+                            .map(|s| String::from(*s))
+                            .unwrap_or("".to_string()),
+                    )
+                }
+                "DD_APPLICATION_KEY" => {
+                    application_key = Some(
+                        split_kv
+                            .get(1)
+                            .map(|s| String::from(*s))
+                            .unwrap_or("".to_string()),
+                    )
+                }
                 _ => (),
             }
         }
@@ -88,7 +110,7 @@ impl From<Vec<String>> for EnvConfig {
 /// ```rust
 /// use ddog::types::env::*;
 ///
-/// let env_config = EnvConfig::from("DD_API_KEY=<api_key>,DD_APPLICATION_KEY=<application_key>");
+/// let env_config = EnvConfig::from("DD_API_KEY=<api_key>,DD_APPLICATION_KEY=<application_key>".to_string());
 /// assert_eq!(env_config.api_key, Some("<api_key>".to_string()));
 /// assert_eq!(env_config.application_key, Some("<application_key>".to_string()));
 /// ```
@@ -98,10 +120,24 @@ impl From<String> for EnvConfig {
         let mut application_key = None;
         for potential_kv in potential_envs.split(',') {
             let split_kv = potential_kv.trim().split('=').collect::<Vec<&str>>();
-            let key = *split_kv.get(0).unwrap_or(&"");
+            let key = *split_kv.first().unwrap_or(&"");
             match key {
-                "DD_API_KEY" => api_key = Some(split_kv.get(1).map(|s| String::from(*s)).unwrap_or("".to_string())),
-                "DD_APPLICATION_KEY" => application_key = Some(split_kv.get(1).map(|s| String::from(*s)).unwrap_or("".to_string())),
+                "DD_API_KEY" => {
+                    api_key = Some(
+                        split_kv
+                            .get(1)
+                            .map(|s| String::from(*s))
+                            .unwrap_or("".to_string()),
+                    )
+                }
+                "DD_APPLICATION_KEY" => {
+                    application_key = Some(
+                        split_kv
+                            .get(1)
+                            .map(|s| String::from(*s))
+                            .unwrap_or("".to_string()),
+                    )
+                }
                 _ => (),
             }
         }
@@ -1,6 +1,6 @@
 //! Datadog API Types
 
-/// The Base Datadog API URL
+/// The Base Datadog API URL and Sites
 pub mod base;
 
 /// Environment Variable Config
@@ -12,10 +12,26 @@ pub mod version;
 /// Route Type
 pub mod route;
 
+/// Route Response Type
+pub mod response;
+
+/// Request Body Compression
+pub mod compression;
+
+/// Request Retry Policy
+pub mod retry;
+
+/// Rate Limit Headers
+pub mod rate_limit;
+
 /// Prelude to re-export common types
 pub mod prelude {
     pub use super::{
         base::{self, *},
+        compression::{self, *},
+        rate_limit::{self, *},
+        response::{self, *},
+        retry::{self, *},
         route::{self, *},
         version::{self, *},
     };
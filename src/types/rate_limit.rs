@@ -0,0 +1,67 @@
+//! Rate Limit Headers
+
+use std::time::Duration;
+
+/// Datadog Rate Limit
+///
+/// Parsed from the `X-RateLimit-*` headers Datadog returns on rate limited endpoints, see the [Datadog Documentation](https://docs.datadoghq.com/api/latest/rate-limits/).
+///
+/// ```rust
+/// use ddog::types::rate_limit::*;
+///
+/// let mut headers = reqwest::header::HeaderMap::new();
+/// headers.insert("X-RateLimit-Limit", "100".parse().unwrap());
+/// headers.insert("X-RateLimit-Remaining", "0".parse().unwrap());
+/// headers.insert("X-RateLimit-Reset", "7".parse().unwrap());
+/// let rate_limit = RateLimit::from_headers(&headers).unwrap();
+/// assert_eq!(rate_limit.limit, Some(100));
+/// assert!(rate_limit.is_exhausted());
+/// assert_eq!(rate_limit.reset_after(), Some(std::time::Duration::from_secs(7)));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct RateLimit {
+    /// `X-RateLimit-Limit` - number of requests allowed in a time period
+    pub limit: Option<u64>,
+    /// `X-RateLimit-Period` - length of time in seconds for resets
+    pub period: Option<u64>,
+    /// `X-RateLimit-Remaining` - number of allowed requests left in the current time period
+    pub remaining: Option<u64>,
+    /// `X-RateLimit-Reset` - time in seconds until the next reset
+    pub reset: Option<u64>,
+    /// `X-RateLimit-Name` - name of the rate limit
+    pub name: Option<String>,
+}
+
+impl RateLimit {
+    /// Parses the rate limit headers, returning `None` when no rate limit header is present
+    pub fn from_headers(headers: &reqwest::header::HeaderMap) -> Option<Self> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.trim().to_string())
+        };
+        let number = |name: &str| header(name).and_then(|v| v.parse::<u64>().ok());
+        let rate_limit = Self {
+            limit: number("x-ratelimit-limit"),
+            period: number("x-ratelimit-period"),
+            remaining: number("x-ratelimit-remaining"),
+            reset: number("x-ratelimit-reset"),
+            name: header("x-ratelimit-name"),
+        };
+        match rate_limit == Self::default() {
+            true => None,
+            false => Some(rate_limit),
+        }
+    }
+
+    /// Whether no requests are left in the current time period
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == Some(0)
+    }
+
+    /// The time until the rate limit resets
+    pub fn reset_after(&self) -> Option<Duration> {
+        self.reset.map(Duration::from_secs)
+    }
+}
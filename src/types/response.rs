@@ -0,0 +1,27 @@
+//! Route Response
+
+/// A successful route response
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Response<T> {
+    /// The response status code
+    pub status: reqwest::StatusCode,
+    /// The rate limit headers of the response
+    pub rate_limit: Option<super::rate_limit::RateLimit>,
+    /// The deserialized response body
+    pub data: T,
+}
+
+impl<T> Response<T> {
+    /// Consumes the response, returning the deserialized body
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+impl<T> std::ops::Deref for Response<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
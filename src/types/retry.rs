@@ -0,0 +1,121 @@
+//! Request Retry Policy
+
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+/// A Retry Policy with exponential backoff
+///
+/// Failed requests are retried when the request is idempotent (GET requests and metric intake submissions) and either the connection failed or the response status is retryable.
+/// The delay before retry `n` (starting at 1) is `base_delay * 2^(n - 1)`, capped at `max_delay`.
+/// With jitter enabled, a random delay between half and the full backoff is used instead.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use ddog::types::retry::*;
+///
+/// let policy = RetryPolicy::default()
+///     .with_max_attempts(5)
+///     .with_base_delay(Duration::from_millis(100))
+///     .with_jitter(false);
+/// assert_eq!(policy.backoff(1), Duration::from_millis(100));
+/// assert_eq!(policy.backoff(3), Duration::from_millis(400));
+/// assert!(policy.is_retryable_status(reqwest::StatusCode::SERVICE_UNAVAILABLE));
+/// assert!(!policy.is_retryable_status(reqwest::StatusCode::FORBIDDEN));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first request
+    pub max_attempts: u32,
+    /// The delay before the first retry
+    pub base_delay: Duration,
+    /// The maximum delay between two attempts
+    pub max_delay: Duration,
+    /// Randomize delays to spread retries from concurrent clients
+    pub jitter: bool,
+    /// Response status codes that are retried
+    pub retryable_statuses: Vec<reqwest::StatusCode>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(10),
+            jitter: true,
+            retryable_statuses: vec![
+                reqwest::StatusCode::REQUEST_TIMEOUT,
+                reqwest::StatusCode::TOO_MANY_REQUESTS,
+                reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+                reqwest::StatusCode::BAD_GATEWAY,
+                reqwest::StatusCode::SERVICE_UNAVAILABLE,
+                reqwest::StatusCode::GATEWAY_TIMEOUT,
+            ],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Instantiates the default retry policy
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of attempts, including the first request
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the delay before the first retry
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the maximum delay between two attempts
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Enables or disables jitter
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets the response status codes that are retried
+    pub fn with_retryable_statuses(mut self, statuses: Vec<reqwest::StatusCode>) -> Self {
+        self.retryable_statuses = statuses;
+        self
+    }
+
+    /// Whether a response with the given status should be retried
+    pub fn is_retryable_status(&self, status: reqwest::StatusCode) -> bool {
+        self.retryable_statuses.contains(&status)
+    }
+
+    /// Whether a failed request should be retried
+    pub fn is_retryable_error(&self, error: &reqwest::Error) -> bool {
+        !error.is_builder() && !error.is_redirect() && !error.is_decode()
+    }
+
+    /// The delay before the given retry, starting at 1
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .saturating_mul(1u32 << exponent)
+            .min(self.max_delay);
+        if !self.jitter {
+            return delay;
+        }
+        let half = delay / 2;
+        let random = RandomState::new().build_hasher().finish();
+        half + half.mul_f64((random % 1_000_000) as f64 / 1_000_000.0)
+    }
+}
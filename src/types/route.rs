@@ -8,7 +8,7 @@ pub trait Route<T>
 where
     T: Debug,
 {
-    /// Response for execute a route query
+    // Response for execute a route query
     // type ExecutionResult: impl Debug;
 
     /// The route path
@@ -39,5 +39,5 @@ where
     fn with_application_key(self, key: &str) -> Self;
 
     /// Executes the api request
-    async fn execute(self) -> (reqwest::StatusCode, Result<T, Option<reqwest::Error>>);
+    async fn execute(self) -> crate::Result<super::response::Response<T>>;
 }
@@ -1,20 +1,15 @@
 use std::fmt;
 
 /// API Versions
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub enum ApiVersion {
     /// API Version 1
     V1,
     /// API Version 2
+    #[default]
     V2,
 }
 
-impl Default for ApiVersion {
-    fn default() -> Self {
-        Self::V2
-    }
-}
-
 impl fmt::Display for ApiVersion {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -0,0 +1,191 @@
+use std::time::Duration;
+
+use ddog::prelude::*;
+
+mod common;
+use common::{MockResponse, MockServer};
+
+fn find<'a>(series: &'a [series::MetricSeries], metric: &str) -> &'a series::MetricSeries {
+    series.iter().find(|s| s.metric == metric).unwrap()
+}
+
+#[test]
+fn aggregates_observations_by_name_and_tags() {
+    let aggregator = Aggregator::new(Client::new())
+        .with_flush_interval(Duration::from_secs(10))
+        .with_host("worker_1")
+        .with_tags(vec!["env:test"]);
+
+    aggregator.count("requests", 1.0, &["route:/", "method:get"]);
+    aggregator.count("requests", 2.0, &["method:get", "route:/"]);
+    aggregator.count("requests", 5.0, &["route:/health"]);
+    aggregator.gauge("queue.depth", 3.0, &[]);
+    aggregator.gauge("queue.depth", 7.0, &[]);
+    aggregator.rate("bytes", 50.0, &[]);
+    aggregator.rate("bytes", 50.0, &[]);
+    aggregator.set("users", "a", &[]);
+    aggregator.set("users", "b", &[]);
+    aggregator.set("users", "a", &[]);
+
+    let (series, distributions) = aggregator.drain(1636629071);
+    assert!(distributions.is_empty());
+    assert_eq!(series.len(), 5);
+
+    let requests = series
+        .iter()
+        .find(|s| s.metric == "requests" && s.tags.contains(&"route:/".to_string()))
+        .unwrap();
+    assert_eq!(requests.type_, Some(series::MetricIntakeType::Count));
+    assert_eq!(requests.interval, Some(10));
+    assert_eq!(requests.points[0].timestamp, 1636629071);
+    assert_eq!(requests.points[0].value, 3.0);
+    assert_eq!(requests.tags, vec!["env:test", "method:get", "route:/"]);
+    assert_eq!(requests.resources[0].name, "worker_1");
+    assert_eq!(requests.resources[0].type_, "host");
+
+    let depth = find(&series, "queue.depth");
+    assert_eq!(depth.type_, Some(series::MetricIntakeType::Gauge));
+    assert_eq!(depth.interval, None);
+    assert_eq!(depth.points[0].value, 7.0);
+
+    let bytes = find(&series, "bytes");
+    assert_eq!(bytes.type_, Some(series::MetricIntakeType::Rate));
+    assert_eq!(bytes.points[0].value, 10.0);
+
+    assert_eq!(find(&series, "users").points[0].value, 2.0);
+
+    // Draining empties the buffer
+    let (series, distributions) = aggregator.drain(1636629081);
+    assert!(series.is_empty() && distributions.is_empty());
+}
+
+#[test]
+fn aggregates_histograms_and_distributions() {
+    let aggregator = Aggregator::new(Client::new()).with_host("worker_1");
+    for value in 1..=100 {
+        aggregator.histogram("latency", value as f64, &["route:/"]);
+        aggregator.distribution("rpc_latency", value as f64, &["route:/"]);
+    }
+
+    let (series, distributions) = aggregator.drain(1636629071);
+    assert_eq!(series.len(), 5);
+    assert_eq!(find(&series, "latency.avg").points[0].value, 50.5);
+    assert_eq!(find(&series, "latency.median").points[0].value, 50.0);
+    assert_eq!(find(&series, "latency.max").points[0].value, 100.0);
+    assert_eq!(find(&series, "latency.95percentile").points[0].value, 95.0);
+    let count = find(&series, "latency.count");
+    assert_eq!(count.type_, Some(series::MetricIntakeType::Count));
+    assert_eq!(count.points[0].value, 100.0);
+
+    assert_eq!(distributions.len(), 1);
+    assert_eq!(distributions[0].metric, "rpc_latency");
+    assert_eq!(distributions[0].host.as_deref(), Some("worker_1"));
+    assert_eq!(distributions[0].tags, vec!["route:/"]);
+    assert_eq!(distributions[0].points[0].values.len(), 100);
+}
+
+#[test]
+fn flush_submits_series_and_distributions() {
+    let server = MockServer::start(vec![
+        MockResponse::new(202, r#"{ "errors": [] }"#),
+        MockResponse::new(202, r#"{ "status": "ok" }"#),
+    ]);
+    let client = Client::new()
+        .with_api_key("<api_key>")
+        .with_base_url(&server.url);
+    let aggregator = Aggregator::new(client);
+    aggregator.count("requests", 1.0, &[]);
+    aggregator.distribution("rpc_latency", 1.0, &[]);
+
+    tokio_test::block_on(async {
+        aggregator.flush().await.unwrap();
+        // Nothing left to submit
+        aggregator.flush().await.unwrap();
+    });
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].request_line, "POST /api/v2/series HTTP/1.1");
+    assert_eq!(requests[0].header("dd-api-key"), Some("<api_key>"));
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(body["series"][0]["metric"], "requests");
+    assert_eq!(
+        requests[1].request_line,
+        "POST /api/v1/distribution_points HTTP/1.1"
+    );
+    let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!(body["series"][0]["metric"], "rpc_latency");
+}
+
+#[test]
+fn flush_returns_failed_submission() {
+    let server = MockServer::start(vec![MockResponse::new(
+        403,
+        r#"{ "errors": ["Forbidden"] }"#,
+    )]);
+    let client = Client::new().with_base_url(&server.url);
+    let aggregator = Aggregator::new(client);
+    aggregator.gauge("queue.depth", 1.0, &[]);
+
+    let err = tokio_test::block_on(aggregator.flush()).unwrap_err();
+    assert_eq!(err.status(), Some(reqwest::StatusCode::FORBIDDEN));
+}
+
+#[test]
+fn failed_flush_counts_dropped_series() {
+    let server = MockServer::start(vec![
+        MockResponse::new(403, r#"{ "errors": ["Forbidden"] }"#),
+        MockResponse::new(202, r#"{ "status": "ok" }"#),
+    ]);
+    let client = Client::new().with_base_url(&server.url);
+    let aggregator = Aggregator::new(client);
+    aggregator.gauge("queue.depth", 1.0, &[]);
+    aggregator.histogram("request.size", 1.0, &[]);
+    aggregator.distribution("rpc_latency", 1.0, &[]);
+
+    tokio_test::block_on(async {
+        assert!(aggregator.flush().await.is_err());
+        // The dropped series are not resubmitted
+        aggregator.flush().await.unwrap();
+    });
+
+    // The gauge and the five histogram series
+    assert_eq!(aggregator.dropped(), 6);
+    assert_eq!(aggregator.clone().dropped(), 6);
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn spawn_flushes_periodically() {
+    let server = MockServer::start(vec![MockResponse::new(202, r#"{ "errors": [] }"#)]);
+    let client = Client::new().with_base_url(&server.url);
+    let aggregator = Aggregator::new(client).with_flush_interval(Duration::from_millis(50));
+
+    tokio_test::block_on(async {
+        let handle = aggregator.spawn();
+        aggregator.count("requests", 1.0, &[]);
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        handle.abort();
+    });
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].request_line, "POST /api/v2/series HTTP/1.1");
+}
+
+#[test]
+fn zero_flush_interval_is_clamped() {
+    let server = MockServer::start(vec![MockResponse::new(202, r#"{ "errors": [] }"#)]);
+    let client = Client::new().with_base_url(&server.url);
+    let aggregator = Aggregator::new(client).with_flush_interval(Duration::ZERO);
+    assert_eq!(aggregator.flush_interval, aggregator::MIN_FLUSH_INTERVAL);
+
+    let mut aggregator = aggregator;
+    aggregator.flush_interval = Duration::ZERO;
+    tokio_test::block_on(async {
+        let handle = aggregator.spawn();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        handle.abort();
+        assert!(handle.await.unwrap_err().is_cancelled());
+    });
+}
@@ -0,0 +1,214 @@
+use std::time::Duration;
+
+use bulk_tags::{BulkTagsAttributes, BulkTagsStatus};
+use ddog::prelude::*;
+
+mod common;
+use common::{MockResponse, MockServer};
+
+const BULK_TAGS: &str = r#"{
+    "data": {
+        "type": "metric_bulk_configure_tags",
+        "id": "kafka.",
+        "attributes": { "emails": [ "sue@example.com" ], "tags": [ "pod_name", "host" ] }
+    }
+}"#;
+
+const PENDING: &str = r#"{
+    "data": [
+        { "type": "manage_tags", "id": "kafka.lag", "attributes": { "tags": [ "host", "pod_name" ] } },
+        { "type": "metrics", "id": "kafka.offset" },
+        { "type": "metrics", "id": "system.cpu.idle" }
+    ]
+}"#;
+
+const APPLIED: &str = r#"{
+    "data": [
+        { "type": "manage_tags", "id": "kafka.lag", "attributes": { "tags": [ "host", "pod_name" ] } },
+        { "type": "manage_tags", "id": "kafka.offset", "attributes": { "tags": [ "pod_name", "host" ], "exclude_tags_mode": false } },
+        { "type": "metrics", "id": "system.cpu.idle" }
+    ]
+}"#;
+
+#[test]
+fn bulk_tag_config_and_poll_status() {
+    let server = MockServer::start(vec![
+        MockResponse::new(202, BULK_TAGS),
+        MockResponse::new(200, PENDING),
+        MockResponse::new(200, APPLIED),
+    ]);
+    let client = Client::new().with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let res = client
+            .bulk_tag_config("kafka.")
+            .unwrap()
+            .configuration(
+                BulkTagsAttributes::new(vec!["host", "pod_name"])
+                    .with_emails(vec!["sue@example.com"])
+                    .with_override_existing_configurations(true),
+            )
+            .execute()
+            .await;
+        let response = res.unwrap().into_inner();
+        assert_eq!(response.data.id, "kafka.");
+
+        let status = response
+            .data
+            .poll_status(
+                client.list_tag_configs().unwrap(),
+                Duration::from_millis(1),
+                5,
+            )
+            .await
+            .unwrap();
+        assert!(status.is_complete());
+        assert_eq!(status.applied, vec!["kafka.lag", "kafka.offset"]);
+    });
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(
+        requests[0].request_line,
+        "POST /api/v2/metrics/config/bulk-tags HTTP/1.1"
+    );
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "data": {
+                "type": "metric_bulk_configure_tags",
+                "id": "kafka.",
+                "attributes": {
+                    "emails": [ "sue@example.com" ],
+                    "override_existing_configurations": true,
+                    "tags": [ "host", "pod_name" ]
+                }
+            }
+        })
+    );
+    assert_eq!(requests[1].request_line, "GET /api/v2/metrics HTTP/1.1");
+}
+
+#[test]
+fn poll_status_gives_up_after_max_attempts() {
+    let server = MockServer::start(vec![MockResponse::new(200, PENDING)]);
+    let client = Client::new().with_base_url(&server.url);
+    let response: bulk_tags::BulkTagsResponse = serde_json::from_str(BULK_TAGS).unwrap();
+
+    let status = tokio_test::block_on(response.data.poll_status(
+        client.list_tag_configs().unwrap(),
+        Duration::from_millis(1),
+        2,
+    ))
+    .unwrap();
+    assert_eq!(
+        status,
+        BulkTagsStatus {
+            applied: vec![String::from("kafka.lag")],
+            pending: vec![String::from("kafka.offset")],
+        }
+    );
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn delete_bulk_tag_config() {
+    let server = MockServer::start(vec![MockResponse::new(
+        202,
+        r#"{ "data": { "type": "metric_bulk_configure_tags", "id": "kafka.", "attributes": { "emails": [ "sue@example.com" ] } } }"#,
+    )]);
+    let mut builder = Builder::new();
+    builder.v2().with_site(Site::Custom(server.url.clone()));
+
+    tokio_test::block_on(async {
+        let res = builder
+            .delete_bulk_tag_config("kafka.")
+            .unwrap()
+            .with_emails(vec!["sue@example.com"])
+            .execute()
+            .await;
+        let data = res.unwrap().into_inner().data;
+        assert_eq!(data.attributes.tags, None);
+
+        // A deletion is applied once the matching metrics no longer have a configuration
+        let listing: list_tag_configurations::ListTagConfigurationsResponse =
+            serde_json::from_str(PENDING).unwrap();
+        let status = data.status(&listing.data);
+        assert_eq!(status.applied, vec!["kafka.offset"]);
+        assert_eq!(status.pending, vec!["kafka.lag"]);
+    });
+
+    let request = &server.requests()[0];
+    assert_eq!(
+        request.request_line,
+        "DELETE /api/v2/metrics/config/bulk-tags HTTP/1.1"
+    );
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "data": {
+                "type": "metric_bulk_configure_tags",
+                "id": "kafka.",
+                "attributes": { "emails": [ "sue@example.com" ] }
+            }
+        })
+    );
+}
+
+#[test]
+fn bulk_tags_require_prefix() {
+    let res = tokio_test::block_on(bulk_tags::BulkTags::default().execute());
+    assert!(matches!(res, Err(Error::Validation(_))));
+    let res = tokio_test::block_on(delete_bulk_tags::DeleteBulkTags::default().execute());
+    assert!(matches!(res, Err(Error::Validation(_))));
+}
+
+#[test]
+fn poll_status_lists_with_the_route_filters() {
+    let server = MockServer::start(vec![MockResponse::new(200, APPLIED)]);
+    let client = Client::new().with_base_url(&server.url);
+    let response: bulk_tags::BulkTagsResponse = serde_json::from_str(BULK_TAGS).unwrap();
+    let configurations = client
+        .list_tag_configs()
+        .unwrap()
+        .with_metric_type(list_tag_configurations::MetricTypeFilter::NonDistribution);
+
+    let status = tokio_test::block_on(response.data.poll_status(
+        configurations,
+        Duration::from_millis(1),
+        2,
+    ))
+    .unwrap();
+    assert!(status.is_complete());
+    assert_eq!(
+        server.requests()[0].request_line,
+        "GET /api/v2/metrics?filter%5Bmetric_type%5D=non_distribution HTTP/1.1"
+    );
+}
+
+#[test]
+fn poll_status_of_a_deletion_lists_configured_metrics() {
+    let server = MockServer::start(vec![MockResponse::new(
+        200,
+        r#"{ "data": [ { "type": "manage_tags", "id": "system.cpu.idle", "attributes": { "tags": [ "host" ] } } ] }"#,
+    )]);
+    let client = Client::new().with_base_url(&server.url);
+    let response: bulk_tags::BulkTagsResponse = serde_json::from_str(
+        r#"{ "data": { "type": "metric_bulk_configure_tags", "id": "kafka.", "attributes": {} } }"#,
+    )
+    .unwrap();
+
+    let status = tokio_test::block_on(response.data.poll_status(
+        client.list_tag_configs().unwrap(),
+        Duration::from_millis(1),
+        2,
+    ))
+    .unwrap();
+    assert!(status.is_complete());
+    assert_eq!(
+        server.requests()[0].request_line,
+        "GET /api/v2/metrics?filter%5Bconfigured%5D=true HTTP/1.1"
+    );
+}
@@ -0,0 +1,46 @@
+use ddog::prelude::*;
+
+#[test]
+fn client_request_headers_include_credentials() {
+    let client = Client::new()
+        .with_api_key("<api_key>")
+        .with_application_key("<application_key>")
+        .with_header("X-Custom", "custom");
+
+    let headers = client.request_headers();
+    assert_eq!(headers.get("DD-API-KEY").unwrap(), "<api_key>");
+    assert_eq!(
+        headers.get("DD-APPLICATION-KEY").unwrap(),
+        "<application_key>"
+    );
+    assert_eq!(headers.get("Accept").unwrap(), "application/json");
+    assert_eq!(headers.get("X-Custom").unwrap(), "custom");
+}
+
+#[test]
+fn client_from_env_config() {
+    let env_config = types::env::EnvConfig::from(
+        "DD_API_KEY=<api_key>,DD_APPLICATION_KEY=<application_key>".to_string(),
+    );
+    let client = Client::from(env_config);
+    assert_eq!(client.api_key, Some("<api_key>".to_string()));
+    assert_eq!(
+        client.application_key,
+        Some("<application_key>".to_string())
+    );
+    assert_eq!(client.site, Site::US1);
+}
+
+#[test]
+fn client_shares_configuration_with_routes() {
+    let mut client = Client::new()
+        .with_api_key("<api_key>")
+        .with_base_url("http://localhost:8080/api/");
+    let route = series::Series::new().with_client(client.v2().clone());
+    let route_client = route.client.unwrap();
+    assert_eq!(route_client.site.api_url(), "http://localhost:8080/api");
+    assert_eq!(route_client.api_key, Some("<api_key>".to_string()));
+
+    // Routes created without a client only resolve one when executed
+    assert!(series::Series::new().client.is_none());
+}
@@ -0,0 +1,171 @@
+//! Minimal local stand-ins for the Datadog API and agent
+#![allow(dead_code)]
+
+use std::{
+    io::{Read, Write},
+    net::{TcpListener, UdpSocket},
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+/// A request received by the mock server
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    /// The request line, ie `POST /api/v2/series HTTP/1.1`
+    pub request_line: String,
+    /// The request headers with lowercase names
+    pub headers: Vec<(String, String)>,
+    /// The raw request body
+    pub body: Vec<u8>,
+}
+
+impl MockRequest {
+    /// Returns the value of the given (lowercase) header
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// A canned response returned by the mock server
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl MockResponse {
+    pub fn new(status: u16, body: &str) -> Self {
+        Self {
+            status,
+            headers: vec![],
+            body: body.to_string(),
+        }
+    }
+
+    pub fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.push((key.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// A mock server answering each connection with the next canned response
+pub struct MockServer {
+    /// The base api url of the server, ie `http://127.0.0.1:1234/api`
+    pub url: String,
+    /// The requests received so far
+    pub requests: Arc<Mutex<Vec<MockRequest>>>,
+}
+
+impl MockServer {
+    /// Starts a server replying with the given responses in order, repeating the last one
+    pub fn start(responses: Vec<MockResponse>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/api", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let received = requests.clone();
+        thread::spawn(move || {
+            let mut served = 0;
+            for stream in listener.incoming() {
+                let mut stream = match stream {
+                    Ok(s) => s,
+                    Err(_) => break,
+                };
+                let request = match read_request(&mut stream) {
+                    Some(r) => r,
+                    None => continue,
+                };
+                received.lock().unwrap().push(request);
+                let response = &responses[served.min(responses.len() - 1)];
+                served += 1;
+                let mut raw = format!(
+                    "HTTP/1.1 {} Mock\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n",
+                    response.status,
+                    response.body.len()
+                );
+                for (k, v) in &response.headers {
+                    raw.push_str(&format!("{}: {}\r\n", k, v));
+                }
+                raw.push_str("\r\n");
+                raw.push_str(&response.body);
+                let _ = stream.write_all(raw.as_bytes());
+            }
+        });
+        Self { url, requests }
+    }
+
+    /// Returns the requests received so far
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// A mock agent receiving DogStatsD datagrams over UDP
+pub struct MockAgent {
+    /// The `host:port` address of the agent
+    pub addr: String,
+    socket: UdpSocket,
+}
+
+impl MockAgent {
+    /// Binds the agent to a random local port
+    pub fn start() -> Self {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        socket
+            .set_read_timeout(Some(Duration::from_millis(200)))
+            .unwrap();
+        let addr = socket.local_addr().unwrap().to_string();
+        Self { addr, socket }
+    }
+
+    /// Returns the next datagram, or `None` if nothing arrives before the read timeout
+    pub fn recv(&self) -> Option<String> {
+        let mut buf = [0u8; 65_535];
+        let n = self.socket.recv(&mut buf).ok()?;
+        Some(String::from_utf8_lossy(&buf[..n]).to_string())
+    }
+}
+
+fn read_request(stream: &mut impl Read) -> Option<MockRequest> {
+    let mut buf = vec![];
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        let n = stream.read(&mut chunk).ok()?;
+        if n == 0 {
+            return None;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(i) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break i + 4;
+        }
+    };
+    let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+    let mut lines = head.split("\r\n");
+    let request_line = lines.next()?.to_string();
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|l| l.split_once(':'))
+        .map(|(k, v)| (k.trim().to_lowercase(), v.trim().to_string()))
+        .collect();
+    let length = headers
+        .iter()
+        .find(|(k, _)| k == "content-length")
+        .and_then(|(_, v)| v.parse::<usize>().ok())
+        .unwrap_or(0);
+    let mut body = buf[header_end..].to_vec();
+    while body.len() < length {
+        let n = stream.read(&mut chunk).ok()?;
+        if n == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..n]);
+    }
+    Some(MockRequest {
+        request_line,
+        headers,
+        body,
+    })
+}
@@ -0,0 +1,97 @@
+use std::io::Read;
+
+use ddog::prelude::*;
+
+mod common;
+use common::{MockResponse, MockServer};
+
+fn large_series() -> Vec<series::MetricSeries> {
+    (0..100)
+        .map(|i| {
+            series::MetricSeries::new("rpc_latency", series::MetricIntakeType::Gauge)
+                .with_tag(&format!("worker:{}", i))
+                .with_point(1660157680, i as f64)
+        })
+        .collect()
+}
+
+#[test]
+fn series_bodies_are_gzipped_above_threshold() {
+    let server = MockServer::start(vec![MockResponse::new(202, r#"{ "errors": [] }"#)]);
+    let client = Client::new()
+        .with_base_url(&server.url)
+        .with_compression(Compression::gzip());
+
+    tokio_test::block_on(async {
+        let res = client
+            .post_series()
+            .unwrap()
+            .series(large_series())
+            .execute()
+            .await;
+        assert_eq!(res.unwrap().status, 202);
+    });
+
+    let request = &server.requests()[0];
+    assert_eq!(request.header("content-encoding"), Some("gzip"));
+    let mut decoded = String::new();
+    flate2::read::GzDecoder::new(&request.body[..])
+        .read_to_string(&mut decoded)
+        .unwrap();
+    let payload: series::SeriesPayload = serde_json::from_str(&decoded).unwrap();
+    assert_eq!(payload.series, large_series());
+    assert!(request.body.len() < decoded.len());
+}
+
+#[test]
+fn distribution_bodies_are_deflated() {
+    let server = MockServer::start(vec![MockResponse::new(202, r#"{ "status": "ok" }"#)]);
+    let body = format!(
+        r#"{{ "series": [{{ "metric": "rpc_latency", "points": [ [ 1636629071, [ {} ] ] ] }}] }}"#,
+        vec!["1.0"; 1000].join(", ")
+    );
+
+    tokio_test::block_on(async {
+        let res = Client::new()
+            .with_base_url(&server.url)
+            .v1()
+            .post_distribution()
+            .unwrap()
+            .with_compression(Compression::deflate())
+            .body(body.clone())
+            .execute()
+            .await;
+        assert_eq!(res.unwrap().status, 202);
+    });
+
+    let request = &server.requests()[0];
+    assert_eq!(request.header("content-encoding"), Some("deflate"));
+    let mut decoded = String::new();
+    flate2::read::ZlibDecoder::new(&request.body[..])
+        .read_to_string(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded, body);
+}
+
+#[test]
+fn small_bodies_skip_compression() {
+    let server = MockServer::start(vec![MockResponse::new(202, r#"{ "errors": [] }"#)]);
+    let client = Client::new()
+        .with_base_url(&server.url)
+        .with_compression(Compression::gzip().with_threshold(1 << 20));
+
+    tokio_test::block_on(async {
+        let res = client
+            .post_series()
+            .unwrap()
+            .series(large_series())
+            .execute()
+            .await;
+        assert_eq!(res.unwrap().status, 202);
+    });
+
+    let request = &server.requests()[0];
+    assert_eq!(request.header("content-encoding"), None);
+    let payload: series::SeriesPayload = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(payload.series.len(), 100);
+}
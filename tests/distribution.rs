@@ -3,6 +3,9 @@ use ddog::prelude::*;
 extern crate dotenv;
 use dotenv::dotenv;
 
+mod common;
+use common::{MockResponse, MockServer};
+
 #[test]
 fn post_distribution_points() {
     // Read id and key from environment variables
@@ -19,8 +22,9 @@ fn post_distribution_points() {
         builder_ref = builder_ref.with_subscriber();
     }
     tokio_test::block_on(async {
-        let (status, res) = builder_ref
+        let res = builder_ref
             .post_distribution()
+            .unwrap()
             .headers(vec![
                 ("Accept", "application/json"),
                 ("Content-Type", "application/json"),
@@ -41,9 +45,10 @@ fn post_distribution_points() {
             .await;
 
         tracing::info!(target: "/v2/distribution_points", "Response: {:?}", res);
-        assert_eq!(status, 202);
+        let response = res.unwrap();
+        assert_eq!(response.status, 202);
         assert_eq!(
-            res.unwrap(),
+            response.data,
             distribution::DistributionResponse {
                 status: String::from("ok")
             }
@@ -67,8 +72,9 @@ fn post_distribution_invalid_json() {
         builder_ref = builder_ref.with_subscriber();
     }
     tokio_test::block_on(async {
-        let (status, res) = builder_ref
+        let res = builder_ref
             .post_distribution()
+            .unwrap()
             .headers(vec![
                 ("Accept", "application/json"),
                 ("Content-Type", "application/json"),
@@ -90,7 +96,10 @@ fn post_distribution_invalid_json() {
             .await;
 
         tracing::info!(target: "/v2/distribution_points", "Request Error: {:?}", res);
-        assert_eq!(status, reqwest::StatusCode::from_u16(400).unwrap());
+        assert_eq!(
+            res.unwrap_err().status(),
+            Some(reqwest::StatusCode::from_u16(400).unwrap())
+        );
     });
 }
 
@@ -106,8 +115,9 @@ fn distribution_post_fails_invalid_api_key() {
         builder_ref = builder_ref.with_subscriber();
     }
     tokio_test::block_on(async {
-        let (status, res) = builder_ref
+        let res = builder_ref
             .post_distribution()
+            .unwrap()
             .headers(vec![
                 ("Accept", "application/json"),
                 ("Content-Type", "application/json"),
@@ -128,6 +138,64 @@ fn distribution_post_fails_invalid_api_key() {
             .await;
 
         tracing::info!(target: "/v2/distribution_points", "Unauthorized Error: {:?}", res);
-        assert_eq!(status, reqwest::StatusCode::from_u16(403).unwrap());
+        assert_eq!(
+            res.unwrap_err().status(),
+            Some(reqwest::StatusCode::from_u16(403).unwrap())
+        );
+    });
+}
+
+#[test]
+fn post_typed_distribution_points() {
+    let server = MockServer::start(vec![MockResponse::new(202, r#"{ "status": "ok" }"#)]);
+    let mut client = Client::new()
+        .with_api_key("<api_key>")
+        .with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let res = client
+            .v1()
+            .post_distribution()
+            .unwrap()
+            .series(vec![distribution::DistributionSeries::new("rpc_latency")
+                .with_host("cloudflare_worker_1.jpeg")
+                .with_tag("gridfin:")
+                .with_point(1636629071, vec![1.0, 2.0])])
+            .execute()
+            .await;
+        let response = res.unwrap();
+        assert_eq!(response.status, 202);
+        assert_eq!(response.data.status, "ok");
     });
+
+    let request = &server.requests()[0];
+    assert_eq!(
+        request.request_line,
+        "POST /api/v1/distribution_points HTTP/1.1"
+    );
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "series": [{
+                "host": "cloudflare_worker_1.jpeg",
+                "metric": "rpc_latency",
+                "points": [ [ 1636629071, [ 1.0, 2.0 ] ] ],
+                "tags": [ "gridfin:" ],
+                "type": "distribution"
+            }]
+        })
+    );
+}
+
+#[test]
+fn deserialize_distribution_payload() {
+    let payload: distribution::DistributionPayload = serde_json::from_str(
+        r#"{ "series": [{ "metric": "rpc_latency", "points": [ [ 1636629071, [ 1.0, 2.0 ] ] ] }] }"#,
+    )
+    .unwrap();
+    let point = &payload.series[0].points[0];
+    assert_eq!(point.timestamp, 1636629071);
+    assert_eq!(point.values, vec![1.0, 2.0]);
+    assert_eq!(payload.series[0].type_, None);
 }
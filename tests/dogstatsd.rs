@@ -0,0 +1,354 @@
+use std::time::Duration;
+
+use ddog::prelude::*;
+
+mod common;
+use common::MockAgent;
+
+#[test]
+fn renders_metric_datagrams() {
+    use dogstatsd::metric::Metric;
+
+    assert_eq!(
+        Metric::count("page.views", 1.0).to_string(),
+        "page.views:1|c"
+    );
+    assert_eq!(
+        Metric::gauge("fuel.level", 0.5).to_string(),
+        "fuel.level:0.5|g"
+    );
+    assert_eq!(
+        Metric::histogram("song.length", 240.0).to_string(),
+        "song.length:240|h"
+    );
+    assert_eq!(
+        Metric::distribution("request.latency", 12.25).to_string(),
+        "request.latency:12.25|d"
+    );
+    assert_eq!(
+        Metric::set("users.uniques", "1234").to_string(),
+        "users.uniques:1234|s"
+    );
+    assert_eq!(
+        Metric::timer("query.time", Duration::from_millis(250)).to_string(),
+        "query.time:250|ms"
+    );
+    assert_eq!(
+        Metric::count("page.views", 1.0)
+            .with_sample_rate(0.25)
+            .with_tags(vec!["env:prod", "route:/"])
+            .to_string(),
+        "page.views:1|c|@0.25|#env:prod,route:/"
+    );
+    // A sample rate of one is implied
+    assert_eq!(
+        Metric::count("page.views", 1.0)
+            .with_sample_rate(1.0)
+            .to_string(),
+        "page.views:1|c"
+    );
+    // Reserved characters are replaced
+    assert_eq!(
+        Metric::gauge("bad|name:x", 1.0)
+            .with_tag("a,b|c")
+            .to_string(),
+        "bad_name_x:1|g|#a_b_c"
+    );
+}
+
+#[test]
+fn sends_metrics_to_agent() {
+    let agent = MockAgent::start();
+    let statsd = DogStatsd::new(&agent.addr)
+        .unwrap()
+        .with_tags(vec!["service:ddog"]);
+
+    statsd.incr("page.views", &["route:/"]).unwrap();
+    statsd.decr("workers", &[]).unwrap();
+    statsd.count("bytes", 512.0, &[]).unwrap();
+    statsd.gauge("queue.depth", 3.0, &[]).unwrap();
+    statsd.histogram("request.size", 128.0, &[]).unwrap();
+    statsd.distribution("request.latency", 1.5, &[]).unwrap();
+    statsd.set("users.uniques", "alice", &[]).unwrap();
+    statsd
+        .timing("query.time", Duration::from_millis(20), &[])
+        .unwrap();
+
+    let expected = [
+        "page.views:1|c|#route:/,service:ddog",
+        "workers:-1|c|#service:ddog",
+        "bytes:512|c|#service:ddog",
+        "queue.depth:3|g|#service:ddog",
+        "request.size:128|h|#service:ddog",
+        "request.latency:1.5|d|#service:ddog",
+        "users.uniques:alice|s|#service:ddog",
+        "query.time:20|ms|#service:ddog",
+    ];
+    for datagram in expected {
+        assert_eq!(agent.recv().as_deref(), Some(datagram));
+    }
+    assert_eq!(agent.recv(), None);
+}
+
+#[test]
+fn samples_metrics() {
+    let agent = MockAgent::start();
+    let statsd = DogStatsd::new(&agent.addr).unwrap();
+
+    let metric = dogstatsd::metric::Metric::count("page.views", 1.0).with_sample_rate(0.5);
+    for _ in 0..200 {
+        statsd.send(&metric).unwrap();
+    }
+
+    let mut received = 0;
+    while let Some(datagram) = agent.recv() {
+        assert_eq!(datagram, "page.views:1|c|@0.5");
+        received += 1;
+    }
+    assert!(received > 0 && received < 200, "received {}", received);
+}
+
+#[test]
+fn rejects_unresolvable_agent_address() {
+    assert!(DogStatsd::new("not an address").is_err());
+}
+
+#[test]
+fn buffers_metrics_up_to_max_payload_size() {
+    let agent = MockAgent::start();
+    let statsd = DogStatsd::new(&agent.addr)
+        .unwrap()
+        .with_buffering()
+        .with_max_payload_size(32);
+
+    statsd.incr("page.views", &[]).unwrap();
+    statsd.incr("page.views", &[]).unwrap();
+    // Nothing is sent until the buffer is full
+    assert_eq!(agent.recv(), None);
+    statsd.gauge("queue.depth", 3.0, &[]).unwrap();
+    assert_eq!(
+        agent.recv().as_deref(),
+        Some("page.views:1|c\npage.views:1|c")
+    );
+    statsd.flush().unwrap();
+    assert_eq!(agent.recv().as_deref(), Some("queue.depth:3|g"));
+
+    // Dropping the last clone flushes the buffer
+    let clone = statsd.clone();
+    clone.incr("page.views", &[]).unwrap();
+    drop(statsd);
+    assert_eq!(agent.recv(), None);
+    drop(clone);
+    assert_eq!(agent.recv().as_deref(), Some("page.views:1|c"));
+}
+
+#[test]
+fn rejects_metrics_larger_than_max_payload_size() {
+    let agent = MockAgent::start();
+    let statsd = DogStatsd::new(&agent.addr)
+        .unwrap()
+        .with_buffering()
+        .with_max_payload_size(32);
+
+    statsd.incr("page.views", &[]).unwrap();
+    let error = statsd
+        .gauge("queue.depth", 3.0, &["queue:a_very_long_queue_name"])
+        .unwrap_err();
+    assert!(matches!(error, Error::Validation(_)));
+    // The buffered metrics are left untouched
+    assert_eq!(agent.recv(), None);
+    statsd.flush().unwrap();
+    assert_eq!(agent.recv().as_deref(), Some("page.views:1|c"));
+
+    let statsd = DogStatsd::new(&agent.addr)
+        .unwrap()
+        .with_max_payload_size(32);
+    assert!(statsd
+        .gauge("queue.depth", 3.0, &["queue:a_very_long_queue_name"])
+        .is_err());
+    assert_eq!(agent.recv(), None);
+}
+
+#[test]
+fn sends_container_id() {
+    let agent = MockAgent::start();
+    let statsd = DogStatsd::new(&agent.addr)
+        .unwrap()
+        .with_tags(vec!["env:prod"])
+        .with_container_id("abc123");
+
+    statsd.incr("page.views", &[]).unwrap();
+    assert_eq!(
+        agent.recv().as_deref(),
+        Some("page.views:1|c|#env:prod|c:abc123")
+    );
+}
+
+#[test]
+fn parses_container_id_from_cgroup() {
+    use dogstatsd::parse_container_id;
+
+    let docker = "\
+12:devices:/docker/3726184226f5d3147c25fdeab5b60097e378e8a720503a5e19ecfdf29f869860
+11:pids:/docker/3726184226f5d3147c25fdeab5b60097e378e8a720503a5e19ecfdf29f869860
+";
+    assert_eq!(
+        parse_container_id(docker).as_deref(),
+        Some("3726184226f5d3147c25fdeab5b60097e378e8a720503a5e19ecfdf29f869860")
+    );
+    let systemd = "1:name=systemd:/kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod2d3da189_6407_48e3_9ab6_78188d75e609.slice/cri-containerd-2dde6f1d7e9a7a4f7b0cc3c7ad8b2f0dbc1b4c6e6bb4dbd1e1a3b0e4f8c6d5a1.scope\n";
+    assert_eq!(
+        parse_container_id(systemd).as_deref(),
+        Some("2dde6f1d7e9a7a4f7b0cc3c7ad8b2f0dbc1b4c6e6bb4dbd1e1a3b0e4f8c6d5a1")
+    );
+    let fargate = "1:name=systemd:/ecs/34dc0b5e626f2c5c4c5170e34b10e765-1234567890\n";
+    assert_eq!(
+        parse_container_id(fargate).as_deref(),
+        Some("34dc0b5e626f2c5c4c5170e34b10e765-1234567890")
+    );
+    let uuid = "1:name=systemd:/uuid/34dc0b5e-626f-2c5c-4c51-70e34b10e765\n";
+    assert_eq!(
+        parse_container_id(uuid).as_deref(),
+        Some("34dc0b5e-626f-2c5c-4c51-70e34b10e765")
+    );
+    assert_eq!(parse_container_id("0::/user.slice/session-2.scope\n"), None);
+}
+
+#[cfg(unix)]
+fn socket_path(name: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("ddog-{}-{}.socket", name, std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[cfg(unix)]
+#[test]
+fn sends_metrics_over_unix_datagram_socket() {
+    let path = socket_path("dgram");
+    let listener = std::os::unix::net::UnixDatagram::bind(&path).unwrap();
+    listener
+        .set_read_timeout(Some(Duration::from_millis(200)))
+        .unwrap();
+
+    let statsd = DogStatsd::new(&format!("unix://{}", path.display()))
+        .unwrap()
+        .with_buffering();
+    assert_eq!(
+        statsd.max_payload_size,
+        dogstatsd::DEFAULT_UDS_MAX_PAYLOAD_SIZE
+    );
+    statsd.incr("page.views", &["route:/"]).unwrap();
+    statsd.gauge("queue.depth", 3.0, &[]).unwrap();
+    statsd.flush().unwrap();
+
+    let mut buf = [0u8; 8192];
+    let n = listener.recv(&mut buf).unwrap();
+    assert_eq!(&buf[..n], b"page.views:1|c|#route:/\nqueue.depth:3|g");
+    let _ = std::fs::remove_file(&path);
+}
+
+#[cfg(unix)]
+#[test]
+fn sends_metrics_over_unix_stream_socket() {
+    use std::io::Read;
+
+    let path = socket_path("stream");
+    let listener = std::os::unix::net::UnixListener::bind(&path).unwrap();
+
+    let statsd = DogStatsd::new(&format!("unixstream://{}", path.display())).unwrap();
+    statsd.incr("page.views", &[]).unwrap();
+    statsd.gauge("queue.depth", 3.0, &[]).unwrap();
+
+    let (mut stream, _) = listener.accept().unwrap();
+    let mut frames = vec![];
+    for _ in 0..2 {
+        let mut len = [0u8; 4];
+        stream.read_exact(&mut len).unwrap();
+        let mut frame = vec![0u8; u32::from_le_bytes(len) as usize];
+        stream.read_exact(&mut frame).unwrap();
+        frames.push(String::from_utf8(frame).unwrap());
+    }
+    assert_eq!(frames, vec!["page.views:1|c", "queue.depth:3|g"]);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn renders_event_datagrams() {
+    use dogstatsd::event::{AlertType, Event, Priority};
+
+    assert_eq!(
+        Event::new("Deploy", "Deployed v1.2.3").to_string(),
+        "_e{6,15}:Deploy|Deployed v1.2.3"
+    );
+    assert_eq!(
+        Event::new("Déploiement", "a\nb|c")
+            .with_timestamp(1636629071)
+            .with_hostname("worker_1")
+            .with_aggregation_key("deploys")
+            .with_priority(Priority::Low)
+            .with_source_type_name("deploy-tool")
+            .with_alert_type(AlertType::Error)
+            .with_tags(vec!["service:api", "env:prod"])
+            .to_string(),
+        "_e{12,6}:Déploiement|a\\nb|c|d:1636629071|h:worker_1|k:deploys|p:low|s:deploy-tool|t:error|#service:api,env:prod"
+    );
+}
+
+#[test]
+fn renders_service_check_datagrams() {
+    use dogstatsd::service_check::{ServiceCheck, ServiceCheckStatus};
+
+    assert_eq!(
+        ServiceCheck::new("app.is_up", ServiceCheckStatus::Ok).to_string(),
+        "_sc|app.is_up|0"
+    );
+    assert_eq!(
+        ServiceCheck::new("app.is_up", ServiceCheckStatus::Warning)
+            .with_timestamp(1636629071)
+            .with_hostname("worker_1")
+            .with_tags(vec!["service:api"])
+            .with_message("disk: 91%\nm: high")
+            .to_string(),
+        "_sc|app.is_up|1|d:1636629071|h:worker_1|#service:api|m:disk: 91%\\nm\\: high"
+    );
+    assert_eq!(
+        ServiceCheck::new("app.is_up", ServiceCheckStatus::Critical).to_string(),
+        "_sc|app.is_up|2"
+    );
+    assert_eq!(
+        ServiceCheck::new("app.is_up", ServiceCheckStatus::Unknown).to_string(),
+        "_sc|app.is_up|3"
+    );
+}
+
+#[test]
+fn sends_events_and_service_checks_to_agent() {
+    use dogstatsd::{
+        event::Event,
+        service_check::{ServiceCheck, ServiceCheckStatus},
+    };
+
+    let agent = MockAgent::start();
+    let statsd = DogStatsd::new(&agent.addr)
+        .unwrap()
+        .with_tags(vec!["env:prod"])
+        .with_container_id("abc123");
+
+    statsd
+        .send_event(&Event::new("Deploy", "Deployed v1.2.3").with_tag("service:api"))
+        .unwrap();
+    statsd
+        .send_service_check(
+            &ServiceCheck::new("app.is_up", ServiceCheckStatus::Ok).with_message("all good"),
+        )
+        .unwrap();
+
+    assert_eq!(
+        agent.recv().as_deref(),
+        Some("_e{6,15}:Deploy|Deployed v1.2.3|#service:api,env:prod|c:abc123")
+    );
+    assert_eq!(
+        agent.recv().as_deref(),
+        Some("_sc|app.is_up|0|#env:prod|m:all good|c:abc123")
+    );
+}
@@ -0,0 +1,57 @@
+use ddog::prelude::*;
+
+#[test]
+fn decodes_datadog_errors_array() {
+    let error = Error::from_response(
+        reqwest::StatusCode::FORBIDDEN,
+        br#"{ "errors": [ "Forbidden" ] }"#,
+    );
+    assert_eq!(error.status(), Some(reqwest::StatusCode::FORBIDDEN));
+    match error {
+        Error::Status { status, errors, .. } => {
+            assert_eq!(status, 403);
+            assert_eq!(errors, vec![String::from("Forbidden")]);
+        }
+        e => panic!("Expected a status error, got {:?}", e),
+    }
+}
+
+#[test]
+fn decodes_non_json_error_body() {
+    let error = Error::from_response(reqwest::StatusCode::TOO_MANY_REQUESTS, b"Slow down");
+    match error {
+        Error::Status { status, errors, .. } => {
+            assert_eq!(status, 429);
+            assert_eq!(errors, vec![String::from("Slow down")]);
+        }
+        e => panic!("Expected a status error, got {:?}", e),
+    }
+}
+
+#[test]
+fn unsupported_version_error() {
+    match distribution::Distribution::try_from(ApiVersion::V2) {
+        Err(Error::UnsupportedVersion(version)) => assert_eq!(version, ApiVersion::V2),
+        r => panic!("Expected an unsupported version error, got {:?}", r),
+    }
+}
+
+#[test]
+fn factories_return_unsupported_version_error() {
+    let mut client = Client::new();
+    assert!(matches!(
+        client.v1().post_series(),
+        Err(Error::UnsupportedVersion(ApiVersion::V1))
+    ));
+    assert!(matches!(
+        client.v2().get_monitor(1234567),
+        Err(Error::UnsupportedVersion(ApiVersion::V2))
+    ));
+
+    let mut builder = Builder::new();
+    assert!(matches!(
+        builder.v2().post_distribution(),
+        Err(Error::UnsupportedVersion(ApiVersion::V2))
+    ));
+    assert!(builder.v1().post_distribution().is_ok());
+}
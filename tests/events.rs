@@ -0,0 +1,145 @@
+use ddog::prelude::*;
+
+mod common;
+use common::{MockResponse, MockServer};
+
+const EVENT: &str = r#"{
+    "event": {
+        "alert_type": "success",
+        "date_happened": 1636629071,
+        "id": 6509751066204996294,
+        "id_str": "6509751066204996294",
+        "priority": "normal",
+        "tags": [ "service:api" ],
+        "text": "Deployed v1.2.3",
+        "title": "Deploy",
+        "url": "https://app.datadoghq.com/event/event?id=6509751066204996294"
+    },
+    "status": "ok"
+}"#;
+
+#[test]
+fn post_typed_event() {
+    let server = MockServer::start(vec![MockResponse::new(202, EVENT)]);
+    let mut client = Client::new()
+        .with_api_key("<api_key>")
+        .with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let res = client
+            .v1()
+            .post_event()
+            .unwrap()
+            .event(
+                post_event::Event::new("Deploy", "Deployed v1.2.3")
+                    .with_alert_type(post_event::EventAlertType::Success)
+                    .with_date_happened(1636629071)
+                    .with_tag("service:api"),
+            )
+            .execute()
+            .await;
+        let response = res.unwrap();
+        assert_eq!(response.status, 202);
+        assert_eq!(response.data.status.as_deref(), Some("ok"));
+        assert_eq!(response.data.event.id, Some(6509751066204996294));
+        assert_eq!(
+            response.data.event.alert_type,
+            Some(post_event::EventAlertType::Success)
+        );
+    });
+
+    let request = &server.requests()[0];
+    assert_eq!(request.request_line, "POST /api/v1/events HTTP/1.1");
+    assert_eq!(request.header("dd-api-key"), Some("<api_key>"));
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "title": "Deploy",
+            "text": "Deployed v1.2.3",
+            "alert_type": "success",
+            "date_happened": 1636629071,
+            "tags": [ "service:api" ]
+        })
+    );
+}
+
+#[test]
+fn get_event_by_id() {
+    let server = MockServer::start(vec![MockResponse::new(200, EVENT)]);
+    let mut builder = Builder::new();
+    builder.v1().with_site(Site::Custom(server.url.clone()));
+
+    tokio_test::block_on(async {
+        let res = builder
+            .get_event(6509751066204996294)
+            .unwrap()
+            .with_api_key("<api_key>")
+            .with_application_key("<application_key>")
+            .execute()
+            .await;
+        let response: types::response::Response<post_event::EventResponse> = res.unwrap();
+        assert_eq!(response.data.event.title, "Deploy");
+        assert_eq!(response.data.event.tags, vec!["service:api"]);
+    });
+
+    let request = &server.requests()[0];
+    assert_eq!(
+        request.request_line,
+        "GET /api/v1/events/6509751066204996294 HTTP/1.1"
+    );
+    assert_eq!(
+        request.header("dd-application-key"),
+        Some("<application_key>")
+    );
+}
+
+#[test]
+fn list_events_in_time_range() {
+    let server = MockServer::start(vec![MockResponse::new(
+        200,
+        r#"{ "events": [ { "id": 1, "title": "Deploy", "text": "v1" }, { "id": 2, "title": "Deploy", "text": "v2" } ], "status": "ok" }"#,
+    )]);
+    let mut client = Client::new().with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let res = client
+            .v1()
+            .list_events(1636629071, 1636632671)
+            .unwrap()
+            .with_priority(post_event::EventPriority::Normal)
+            .with_source("jenkins")
+            .with_tag("service:api")
+            .with_tag("env:prod")
+            .unaggregated(true)
+            .execute()
+            .await;
+        let events = res.unwrap().into_inner().events;
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].text, "v2");
+    });
+
+    let request = &server.requests()[0];
+    assert_eq!(
+        request.request_line,
+        "GET /api/v1/events?start=1636629071&end=1636632671&priority=normal&sources=jenkins&tags=service%3Aapi%2Cenv%3Aprod&unaggregated=true HTTP/1.1"
+    );
+}
+
+#[test]
+fn get_event_requires_event_id() {
+    let route = get_event::GetEvent::default();
+    let res = tokio_test::block_on(types::route::Route::<post_event::EventResponse>::execute(
+        route,
+    ));
+    assert!(matches!(res, Err(Error::Validation(_))));
+}
+
+#[test]
+fn events_require_v1() {
+    assert!(matches!(
+        post_event::PostEvent::try_from(ApiVersion::V2),
+        Err(Error::UnsupportedVersion(ApiVersion::V2))
+    ));
+    assert!(list_events::ListEvents::try_from(ApiVersion::V1).is_ok());
+}
@@ -3,6 +3,13 @@ use ddog::prelude::*;
 extern crate dotenv;
 use dotenv::dotenv;
 
+use chrono::{TimeZone, Utc};
+use futures_util::StreamExt;
+use get_metrics::TagFilter;
+
+mod common;
+use common::{MockResponse, MockServer};
+
 #[test]
 fn get_metrics() {
     // Read id and key from environment variables
@@ -20,8 +27,10 @@ fn get_metrics() {
         builder_ref = builder_ref.with_subscriber();
     }
     tokio_test::block_on(async {
-        let (status, res) = builder_ref
-            .get_metrics(0, None, Some("metric_name:rpc_latency".to_string()))
+        let res = builder_ref
+            .get_metrics(chrono::DateTime::UNIX_EPOCH)
+            .unwrap()
+            .set_tag_filter(get_metrics::TagFilter::tag("metric_name", "rpc_latency"))
             .headers(vec![
                 ("Accept", "application/json"),
                 ("Content-Type", "application/json"),
@@ -32,9 +41,155 @@ fn get_metrics() {
             .await;
 
         tracing::info!(target: "/v1/metrics", "Response: {:?}", res);
-        assert_eq!(status, 200);
         let unwrapped_response = res.unwrap();
+        assert_eq!(unwrapped_response.status, 200);
         assert_eq!(unwrapped_response.from, String::from("0"));
         assert_ne!(unwrapped_response.metrics.len(), 0);
     });
 }
+
+#[test]
+fn get_metrics_omits_empty_parameters() {
+    let server = MockServer::start(vec![MockResponse::new(
+        200,
+        r#"{ "from": "1700000000", "metrics": [ "system.cpu.idle" ] }"#,
+    )]);
+    let mut client = Client::new().with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let res = client
+            .v1()
+            .get_metrics(Utc.timestamp_opt(1700000000, 0).unwrap())
+            .unwrap()
+            .set_host(String::new())
+            .execute()
+            .await;
+        assert_eq!(res.unwrap().metrics, vec!["system.cpu.idle"]);
+    });
+
+    assert_eq!(
+        server.requests()[0].request_line,
+        "GET /api/v1/metrics?from=1700000000 HTTP/1.1"
+    );
+}
+
+#[test]
+fn get_metrics_with_tag_filter_expression() {
+    let server = MockServer::start(vec![MockResponse::new(
+        200,
+        r#"{ "from": "1700000000", "metrics": [] }"#,
+    )]);
+    let mut builder = Builder::new();
+    builder.v1().with_site(Site::Custom(server.url.clone()));
+
+    tokio_test::block_on(async {
+        let res = builder
+            .get_metrics(Utc.timestamp_opt(1700000000, 0).unwrap())
+            .unwrap()
+            .set_host(String::from("web-1"))
+            .set_tag_filter(TagFilter::tag("env", "prod").and(!TagFilter::tag("service", "web*")))
+            .execute()
+            .await;
+        assert!(res.unwrap().metrics.is_empty());
+    });
+
+    assert_eq!(
+        server.requests()[0].request_line,
+        "GET /api/v1/metrics?from=1700000000&host=web-1&tag_filter=env%3Aprod+AND+NOT+service%3Aweb* HTTP/1.1"
+    );
+}
+
+#[test]
+fn tag_filter_expressions() {
+    let filter = TagFilter::tag("env", "prod")
+        .or(TagFilter::tag("env", "staging"))
+        .and(TagFilter::from("service:web*"));
+    assert_eq!(
+        filter.to_string(),
+        "(env:prod OR env:staging) AND service:web*"
+    );
+    assert_eq!(
+        (!filter).to_string(),
+        "NOT ((env:prod OR env:staging) AND service:web*)"
+    );
+    assert_eq!(
+        TagFilter::tag("a", "1")
+            .or(TagFilter::tag("b", "2").and(TagFilter::tag("c", "3")))
+            .to_string(),
+        "a:1 OR (b:2 AND c:3)"
+    );
+    assert_eq!(
+        TagFilter::from("a:1 OR b:2")
+            .and(TagFilter::tag("k", "v"))
+            .to_string(),
+        "(a:1 OR b:2) AND k:v"
+    );
+    assert_eq!(
+        (!TagFilter::from("a:1 OR b:2")).to_string(),
+        "NOT (a:1 OR b:2)"
+    );
+    assert_eq!(TagFilter::from("a:1 OR b:2").to_string(), "a:1 OR b:2");
+}
+
+#[test]
+fn stream_active_metrics() {
+    let server = MockServer::start(vec![MockResponse::new(
+        200,
+        r#"{ "from": "0", "metrics": [ "system.cpu.idle", "system.load.1" ] }"#,
+    )]);
+    let mut client = Client::new().with_base_url(&server.url);
+
+    let metrics = tokio_test::block_on(
+        client
+            .v1()
+            .get_metrics(chrono::DateTime::UNIX_EPOCH)
+            .unwrap()
+            .stream()
+            .collect::<Vec<_>>(),
+    );
+    let metrics = metrics.into_iter().collect::<ddog::Result<Vec<_>>>();
+    assert_eq!(metrics.unwrap(), vec!["system.cpu.idle", "system.load.1"]);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn stream_active_metrics_per_host() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, r#"{ "from": "0", "metrics": [ "system.cpu.idle" ] }"#),
+        MockResponse::new(403, r#"{ "errors": [ "Forbidden" ] }"#),
+        MockResponse::new(200, r#"{ "from": "0", "metrics": [] }"#),
+    ]);
+    let mut client = Client::new().with_base_url(&server.url);
+
+    let hosts = tokio_test::block_on(
+        client
+            .v1()
+            .get_metrics(chrono::DateTime::UNIX_EPOCH)
+            .unwrap()
+            .set_tag_filter(TagFilter::tag("env", "prod"))
+            .per_host(vec!["web-1", "web-2", "web-3"])
+            .collect::<Vec<_>>(),
+    );
+    assert_eq!(hosts.len(), 3);
+    let (host, response) = &hosts[0];
+    assert_eq!(host, "web-1");
+    assert_eq!(response.as_ref().unwrap().metrics, vec!["system.cpu.idle"]);
+    let (host, response) = &hosts[1];
+    assert_eq!(host, "web-2");
+    assert_eq!(
+        response.as_ref().unwrap_err().status(),
+        Some(reqwest::StatusCode::FORBIDDEN)
+    );
+    assert_eq!(hosts[2].0, "web-3");
+    assert!(hosts[2].1.is_ok());
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[1].request_line,
+        "GET /api/v1/metrics?from=0&host=web-2&tag_filter=env%3Aprod HTTP/1.1"
+    );
+    assert_eq!(
+        requests[2].request_line,
+        "GET /api/v1/metrics?from=0&host=web-3&tag_filter=env%3Aprod HTTP/1.1"
+    );
+}
@@ -0,0 +1,152 @@
+use ddog::prelude::*;
+use tracing_subscriber::layer::SubscriberExt;
+
+mod common;
+use common::{MockResponse, MockServer};
+
+fn shipped_logs(server: &MockServer) -> Vec<serde_json::Value> {
+    server
+        .requests()
+        .iter()
+        .flat_map(|r| serde_json::from_slice::<Vec<serde_json::Value>>(&r.body).unwrap())
+        .collect()
+}
+
+#[test]
+fn ships_events_as_logs() {
+    let server = MockServer::start(vec![MockResponse::new(202, "{}")]);
+    let client = Client::new()
+        .with_api_key("<api_key>")
+        .with_base_url(&server.url);
+    let layer = LogsLayer::new(client)
+        .with_service("api")
+        .with_hostname("worker_1")
+        .with_tags(vec!["env:prod"]);
+
+    let subscriber = tracing_subscriber::registry().with(layer.clone());
+    tracing::subscriber::with_default(subscriber, || {
+        let span = tracing::info_span!("request", trace_id = 42_u64, route = "/");
+        let _guard = span.enter();
+        tracing::warn!(target: "app::auth", user = "alice", attempts = 3, "login failed");
+        tracing::debug!("not shipped");
+    });
+    tokio_test::block_on(layer.flush()).unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].request_line, "POST /api/v2/logs HTTP/1.1");
+
+    let logs = shipped_logs(&server);
+    assert_eq!(logs.len(), 1);
+    let log = &logs[0];
+    assert_eq!(log["message"], "login failed");
+    assert_eq!(log["status"], "warning");
+    assert_eq!(log["logger.name"], "app::auth");
+    assert_eq!(log["ddsource"], "rust");
+    assert_eq!(log["service"], "api");
+    assert_eq!(log["hostname"], "worker_1");
+    assert_eq!(log["ddtags"], "env:prod");
+    assert_eq!(log["user"], "alice");
+    assert_eq!(log["attempts"], 3);
+    assert_eq!(log["trace_id"], 42);
+    assert_eq!(log["route"], "/");
+    assert_eq!(log["spans"], serde_json::json!(["request"]));
+    assert!(log["date"].is_string());
+}
+
+#[test]
+fn records_span_fields_and_levels() {
+    let server = MockServer::start(vec![MockResponse::new(202, "{}")]);
+    let layer =
+        LogsLayer::new(Client::new().with_base_url(&server.url)).with_level(tracing::Level::DEBUG);
+
+    let subscriber = tracing_subscriber::registry().with(layer.clone());
+    tracing::subscriber::with_default(subscriber, || {
+        let outer = tracing::info_span!("job", id = 1, step = tracing::field::Empty);
+        let _outer = outer.enter();
+        outer.record("step", "load");
+        let inner = tracing::info_span!("query", id = 2);
+        let _inner = inner.enter();
+        tracing::debug!("querying");
+        tracing::error!(error = ?std::io::ErrorKind::NotFound, "query failed");
+        tracing::trace!("not shipped");
+    });
+    tokio_test::block_on(layer.flush()).unwrap();
+
+    let logs = shipped_logs(&server);
+    assert_eq!(logs.len(), 2);
+    assert_eq!(logs[0]["status"], "debug");
+    assert_eq!(logs[0]["step"], "load");
+    // Inner span fields take precedence
+    assert_eq!(logs[0]["id"], 2);
+    assert_eq!(logs[0]["spans"], serde_json::json!(["job", "query"]));
+    assert_eq!(logs[1]["status"], "error");
+    assert_eq!(logs[1]["error"], "NotFound");
+}
+
+#[test]
+fn reserved_fields_set_the_log_attributes() {
+    let server = MockServer::start(vec![MockResponse::new(202, "{}")]);
+    let layer = LogsLayer::new(Client::new().with_base_url(&server.url))
+        .with_service("api")
+        .with_tags(vec!["env:prod"]);
+
+    let subscriber = tracing_subscriber::registry().with(layer.clone());
+    tracing::subscriber::with_default(subscriber, || {
+        let span = tracing::info_span!("job", hostname = "worker_2");
+        let _guard = span.enter();
+        tracing::info!(
+            service = "billing",
+            ddtags = "team:payments",
+            "invoice sent"
+        );
+    });
+    tokio_test::block_on(layer.flush()).unwrap();
+
+    let body = String::from_utf8(server.requests()[0].body.clone()).unwrap();
+    assert_eq!(body.matches("\"service\"").count(), 1);
+    let logs = shipped_logs(&server);
+    assert_eq!(logs[0]["service"], "billing");
+    assert_eq!(logs[0]["hostname"], "worker_2");
+    assert_eq!(logs[0]["ddtags"], "env:prod,team:payments");
+}
+
+#[test]
+fn drops_events_when_buffer_is_full() {
+    let server = MockServer::start(vec![MockResponse::new(202, "{}")]);
+    let layer = LogsLayer::new(Client::new().with_base_url(&server.url)).with_max_buffered(2);
+
+    let subscriber = tracing_subscriber::registry().with(layer.clone());
+    tracing::subscriber::with_default(subscriber, || {
+        for i in 0..5 {
+            tracing::info!(i, "event");
+        }
+    });
+    tokio_test::block_on(async {
+        layer.flush().await.unwrap();
+        // Nothing left to send
+        layer.flush().await.unwrap();
+    });
+
+    let logs = shipped_logs(&server);
+    assert_eq!(logs.len(), 2);
+    assert_eq!(logs[1]["i"], 1);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn zero_flush_interval_is_clamped() {
+    let server = MockServer::start(vec![MockResponse::new(202, "{}")]);
+    let client = Client::new().with_base_url(&server.url);
+    let layer = LogsLayer::new(client).with_flush_interval(std::time::Duration::ZERO);
+    assert_eq!(layer.flush_interval, layer::MIN_FLUSH_INTERVAL);
+
+    let mut layer = layer;
+    layer.flush_interval = std::time::Duration::ZERO;
+    tokio_test::block_on(async {
+        let handle = layer.spawn();
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        handle.abort();
+        assert!(handle.await.unwrap_err().is_cancelled());
+    });
+}
@@ -0,0 +1,190 @@
+use std::io::Read;
+
+use ddog::prelude::*;
+
+mod common;
+use common::{MockResponse, MockServer};
+
+#[test]
+fn logs_are_sent_to_logs_intake_host() {
+    assert_eq!(
+        Site::US1.logs_intake_url(),
+        "https://http-intake.logs.datadoghq.com/api"
+    );
+    assert_eq!(
+        Site::US5.logs_intake_url(),
+        "https://http-intake.logs.us5.datadoghq.com/api"
+    );
+    assert_eq!(
+        Site::Custom(String::from("http://localhost:8080/api/")).logs_intake_url(),
+        "http://localhost:8080/api"
+    );
+}
+
+#[test]
+fn post_typed_logs() {
+    let server = MockServer::start(vec![MockResponse::new(202, "{}")]);
+    let client = Client::new()
+        .with_api_key("<api_key>")
+        .with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let res = client
+            .post_logs()
+            .unwrap()
+            .logs(vec![
+                send_logs::LogEntry::new("job started")
+                    .with_source("rust")
+                    .with_service("batch")
+                    .with_tag("env:prod"),
+                send_logs::LogEntry::new("job finished").with_attribute("duration", 1.5),
+            ])
+            .execute()
+            .await;
+        assert_eq!(res.unwrap().status, 202);
+    });
+
+    let request = &server.requests()[0];
+    assert_eq!(request.request_line, "POST /api/v2/logs HTTP/1.1");
+    assert_eq!(request.header("dd-api-key"), Some("<api_key>"));
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!([
+            {
+                "message": "job started",
+                "ddsource": "rust",
+                "ddtags": "env:prod",
+                "service": "batch"
+            },
+            { "message": "job finished", "duration": 1.5 }
+        ])
+    );
+}
+
+#[test]
+fn reserved_attributes_set_the_named_fields() {
+    let mut attributes = serde_json::Map::new();
+    attributes.insert(String::from("service"), serde_json::json!("billing"));
+    attributes.insert(String::from("ddtags"), serde_json::json!("team:payments"));
+    attributes.insert(String::from("retries"), serde_json::json!(3));
+    let entry = send_logs::LogEntry::new("invoice sent")
+        .with_service("batch")
+        .with_tag("env:prod")
+        .with_attribute("hostname", "worker_1")
+        .with_attribute("message", "invoice 42 sent")
+        .with_attributes(attributes);
+    assert_eq!(entry.service.as_deref(), Some("billing"));
+    assert_eq!(
+        serde_json::to_value(&entry).unwrap(),
+        serde_json::json!({
+            "message": "invoice 42 sent",
+            "ddtags": "env:prod,team:payments",
+            "hostname": "worker_1",
+            "service": "billing",
+            "retries": 3
+        })
+    );
+}
+
+#[test]
+fn reserved_keys_assigned_to_attributes_are_not_serialized() {
+    let mut entry = send_logs::LogEntry::new("invoice sent").with_service("batch");
+    entry
+        .attributes
+        .insert(String::from("service"), serde_json::json!("billing"));
+    entry
+        .attributes
+        .insert(String::from("retries"), serde_json::json!(3));
+    assert_eq!(
+        serde_json::to_string(&entry).unwrap(),
+        r#"{"message":"invoice sent","service":"batch","retries":3}"#
+    );
+}
+
+#[test]
+fn empty_logs_are_not_sent() {
+    let server = MockServer::start(vec![MockResponse::new(202, "{}")]);
+    let client = Client::new().with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let res = client.post_logs().unwrap().logs(vec![]).execute().await;
+        assert_eq!(res.unwrap().status, 202);
+    });
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn post_gzipped_logs() {
+    let server = MockServer::start(vec![MockResponse::new(202, "")]);
+    let client = Client::new()
+        .with_base_url(&server.url)
+        .with_compression(Compression::gzip().with_threshold(0));
+
+    let res = tokio_test::block_on(
+        client
+            .post_logs()
+            .unwrap()
+            .logs(vec![send_logs::LogEntry::new("compressed")])
+            .execute(),
+    );
+    assert_eq!(res.unwrap().status, 202);
+
+    let request = &server.requests()[0];
+    assert_eq!(request.header("content-encoding"), Some("gzip"));
+    let mut body = String::new();
+    flate2::read::GzDecoder::new(request.body.as_slice())
+        .read_to_string(&mut body)
+        .unwrap();
+    assert_eq!(body, r#"[{"message":"compressed"}]"#);
+}
+
+#[test]
+fn logs_are_batched_by_entry_count() {
+    let server = MockServer::start(vec![MockResponse::new(202, "{}")]);
+    let client = Client::new().with_base_url(&server.url);
+    let logs = (0..2500)
+        .map(|i| send_logs::LogEntry::new(&format!("entry {}", i)))
+        .collect::<Vec<_>>();
+
+    let results = tokio_test::block_on(client.post_logs().unwrap().logs(logs).execute_batches());
+    assert_eq!(results.len(), 3);
+    assert!(results.iter().all(|r| r.is_ok()));
+
+    let sizes = server
+        .requests()
+        .iter()
+        .map(|r| {
+            serde_json::from_slice::<Vec<serde_json::Value>>(&r.body)
+                .unwrap()
+                .len()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(sizes, vec![1000, 1000, 500]);
+}
+
+#[test]
+fn logs_are_batched_by_payload_size() {
+    let message = "a".repeat(1_000_000);
+    let logs = (0..12)
+        .map(|_| send_logs::LogEntry::new(&message))
+        .collect::<Vec<_>>();
+
+    let batches = send_logs::batch_logs(&logs).unwrap();
+    assert_eq!(batches.len(), 3);
+    for batch in &batches {
+        assert!(batch.len() <= send_logs::MAX_LOGS_PAYLOAD_SIZE);
+        serde_json::from_slice::<Vec<send_logs::LogEntry>>(batch).unwrap();
+    }
+}
+
+#[test]
+fn oversized_log_entry_is_rejected() {
+    let logs = vec![send_logs::LogEntry::new(
+        &"a".repeat(send_logs::MAX_LOG_ENTRY_SIZE),
+    )];
+    assert!(matches!(
+        send_logs::batch_logs(&logs),
+        Err(Error::Validation(_))
+    ));
+}
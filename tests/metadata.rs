@@ -0,0 +1,92 @@
+use ddog::prelude::*;
+use get_metadata::MetricMetadata;
+
+mod common;
+use common::{MockResponse, MockServer};
+
+const METADATA: &str = r#"{
+    "description": "The number of bytes sent per second",
+    "integration": "system",
+    "per_unit": "second",
+    "short_name": "bytes sent",
+    "statsd_interval": 10,
+    "type": "gauge",
+    "unit": "byte"
+}"#;
+
+#[test]
+fn get_metric_metadata() {
+    let server = MockServer::start(vec![MockResponse::new(200, METADATA)]);
+    let mut client = Client::new().with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let res = client
+            .v1()
+            .get_metric_metadata("system.net.bytes_sent")
+            .unwrap()
+            .execute()
+            .await;
+        let metadata = res.unwrap().into_inner();
+        assert_eq!(metadata.integration.as_deref(), Some("system"));
+        assert_eq!(metadata.type_, Some(tags::MetricType::Gauge));
+        assert_eq!(metadata.statsd_interval, Some(10));
+        assert_eq!(metadata.unit.as_deref(), Some("byte"));
+    });
+
+    assert_eq!(
+        server.requests()[0].request_line,
+        "GET /api/v1/metrics/system.net.bytes_sent HTTP/1.1"
+    );
+}
+
+#[test]
+fn update_metric_metadata() {
+    let server = MockServer::start(vec![MockResponse::new(200, METADATA)]);
+    let mut builder = Builder::new();
+    builder.v1().with_site(Site::Custom(server.url.clone()));
+
+    tokio_test::block_on(async {
+        let res = builder
+            .update_metric_metadata("system.net.bytes_sent")
+            .unwrap()
+            .metadata(
+                MetricMetadata::new()
+                    .with_description("The number of bytes sent per second")
+                    .with_short_name("bytes sent")
+                    .with_unit("byte")
+                    .with_per_unit("second"),
+            )
+            .execute()
+            .await;
+        assert_eq!(res.unwrap().short_name.as_deref(), Some("bytes sent"));
+    });
+
+    let request = &server.requests()[0];
+    assert_eq!(
+        request.request_line,
+        "PUT /api/v1/metrics/system.net.bytes_sent HTTP/1.1"
+    );
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "description": "The number of bytes sent per second",
+            "per_unit": "second",
+            "short_name": "bytes sent",
+            "unit": "byte"
+        })
+    );
+}
+
+#[test]
+fn metadata_routes_require_metric_name_and_v1() {
+    let res: ddog::Result<types::response::Response<MetricMetadata>> =
+        tokio_test::block_on(get_metadata::GetMetadata::default().execute());
+    assert!(matches!(res, Err(Error::Validation(_))));
+    let res = tokio_test::block_on(update_metadata::UpdateMetadata::default().execute());
+    assert!(matches!(res, Err(Error::Validation(_))));
+    assert!(matches!(
+        update_metadata::UpdateMetadata::try_from(ApiVersion::V2),
+        Err(Error::UnsupportedVersion(ApiVersion::V2))
+    ));
+}
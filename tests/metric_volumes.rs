@@ -0,0 +1,143 @@
+use ddog::prelude::*;
+
+mod common;
+use common::{MockResponse, MockServer};
+
+#[test]
+fn get_metric_volumes() {
+    let server = MockServer::start(vec![
+        MockResponse::new(
+            200,
+            r#"{ "data": { "type": "metric_volumes", "id": "rpc.latency", "attributes": { "indexed_volume": 10, "ingested_volume": 20 } } }"#,
+        ),
+        MockResponse::new(
+            200,
+            r#"{ "data": { "type": "distinct_metric_volumes", "id": "rpc.duration", "attributes": { "distinct_volume": 5 } } }"#,
+        ),
+    ]);
+    let client = Client::new().with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let res = client
+            .get_metric_volumes("rpc.latency")
+            .unwrap()
+            .execute()
+            .await;
+        let data = res.unwrap().into_inner().data.unwrap();
+        assert_eq!(data.type_, volumes::MetricVolumesType::MetricVolumes);
+        assert_eq!(data.attributes.indexed_volume, Some(10));
+        assert_eq!(data.attributes.ingested_volume, Some(20));
+        assert_eq!(data.attributes.distinct_volume, None);
+
+        let res = client
+            .get_metric_volumes("rpc.duration")
+            .unwrap()
+            .execute()
+            .await;
+        let data = res.unwrap().into_inner().data.unwrap();
+        assert_eq!(
+            data.type_,
+            volumes::MetricVolumesType::DistinctMetricVolumes
+        );
+        assert_eq!(data.attributes.distinct_volume, Some(5));
+    });
+
+    assert_eq!(
+        server.requests()[0].request_line,
+        "GET /api/v2/metrics/rpc.latency/volumes HTTP/1.1"
+    );
+}
+
+#[test]
+fn get_metric_all_tags_and_active_configurations() {
+    let server = MockServer::start(vec![
+        MockResponse::new(
+            200,
+            r#"{ "data": { "type": "metrics", "id": "rpc.latency", "attributes": { "tags": [ "env:prod" ], "ingested_tags": [ "env:prod", "host:web-1" ] } } }"#,
+        ),
+        MockResponse::new(
+            200,
+            r#"{ "data": { "type": "actively_queried_configurations", "id": "rpc.latency", "attributes": { "active_aggregations": [ { "space": "sum", "time": "sum" } ], "active_tags": [ "env" ] } } }"#,
+        ),
+    ]);
+    let mut builder = Builder::new();
+    builder.v2().with_site(Site::Custom(server.url.clone()));
+
+    tokio_test::block_on(async {
+        let res = builder
+            .get_metric_all_tags("rpc.latency")
+            .unwrap()
+            .with_window_seconds(7200)
+            .execute()
+            .await;
+        let attributes = res.unwrap().into_inner().data.unwrap().attributes;
+        assert_eq!(attributes.tags, vec!["env:prod"]);
+        assert_eq!(attributes.ingested_tags, vec!["env:prod", "host:web-1"]);
+
+        let res = builder
+            .get_active_configurations("rpc.latency")
+            .unwrap()
+            .execute()
+            .await;
+        let attributes = res.unwrap().into_inner().data.unwrap().attributes;
+        assert_eq!(
+            attributes.active_aggregations,
+            vec![tags::Aggregation {
+                space: tags::SpaceEnum::Sum,
+                time: tags::TimeEnum::Sum,
+            }]
+        );
+        assert_eq!(attributes.active_tags, vec!["env"]);
+    });
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].request_line,
+        "GET /api/v2/metrics/rpc.latency/all-tags?window%5Bseconds%5D=7200 HTTP/1.1"
+    );
+    assert_eq!(
+        requests[1].request_line,
+        "GET /api/v2/metrics/rpc.latency/active-configurations HTTP/1.1"
+    );
+}
+
+#[test]
+fn estimate_tag_config() {
+    let server = MockServer::start(vec![MockResponse::new(
+        200,
+        r#"{ "data": { "type": "metric_cardinality_estimate", "id": "rpc.latency", "attributes": { "estimate_type": "count_or_gauge", "estimated_at": "2022-04-27T09:48:37.463835Z", "estimated_output_series": 50 } } }"#,
+    )]);
+    let client = Client::new().with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let res = client
+            .estimate_tag_config("rpc.latency")
+            .unwrap()
+            .with_groups(vec!["app", "datacenter"])
+            .with_num_aggregations(2)
+            .with_hours_ago(49)
+            .execute()
+            .await;
+        let attributes = res.unwrap().into_inner().data.unwrap().attributes;
+        assert_eq!(
+            attributes.estimate_type,
+            Some(estimate::EstimateType::CountOrGauge)
+        );
+        assert_eq!(attributes.estimated_output_series, Some(50));
+        assert!(attributes.estimated_at.is_some());
+    });
+
+    assert_eq!(
+        server.requests()[0].request_line,
+        "GET /api/v2/metrics/rpc.latency/estimate?filter%5Bgroups%5D=app%2Cdatacenter&filter%5Bhours_ago%5D=49&filter%5Bnum_aggregations%5D=2 HTTP/1.1"
+    );
+}
+
+#[test]
+fn volume_routes_require_metric_name() {
+    let res: ddog::Result<types::response::Response<volumes::MetricVolumesResponse>> =
+        tokio_test::block_on(volumes::Volumes::default().execute());
+    assert!(matches!(res, Err(Error::Validation(_))));
+    let res = tokio_test::block_on(estimate::Estimate::default().execute());
+    assert!(matches!(res, Err(Error::Validation(_))));
+}
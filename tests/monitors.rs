@@ -0,0 +1,418 @@
+use chrono::{TimeZone, Utc};
+use create_monitor::*;
+use ddog::prelude::*;
+
+mod common;
+use common::{MockResponse, MockServer};
+
+const MONITOR: &str = r#"{
+    "id": 1234567,
+    "type": "metric alert",
+    "name": "Disk usage is high",
+    "query": "avg(last_5m):avg:system.disk.in_use{service:web} by {host} > 0.9",
+    "message": "Disk usage is high on {{host.name}} @slack-ops",
+    "tags": [ "service:web" ],
+    "priority": 2,
+    "options": {
+        "thresholds": { "critical": 0.9, "warning": 0.8 },
+        "notify_no_data": true,
+        "renotify_interval": 60,
+        "silenced": { "*": null }
+    },
+    "created": "2023-11-14T22:13:20.000000+00:00",
+    "modified": "2023-11-14T22:13:20.000000+00:00",
+    "creator": { "email": "sue@example.com", "handle": "sue@example.com", "name": "Sue" },
+    "multi": true,
+    "overall_state": "No Data"
+}"#;
+
+fn disk_monitor() -> Monitor {
+    Monitor::new(
+        MonitorType::MetricAlert,
+        "Disk usage is high",
+        "avg(last_5m):avg:system.disk.in_use{service:web} by {host} > 0.9",
+    )
+    .with_message("Disk usage is high on {{host.name}} @slack-ops")
+    .with_tag("service:web")
+    .with_priority(2)
+    .with_options(
+        MonitorOptions::new()
+            .with_thresholds(MonitorThresholds::critical(0.9).with_warning(0.8))
+            .with_notify_no_data(true)
+            .with_renotify_interval(60),
+    )
+}
+
+#[test]
+fn create_and_update_monitor() {
+    let server = MockServer::start(vec![MockResponse::new(200, MONITOR)]);
+    let mut builder = Builder::new();
+    builder.v1().with_site(Site::Custom(server.url.clone()));
+
+    tokio_test::block_on(async {
+        let res = builder
+            .create_monitor()
+            .unwrap()
+            .monitor(disk_monitor())
+            .execute()
+            .await;
+        let monitor = res.unwrap().into_inner();
+        assert_eq!(monitor.id, Some(1234567));
+        assert_eq!(monitor.type_, MonitorType::MetricAlert);
+        assert_eq!(monitor.overall_state, Some(MonitorOverallState::NoData));
+        assert_eq!(
+            monitor.created,
+            Some(Utc.timestamp_opt(1700000000, 0).unwrap())
+        );
+        assert_eq!(
+            monitor.options.as_ref().unwrap().silenced.get("*"),
+            Some(&None)
+        );
+
+        let res = builder
+            .update_monitor(1234567)
+            .unwrap()
+            .monitor(disk_monitor().with_priority(1))
+            .execute()
+            .await;
+        assert!(res.is_ok());
+    });
+
+    let requests = server.requests();
+    assert_eq!(requests[0].request_line, "POST /api/v1/monitor HTTP/1.1");
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "type": "metric alert",
+            "name": "Disk usage is high",
+            "query": "avg(last_5m):avg:system.disk.in_use{service:web} by {host} > 0.9",
+            "message": "Disk usage is high on {{host.name}} @slack-ops",
+            "tags": [ "service:web" ],
+            "priority": 2,
+            "options": {
+                "thresholds": { "critical": 0.9, "warning": 0.8 },
+                "notify_no_data": true,
+                "renotify_interval": 60
+            }
+        })
+    );
+    assert_eq!(
+        requests[1].request_line,
+        "PUT /api/v1/monitor/1234567 HTTP/1.1"
+    );
+    let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!(body["priority"], 1);
+}
+
+#[test]
+fn get_list_and_delete_monitors() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, MONITOR),
+        MockResponse::new(200, &format!("[{}]", MONITOR)),
+        MockResponse::new(200, r#"{ "deleted_monitor_id": 1234567 }"#),
+    ]);
+    let mut client = Client::new().with_base_url(&server.url);
+    client.v1();
+
+    tokio_test::block_on(async {
+        let res = client
+            .get_monitor(1234567)
+            .unwrap()
+            .with_group_states(vec!["alert", "warn"])
+            .execute()
+            .await;
+        assert_eq!(res.unwrap().name, "Disk usage is high");
+
+        let res = client
+            .list_monitors()
+            .unwrap()
+            .with_name("Disk")
+            .with_monitor_tags(vec!["service:web", "env:prod"])
+            .with_page(0, 100)
+            .execute()
+            .await;
+        let monitors = res.unwrap().into_inner();
+        assert_eq!(monitors.len(), 1);
+        assert_eq!(monitors[0].tags, vec!["service:web"]);
+
+        let res = client
+            .delete_monitor(1234567)
+            .unwrap()
+            .with_force(true)
+            .execute()
+            .await;
+        assert_eq!(res.unwrap().deleted_monitor_id, Some(1234567));
+    });
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].request_line,
+        "GET /api/v1/monitor/1234567?group_states=alert%2Cwarn HTTP/1.1"
+    );
+    assert_eq!(
+        requests[1].request_line,
+        "GET /api/v1/monitor?name=Disk&monitor_tags=service%3Aweb%2Cenv%3Aprod&page=0&page_size=100 HTTP/1.1"
+    );
+    assert_eq!(
+        requests[2].request_line,
+        "DELETE /api/v1/monitor/1234567?force=true HTTP/1.1"
+    );
+}
+
+#[test]
+fn update_round_trips_unmodeled_fields() {
+    let server = MockServer::start(vec![MockResponse::new(
+        200,
+        r#"{
+            "id": 1234567,
+            "type": "query alert",
+            "name": "Errors are high",
+            "query": "sum(last_5m):sum:trace.http.request.errors{*} by {service} > 10",
+            "restricted_roles": [ "00000000-0000-1111-0000-000000000000" ],
+            "org_id": 2,
+            "deleted": null,
+            "overall_state_modified": "2024-05-01T10:00:00+00:00",
+            "matching_downtimes": [],
+            "state": { "groups": {} },
+            "options": {
+                "thresholds": { "critical": 10 },
+                "notify_by": [ "service" ],
+                "on_missing_data": "show_and_notify_no_data",
+                "renotify_statuses": [ "alert" ],
+                "group_retention_duration": "2d",
+                "scheduling_options": { "evaluation_window": { "day_starts": "04:00" } }
+            },
+            "overall_state": "OK"
+        }"#,
+    )]);
+    let mut client = Client::new().with_base_url(&server.url);
+    client.v1();
+
+    tokio_test::block_on(async {
+        let monitor = client
+            .get_monitor(1234567)
+            .unwrap()
+            .execute()
+            .await
+            .unwrap()
+            .into_inner();
+        let options = monitor.options.as_ref().unwrap();
+        assert_eq!(options.extra["on_missing_data"], "show_and_notify_no_data");
+        // Read-only fields are kept when read but not sent back
+        assert_eq!(monitor.extra["org_id"], 2);
+
+        client
+            .update_monitor(1234567)
+            .unwrap()
+            .monitor(monitor.with_priority(1))
+            .execute()
+            .await
+            .unwrap();
+    });
+
+    let body: serde_json::Value = serde_json::from_slice(&server.requests()[1].body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "id": 1234567,
+            "type": "query alert",
+            "name": "Errors are high",
+            "query": "sum(last_5m):sum:trace.http.request.errors{*} by {service} > 10",
+            "priority": 1,
+            "restricted_roles": [ "00000000-0000-1111-0000-000000000000" ],
+            "options": {
+                "thresholds": { "critical": 10.0 },
+                "notify_by": [ "service" ],
+                "on_missing_data": "show_and_notify_no_data",
+                "renotify_statuses": [ "alert" ],
+                "group_retention_duration": "2d",
+                "scheduling_options": { "evaluation_window": { "day_starts": "04:00" } }
+            }
+        })
+    );
+}
+
+#[test]
+fn list_monitors_with_unknown_type() {
+    let server = MockServer::start(vec![MockResponse::new(
+        200,
+        r#"[
+            { "id": 1, "type": "metric alert", "name": "Disk", "query": "avg:system.disk.in_use{*} > 0.9" },
+            { "id": 2, "type": "cost alert", "name": "Cloud cost", "query": "formula(\"query1\").last(\"7d\") > 100" }
+        ]"#,
+    )]);
+    let mut client = Client::new().with_base_url(&server.url);
+    client.v1();
+
+    tokio_test::block_on(async {
+        let monitors = client
+            .list_monitors()
+            .unwrap()
+            .execute()
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(monitors[0].type_, MonitorType::MetricAlert);
+        assert_eq!(
+            monitors[1].type_,
+            MonitorType::Other(String::from("cost alert"))
+        );
+        assert_eq!(
+            serde_json::to_value(&monitors[1]).unwrap()["type"],
+            "cost alert"
+        );
+    });
+}
+
+#[test]
+fn validate_monitor() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, "{}"),
+        MockResponse::new(
+            400,
+            r#"{ "errors": [ "The value provided for parameter 'query' is invalid" ] }"#,
+        ),
+    ]);
+    let mut builder = Builder::new();
+    builder.v1().with_site(Site::Custom(server.url.clone()));
+
+    tokio_test::block_on(async {
+        let res = builder
+            .validate_monitor()
+            .unwrap()
+            .monitor(disk_monitor())
+            .execute()
+            .await;
+        assert_eq!(
+            res.unwrap().into_inner(),
+            validate_monitor::ValidateMonitorResponse {}
+        );
+
+        let res = builder
+            .validate_monitor()
+            .unwrap()
+            .monitor(Monitor::new(MonitorType::QueryAlert, "Invalid", "avg:"))
+            .execute()
+            .await;
+        assert_eq!(
+            res.unwrap_err().status(),
+            Some(reqwest::StatusCode::BAD_REQUEST)
+        );
+    });
+
+    assert_eq!(
+        server.requests()[0].request_line,
+        "POST /api/v1/monitor/validate HTTP/1.1"
+    );
+}
+
+#[test]
+fn search_monitors() {
+    let server = MockServer::start(vec![MockResponse::new(
+        200,
+        r#"{
+            "counts": {
+                "muted": [ { "count": 1, "name": false } ],
+                "status": [ { "count": 1, "name": "Alert" } ],
+                "tag": [ { "count": 1, "name": "service:web" } ],
+                "type": [ { "count": 1, "name": "metric" } ]
+            },
+            "metadata": { "page": 0, "page_count": 1, "per_page": 30, "total_count": 1 },
+            "monitors": [
+                {
+                    "id": 1234567,
+                    "name": "Disk usage is high",
+                    "query": "avg(last_5m):avg:system.disk.in_use{service:web} by {host} > 0.9",
+                    "type": "metric alert",
+                    "status": "Alert",
+                    "tags": [ "service:web" ],
+                    "classification": "metric",
+                    "last_triggered_ts": 1700000000,
+                    "metrics": [ "system.disk.in_use" ]
+                }
+            ]
+        }"#,
+    )]);
+    let mut client = Client::new().with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let res = client
+            .v1()
+            .search_monitors()
+            .unwrap()
+            .with_query("type:metric status:alert")
+            .with_sort("name,asc")
+            .execute()
+            .await;
+        let response = res.unwrap().into_inner();
+        assert_eq!(response.metadata.unwrap().total_count, 1);
+        assert_eq!(
+            response.counts.unwrap().muted[0].name,
+            serde_json::json!(false)
+        );
+        let monitor = &response.monitors[0];
+        assert_eq!(monitor.status, Some(MonitorOverallState::Alert));
+        assert_eq!(monitor.metrics, vec!["system.disk.in_use"]);
+    });
+
+    assert_eq!(
+        server.requests()[0].request_line,
+        "GET /api/v1/monitor/search?query=type%3Ametric+status%3Aalert&sort=name%2Casc HTTP/1.1"
+    );
+}
+
+#[test]
+fn mute_and_unmute_monitor() {
+    let server = MockServer::start(vec![MockResponse::new(200, MONITOR)]);
+    let mut client = Client::new().with_base_url(&server.url);
+    client.v1();
+
+    tokio_test::block_on(async {
+        let res = client
+            .mute_monitor(1234567)
+            .unwrap()
+            .with_scope("role:db")
+            .with_end(Utc.timestamp_opt(1700003600, 0).unwrap())
+            .execute()
+            .await;
+        assert!(res.is_ok());
+
+        let res = client
+            .unmute_monitor(1234567)
+            .unwrap()
+            .with_all_scopes(true)
+            .execute()
+            .await;
+        assert!(res.is_ok());
+    });
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].request_line,
+        "POST /api/v1/monitor/1234567/mute HTTP/1.1"
+    );
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({ "scope": "role:db", "end": 1700003600 })
+    );
+    assert_eq!(
+        requests[1].request_line,
+        "POST /api/v1/monitor/1234567/unmute HTTP/1.1"
+    );
+    let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!(body, serde_json::json!({ "all_scopes": true }));
+}
+
+#[test]
+fn monitor_routes_require_monitor_id_and_v1() {
+    let res = tokio_test::block_on(get_monitor::GetMonitor::default().execute());
+    assert!(matches!(res, Err(Error::Validation(_))));
+    let res = tokio_test::block_on(delete_monitor::DeleteMonitor::default().execute());
+    assert!(matches!(res, Err(Error::Validation(_))));
+    assert!(matches!(
+        CreateMonitor::try_from(ApiVersion::V2),
+        Err(Error::UnsupportedVersion(ApiVersion::V2))
+    ));
+}
@@ -0,0 +1,200 @@
+use chrono::{TimeZone, Utc};
+use ddog::prelude::*;
+use query_timeseries::*;
+
+mod common;
+use common::{MockResponse, MockServer};
+
+#[test]
+fn query_timeseries_with_formula() {
+    let server = MockServer::start(vec![MockResponse::new(
+        200,
+        r#"{
+            "data": {
+                "type": "timeseries_response",
+                "attributes": {
+                    "series": [ { "group_tags": [ "env:prod" ], "query_index": 0, "unit": [ null, null ] } ],
+                    "times": [ 1700000000000, 1700000060000 ],
+                    "values": [ [ 99.5, null ] ]
+                }
+            }
+        }"#,
+    )]);
+    let mut builder = Builder::new();
+    builder.v2().with_site(Site::Custom(server.url.clone()));
+
+    tokio_test::block_on(async {
+        let res = builder
+            .query_timeseries()
+            .unwrap()
+            .set_time_range(
+                Utc.timestamp_opt(1700000000, 0).unwrap(),
+                Utc.timestamp_opt(1700003600, 0).unwrap(),
+            )
+            .with_interval(60000)
+            .with_query(MetricsQuery::new(
+                "a",
+                "sum:requests.ok{service:web} by {env}.as_count()",
+            ))
+            .with_query(MetricsQuery::new(
+                "b",
+                "sum:requests.total{service:web} by {env}.as_count()",
+            ))
+            .with_formula(QueryFormula::new("a / b * 100").with_limit(10, QuerySortOrder::Desc))
+            .with_api_key("<api_key>")
+            .with_application_key("<application_key>")
+            .execute()
+            .await;
+        let response = res.unwrap().into_inner();
+        let attributes = response.data.unwrap().attributes;
+        assert_eq!(attributes.series[0].group_tags, vec!["env:prod"]);
+        assert_eq!(
+            attributes.points(0),
+            vec![(1700000000000, Some(99.5)), (1700000060000, None)]
+        );
+        assert!(attributes.points(1).is_empty());
+    });
+
+    let request = &server.requests()[0];
+    assert_eq!(
+        request.request_line,
+        "POST /api/v2/query/timeseries HTTP/1.1"
+    );
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "data": {
+                "type": "timeseries_request",
+                "attributes": {
+                    "from": 1700000000000_i64,
+                    "to": 1700003600000_i64,
+                    "interval": 60000,
+                    "queries": [
+                        { "data_source": "metrics", "name": "a", "query": "sum:requests.ok{service:web} by {env}.as_count()" },
+                        { "data_source": "metrics", "name": "b", "query": "sum:requests.total{service:web} by {env}.as_count()" }
+                    ],
+                    "formulas": [ { "formula": "a / b * 100", "limit": { "count": 10, "order": "desc" } } ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn query_scalar_with_events_query() {
+    let server = MockServer::start(vec![MockResponse::new(
+        200,
+        r#"{
+            "data": {
+                "type": "scalar_response",
+                "attributes": {
+                    "columns": [
+                        { "name": "env", "type": "group", "values": [ [ "prod" ], [ "staging" ] ] },
+                        { "name": "a / b * 100", "type": "number", "values": [ 99.5, null ], "meta": { "unit": [ null, null ] } }
+                    ]
+                }
+            }
+        }"#,
+    )]);
+    let client = Client::new().with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let res = client
+            .query_scalar()
+            .unwrap()
+            .set_time_range(
+                Utc.timestamp_opt(1700000000, 0).unwrap(),
+                Utc.timestamp_opt(1700003600, 0).unwrap(),
+            )
+            .with_query(
+                MetricsQuery::new("a", "sum:requests.ok{service:web} by {env}.as_count()")
+                    .with_aggregator(MetricsAggregator::Sum),
+            )
+            .with_query(
+                EventsQuery::new("b", EventsDataSource::Logs, EventsAggregation::Count)
+                    .with_search("service:web")
+                    .with_group_by("env", 10),
+            )
+            .with_formula(QueryFormula::new("a / b * 100"))
+            .execute()
+            .await;
+        let columns = res.unwrap().into_inner().data.unwrap().attributes.columns;
+        assert_eq!(columns.len(), 2);
+        assert_eq!(
+            columns[0],
+            query_scalar::ScalarColumn::Group {
+                name: String::from("env"),
+                values: vec![vec![String::from("prod")], vec![String::from("staging")]],
+            }
+        );
+        assert_eq!(columns[1].name(), "a / b * 100");
+        match &columns[1] {
+            query_scalar::ScalarColumn::Number { values, .. } => {
+                assert_eq!(values, &vec![Some(99.5), None])
+            }
+            column => panic!("Unexpected column {:?}", column),
+        }
+    });
+
+    let request = &server.requests()[0];
+    assert_eq!(request.request_line, "POST /api/v2/query/scalar HTTP/1.1");
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(body["data"]["type"], "scalar_request");
+    assert_eq!(
+        body["data"]["attributes"]["queries"],
+        serde_json::json!([
+            { "data_source": "metrics", "name": "a", "query": "sum:requests.ok{service:web} by {env}.as_count()", "aggregator": "sum" },
+            {
+                "data_source": "logs",
+                "name": "b",
+                "compute": { "aggregation": "count" },
+                "search": { "query": "service:web" },
+                "group_by": [ { "facet": "env", "limit": 10 } ]
+            }
+        ])
+    );
+}
+
+#[test]
+fn formula_queries_round_trip() {
+    let request: TimeseriesQueryRequest = serde_json::from_value(serde_json::json!({
+        "from": 0,
+        "to": 1,
+        "queries": [
+            { "data_source": "cloud_cost", "name": "a", "query": "sum:aws.cost.amortized{*}" },
+            { "data_source": "rum", "name": "b", "compute": { "aggregation": "pc95", "metric": "@view.loading_time" } }
+        ]
+    }))
+    .unwrap();
+    assert!(matches!(
+        &request.queries[0],
+        FormulaQuery::Metrics(MetricsQuery {
+            data_source: MetricsDataSource::CloudCost,
+            ..
+        })
+    ));
+    assert!(matches!(
+        &request.queries[1],
+        FormulaQuery::Events(EventsQuery {
+            data_source: EventsDataSource::Rum,
+            compute: EventsCompute {
+                aggregation: EventsAggregation::Pc95,
+                ..
+            },
+            ..
+        })
+    ));
+}
+
+#[test]
+fn formula_queries_require_v2() {
+    assert!(matches!(
+        QueryTimeseries::try_from(ApiVersion::V1),
+        Err(Error::UnsupportedVersion(ApiVersion::V1))
+    ));
+    assert!(matches!(
+        query_scalar::QueryScalar::try_from(ApiVersion::V1),
+        Err(Error::UnsupportedVersion(ApiVersion::V1))
+    ));
+}
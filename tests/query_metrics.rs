@@ -0,0 +1,101 @@
+use ddog::prelude::*;
+
+mod common;
+use common::{MockResponse, MockServer};
+
+const QUERY_RESPONSE: &str = r#"{
+    "from_date": 1575317847000,
+    "group_by": [ "host" ],
+    "query": "avg:system.cpu.idle{*}by{host}",
+    "res_type": "time_series",
+    "series": [
+        {
+            "aggr": "avg",
+            "display_name": "system.cpu.idle",
+            "end": 1575317867000,
+            "expression": "avg:system.cpu.idle{host:web-1}",
+            "interval": 20,
+            "length": 2,
+            "metric": "system.cpu.idle",
+            "pointlist": [ [ 1575317847000.0, 92.5 ], [ 1575317867000.0, null ] ],
+            "query_index": 0,
+            "scope": "host:web-1",
+            "start": 1575317847000,
+            "tag_set": [ "host:web-1" ],
+            "unit": [ { "family": "percentage", "name": "percent", "plural": "percent", "scale_factor": 1.0, "short_name": "%" }, null ]
+        }
+    ],
+    "status": "ok",
+    "to_date": 1575321447000
+}"#;
+
+#[test]
+fn query_metrics_returns_typed_series() {
+    let server = MockServer::start(vec![MockResponse::new(200, QUERY_RESPONSE)]);
+    let mut client = Client::new()
+        .with_api_key("<api_key>")
+        .with_application_key("<application_key>")
+        .with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let res = client
+            .v1()
+            .query_metrics(1575317847, 1575321447, "avg:system.cpu.idle{*}by{host}")
+            .unwrap()
+            .execute()
+            .await;
+        let response = res.unwrap().into_inner();
+        assert_eq!(response.status.as_deref(), Some("ok"));
+        assert_eq!(response.series.len(), 1);
+        let series = &response.series[0];
+        assert_eq!(series.scope.as_deref(), Some("host:web-1"));
+        assert_eq!(
+            series.expression.as_deref(),
+            Some("avg:system.cpu.idle{host:web-1}")
+        );
+        assert_eq!(series.interval, Some(20));
+        assert_eq!(
+            series.pointlist,
+            vec![(1575317847000.0, Some(92.5)), (1575317867000.0, None)]
+        );
+        let unit = series.unit[0].as_ref().unwrap();
+        assert_eq!(unit.short_name.as_deref(), Some("%"));
+        assert_eq!(series.unit[1], None);
+    });
+
+    let request = &server.requests()[0];
+    assert_eq!(
+        request.request_line,
+        "GET /api/v1/query?from=1575317847&to=1575321447&query=avg%3Asystem.cpu.idle%7B*%7Dby%7Bhost%7D HTTP/1.1"
+    );
+    assert_eq!(
+        request.header("dd-application-key"),
+        Some("<application_key>")
+    );
+}
+
+#[test]
+fn query_metrics_from_builder() {
+    let server = MockServer::start(vec![MockResponse::new(200, QUERY_RESPONSE)]);
+    let mut builder = Builder::new();
+    builder.v1().with_site(Site::Custom(server.url.clone()));
+
+    let res = tokio_test::block_on(
+        builder
+            .query_metrics(1575317847, 1575321447, "avg:system.cpu.idle{*}")
+            .unwrap()
+            .execute(),
+    );
+    assert_eq!(res.unwrap().data.series[0].length, Some(2));
+}
+
+#[test]
+fn query_metrics_requires_query() {
+    let route = query_metrics::QueryMetrics::default().set_time_range(0, 60);
+    let res = tokio_test::block_on(route.execute());
+    assert!(matches!(res, Err(Error::Validation(_))));
+    assert!(matches!(
+        query_metrics::QueryMetrics::try_from(ApiVersion::V2),
+        Err(Error::UnsupportedVersion(ApiVersion::V2))
+    ));
+}
@@ -0,0 +1,148 @@
+use std::time::Duration;
+
+use ddog::prelude::*;
+
+mod common;
+use common::{MockResponse, MockServer};
+
+fn rate_limited(status: u16, body: &str, remaining: &str, reset: &str) -> MockResponse {
+    MockResponse::new(status, body)
+        .with_header("X-RateLimit-Limit", "100")
+        .with_header("X-RateLimit-Period", "10")
+        .with_header("X-RateLimit-Remaining", remaining)
+        .with_header("X-RateLimit-Reset", reset)
+        .with_header("X-RateLimit-Name", "metrics_query")
+}
+
+#[test]
+fn responses_expose_rate_limit() {
+    let server = MockServer::start(vec![rate_limited(
+        200,
+        r#"{ "from": "0", "metrics": [ "rpc_latency" ] }"#,
+        "99",
+        "3",
+    )]);
+    let mut client = Client::new().with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let response = client
+            .v1()
+            .get_metrics(chrono::DateTime::UNIX_EPOCH)
+            .unwrap()
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(response.metrics, vec![String::from("rpc_latency")]);
+        assert_eq!(
+            response.rate_limit,
+            Some(RateLimit {
+                limit: Some(100),
+                period: Some(10),
+                remaining: Some(99),
+                reset: Some(3),
+                name: Some(String::from("metrics_query")),
+            })
+        );
+    });
+}
+
+#[test]
+fn rate_limited_errors_expose_rate_limit() {
+    let server = MockServer::start(vec![
+        rate_limited(429, r#"{ "errors": [ "Too many requests" ] }"#, "0", "0"),
+        MockResponse::new(200, r#"{ "from": "0", "metrics": [] }"#),
+    ]);
+    let mut client = Client::new().with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let error = client
+            .v1()
+            .get_metrics(chrono::DateTime::UNIX_EPOCH)
+            .unwrap()
+            .execute()
+            .await
+            .unwrap_err();
+        assert_eq!(error.status(), Some(reqwest::StatusCode::TOO_MANY_REQUESTS));
+        let rate_limit = error.rate_limit().unwrap();
+        assert!(rate_limit.is_exhausted());
+        assert_eq!(rate_limit.reset_after(), Some(Duration::from_secs(0)));
+    });
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn waits_for_rate_limit_reset() {
+    let server = MockServer::start(vec![
+        rate_limited(429, r#"{ "errors": [ "Too many requests" ] }"#, "0", "0"),
+        MockResponse::new(202, r#"{ "status": "ok" }"#),
+    ]);
+    // No retry policy is set, only rate limited requests are retried
+    let mut client = Client::new()
+        .with_base_url(&server.url)
+        .with_rate_limit_wait(Duration::from_secs(1));
+
+    tokio_test::block_on(async {
+        let response = client
+            .v1()
+            .post_distribution()
+            .unwrap()
+            .body(r#"{ "series": [] }"#)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(response.status, 202);
+        assert_eq!(response.rate_limit, None);
+    });
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn does_not_wait_past_max_wait() {
+    let server = MockServer::start(vec![
+        rate_limited(429, r#"{ "errors": [ "Too many requests" ] }"#, "0", "60"),
+        MockResponse::new(200, r#"{ "from": "0", "metrics": [] }"#),
+    ]);
+    let mut client = Client::new()
+        .with_base_url(&server.url)
+        .with_rate_limit_wait(Duration::from_secs(1));
+
+    tokio_test::block_on(async {
+        let error = client
+            .v1()
+            .get_metrics(chrono::DateTime::UNIX_EPOCH)
+            .unwrap()
+            .execute()
+            .await
+            .unwrap_err();
+        assert_eq!(error.rate_limit().unwrap().reset, Some(60));
+    });
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn falls_back_to_retry_policy_past_max_wait() {
+    let server = MockServer::start(vec![
+        rate_limited(429, r#"{ "errors": [ "Too many requests" ] }"#, "0", "60"),
+        MockResponse::new(200, r#"{ "from": "0", "metrics": [ "rpc_latency" ] }"#),
+    ]);
+    let mut client = Client::new()
+        .with_base_url(&server.url)
+        .with_rate_limit_wait(Duration::from_secs(1))
+        .with_retry(
+            RetryPolicy::default()
+                .with_base_delay(Duration::from_millis(1))
+                .with_max_delay(Duration::from_millis(5)),
+        );
+
+    tokio_test::block_on(async {
+        let response = client
+            .v1()
+            .get_metrics(chrono::DateTime::UNIX_EPOCH)
+            .unwrap()
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(response.metrics, vec![String::from("rpc_latency")]);
+    });
+    assert_eq!(server.requests().len(), 2);
+}
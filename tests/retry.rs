@@ -0,0 +1,131 @@
+use std::time::Duration;
+
+use ddog::prelude::*;
+
+mod common;
+use common::{MockResponse, MockServer};
+
+fn fast_retry() -> RetryPolicy {
+    RetryPolicy::default()
+        .with_base_delay(Duration::from_millis(1))
+        .with_max_delay(Duration::from_millis(5))
+}
+
+#[test]
+fn retries_series_until_accepted() {
+    let server = MockServer::start(vec![
+        MockResponse::new(503, r#"{ "errors": [ "Service Unavailable" ] }"#),
+        MockResponse::new(202, r#"{ "errors": [] }"#),
+    ]);
+    let client = Client::new()
+        .with_base_url(&server.url)
+        .with_retry(fast_retry());
+
+    tokio_test::block_on(async {
+        let res = client
+            .post_series()
+            .unwrap()
+            .series(vec![series::MetricSeries::new(
+                "rpc_latency",
+                series::MetricIntakeType::Gauge,
+            )
+            .with_point(1660157680, 1.0)])
+            .execute()
+            .await;
+        assert_eq!(res.unwrap().status, 202);
+    });
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].body, requests[1].body);
+}
+
+#[test]
+fn gives_up_after_max_attempts() {
+    let server = MockServer::start(vec![MockResponse::new(
+        503,
+        r#"{ "errors": [ "Service Unavailable" ] }"#,
+    )]);
+    let mut client = Client::new()
+        .with_base_url(&server.url)
+        .with_retry(fast_retry().with_max_attempts(4));
+
+    tokio_test::block_on(async {
+        let res = client
+            .v1()
+            .get_metrics(chrono::DateTime::UNIX_EPOCH)
+            .unwrap()
+            .execute()
+            .await;
+        assert_eq!(
+            res.unwrap_err().status(),
+            Some(reqwest::StatusCode::SERVICE_UNAVAILABLE)
+        );
+    });
+    assert_eq!(server.requests().len(), 4);
+}
+
+#[test]
+fn does_not_retry_non_retryable_status() {
+    let server = MockServer::start(vec![
+        MockResponse::new(403, r#"{ "errors": [ "Forbidden" ] }"#),
+        MockResponse::new(202, r#"{ "status": "ok" }"#),
+    ]);
+    let mut client = Client::new()
+        .with_base_url(&server.url)
+        .with_retry(fast_retry());
+
+    tokio_test::block_on(async {
+        let res = client
+            .v1()
+            .post_distribution()
+            .unwrap()
+            .body(r#"{ "series": [] }"#)
+            .execute()
+            .await;
+        assert_eq!(
+            res.unwrap_err().status(),
+            Some(reqwest::StatusCode::FORBIDDEN)
+        );
+    });
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn does_not_retry_non_idempotent_posts() {
+    let server = MockServer::start(vec![
+        MockResponse::new(503, r#"{ "errors": [ "Service Unavailable" ] }"#),
+        MockResponse::new(201, "{}"),
+    ]);
+    let mut client = Client::new()
+        .with_base_url(&server.url)
+        .with_retry(fast_retry());
+
+    tokio_test::block_on(async {
+        let res = client
+            .v2()
+            .create_new_tag_config("rpc_latency")
+            .unwrap()
+            .body("{}")
+            .execute()
+            .await;
+        assert_eq!(
+            res.unwrap_err().status(),
+            Some(reqwest::StatusCode::SERVICE_UNAVAILABLE)
+        );
+    });
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn backoff_is_capped_and_jittered() {
+    let policy = RetryPolicy::default()
+        .with_base_delay(Duration::from_millis(100))
+        .with_max_delay(Duration::from_secs(1));
+    for retry in 1..10 {
+        let delay = policy.backoff(retry);
+        let expected = policy.clone().with_jitter(false).backoff(retry);
+        assert!(delay >= expected / 2 && delay <= expected);
+        assert!(expected <= Duration::from_secs(1));
+    }
+}
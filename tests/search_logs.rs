@@ -0,0 +1,155 @@
+use ddog::prelude::*;
+use futures_util::StreamExt;
+
+mod common;
+use common::{MockResponse, MockServer};
+
+const FIRST_PAGE: &str = r#"{
+    "data": [
+        {
+            "id": "AAAAAWgN8Xwgr1vKDQAAAABBV2dOOFh3ZzZobm1mWXJFYTR0OA",
+            "type": "log",
+            "attributes": {
+                "attributes": { "trace_id": "4186426424873185451" },
+                "host": "i-0123",
+                "message": "request started",
+                "service": "web",
+                "status": "info",
+                "tags": [ "env:prod" ],
+                "timestamp": "2019-01-02T09:42:36.320Z"
+            }
+        },
+        { "id": "2", "type": "log", "attributes": { "message": "query executed" } }
+    ],
+    "links": { "next": "https://app.datadoghq.com/api/v2/logs/events?page[cursor]=abc" },
+    "meta": { "elapsed": 132, "page": { "after": "abc" }, "request_id": "req", "status": "done" }
+}"#;
+
+const LAST_PAGE: &str = r#"{
+    "data": [ { "id": "3", "type": "log", "attributes": { "message": "request finished" } } ],
+    "meta": { "elapsed": 12, "status": "done" }
+}"#;
+
+#[test]
+fn search_logs_single_page() {
+    let server = MockServer::start(vec![MockResponse::new(200, FIRST_PAGE)]);
+    let client = Client::new()
+        .with_api_key("<api_key>")
+        .with_application_key("<application_key>")
+        .with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let res = client
+            .search_logs()
+            .unwrap()
+            .with_query("trace_id:4186426424873185451")
+            .with_time_range("now-1d", "now")
+            .with_index("main")
+            .with_sort(search_logs::LogsSort::TimestampAscending)
+            .with_limit(2)
+            .execute()
+            .await;
+        let page = res.unwrap().into_inner();
+        assert_eq!(page.data.len(), 2);
+        assert_eq!(page.next_cursor(), Some("abc"));
+        assert_eq!(
+            page.links.unwrap().next.as_deref(),
+            Some("https://app.datadoghq.com/api/v2/logs/events?page[cursor]=abc")
+        );
+        let log = &page.data[0].attributes;
+        assert_eq!(log.message.as_deref(), Some("request started"));
+        assert_eq!(log.attributes["trace_id"], "4186426424873185451");
+        assert_eq!(log.tags, vec!["env:prod"]);
+        assert_eq!(
+            log.timestamp.unwrap().to_rfc3339(),
+            "2019-01-02T09:42:36.320+00:00"
+        );
+    });
+
+    let request = &server.requests()[0];
+    assert_eq!(
+        request.request_line,
+        "POST /api/v2/logs/events/search HTTP/1.1"
+    );
+    assert_eq!(
+        request.header("dd-application-key"),
+        Some("<application_key>")
+    );
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "filter": {
+                "query": "trace_id:4186426424873185451",
+                "from": "now-1d",
+                "to": "now",
+                "indexes": [ "main" ]
+            },
+            "page": { "limit": 2 },
+            "sort": "timestamp"
+        })
+    );
+}
+
+#[test]
+fn stream_follows_cursors_until_exhausted() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, FIRST_PAGE),
+        MockResponse::new(200, LAST_PAGE),
+    ]);
+    let client = Client::new().with_base_url(&server.url);
+
+    let logs = tokio_test::block_on(
+        client
+            .search_logs()
+            .unwrap()
+            .with_query("service:web")
+            .with_limit(2)
+            .stream()
+            .collect::<Vec<_>>(),
+    );
+    let messages = logs
+        .into_iter()
+        .map(|log| log.unwrap().attributes.message.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec!["request started", "query executed", "request finished"]
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    let bodies = requests
+        .iter()
+        .map(|r| serde_json::from_slice::<serde_json::Value>(&r.body).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(bodies[0]["page"], serde_json::json!({ "limit": 2 }));
+    assert_eq!(
+        bodies[1]["page"],
+        serde_json::json!({ "cursor": "abc", "limit": 2 })
+    );
+    assert_eq!(bodies[1]["filter"]["query"], "service:web");
+}
+
+#[test]
+fn stream_ends_after_failed_page() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, FIRST_PAGE),
+        MockResponse::new(400, r#"{ "errors": [ "invalid cursor" ] }"#),
+    ]);
+    let client = Client::new().with_base_url(&server.url);
+
+    let pages = tokio_test::block_on(client.search_logs().unwrap().pages().collect::<Vec<_>>());
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[0].as_ref().unwrap().data.len(), 2);
+    assert!(matches!(pages[1], Err(Error::Status { .. })));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn search_logs_requires_v2() {
+    assert!(matches!(
+        search_logs::SearchLogs::try_from(ApiVersion::V1),
+        Err(Error::UnsupportedVersion(ApiVersion::V1))
+    ));
+}
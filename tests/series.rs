@@ -3,6 +3,9 @@ use ddog::prelude::*;
 extern crate dotenv;
 use dotenv::dotenv;
 
+mod common;
+use common::{MockResponse, MockServer};
+
 #[test]
 fn post_count_series_explicitly() {
     // Read id and key from environment variables
@@ -20,8 +23,9 @@ fn post_count_series_explicitly() {
         builder_ref = builder_ref.with_subscriber();
     }
     tokio_test::block_on(async {
-        let (status, res) = builder_ref
+        let res = builder_ref
             .post_series()
+            .unwrap()
             .headers(vec![
                 ("Accept", "application/json"),
                 ("Content-Type", "application/json"),
@@ -60,8 +64,9 @@ fn post_count_series_explicitly() {
             .await;
 
         tracing::info!(target: "v2/series", "Response: {:?}", res);
-        assert_eq!(status, 202);
-        assert_eq!(res.unwrap(), series::SeriesResponse { errors: vec![] });
+        let response = res.unwrap();
+        assert_eq!(response.status, 202);
+        assert_eq!(response.data, series::SeriesResponse { errors: vec![] });
     });
 }
 
@@ -81,8 +86,9 @@ fn post_series_invalid_json() {
         builder_ref = builder_ref.with_subscriber();
     }
     tokio_test::block_on(async {
-        let (status, res) = builder_ref
+        let res = builder_ref
             .post_series()
+            .unwrap()
             .headers(vec![
                 ("Accept", "application/json"),
                 ("Content-Type", "application/json"),
@@ -111,7 +117,10 @@ fn post_series_invalid_json() {
             .await;
 
         tracing::info!(target: "v2/series", "Request Error: {:?}", res);
-        assert_eq!(status, reqwest::StatusCode::from_u16(400).unwrap());
+        assert_eq!(
+            res.unwrap_err().status(),
+            Some(reqwest::StatusCode::from_u16(400).unwrap())
+        );
     });
 }
 
@@ -127,8 +136,9 @@ fn series_post_fails_invalid_api_key() {
         builder_ref = builder_ref.with_subscriber();
     }
     tokio_test::block_on(async {
-        let (status, res) = builder_ref
+        let res = builder_ref
             .post_series()
+            .unwrap()
             .headers(vec![
                 ("Accept", "application/json"),
                 ("Content-Type", "application/json"),
@@ -156,6 +166,270 @@ fn series_post_fails_invalid_api_key() {
             .await;
 
         tracing::info!(target: "v2/series", "Unauthorized Error: {:?}", res);
-        assert_eq!(status, reqwest::StatusCode::from_u16(403).unwrap());
+        assert_eq!(
+            res.unwrap_err().status(),
+            Some(reqwest::StatusCode::from_u16(403).unwrap())
+        );
+    });
+}
+
+#[test]
+fn post_typed_series() {
+    let server = MockServer::start(vec![MockResponse::new(202, r#"{ "errors": [] }"#)]);
+    let client = Client::new()
+        .with_api_key("<api_key>")
+        .with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let res = client
+            .post_series()
+            .unwrap()
+            .series(vec![series::MetricSeries::new(
+                "rpc_latency",
+                series::MetricIntakeType::Gauge,
+            )
+            .with_point(1660157680, 10.0)
+            .with_point(1660157690, 5.0)
+            .with_tags(vec!["gridfin:", "env:test"])
+            .with_unit("millisecond")
+            .with_resource("latency", "time")])
+            .execute()
+            .await;
+        assert_eq!(res.unwrap().status, 202);
     });
+
+    let body: serde_json::Value = serde_json::from_slice(&server.requests()[0].body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "series": [{
+                "metric": "rpc_latency",
+                "type": 3,
+                "unit": "millisecond",
+                "tags": [ "gridfin:", "env:test" ],
+                "points": [
+                    { "timestamp": 1660157680, "value": 10.0 },
+                    { "timestamp": 1660157690, "value": 5.0 }
+                ],
+                "resources": [{ "name": "latency", "type": "time" }]
+            }]
+        })
+    );
+}
+
+#[test]
+fn deserialize_series_payload() {
+    let payload: series::SeriesPayload = serde_json::from_str(
+        r#"{
+            "series": [{
+                "metric": "rpc_latency",
+                "type": 1,
+                "interval": 100000,
+                "points": [ { "timestamp": 1660157680, "value": 10.0 } ],
+                "metadata": { "origin": { "metric_type": 1, "product": 1, "service": 1 } }
+            }]
+        }"#,
+    )
+    .unwrap();
+    let series = &payload.series[0];
+    assert_eq!(series.type_, Some(series::MetricIntakeType::Count));
+    assert_eq!(series.interval, Some(100000));
+    assert_eq!(
+        series
+            .metadata
+            .as_ref()
+            .unwrap()
+            .origin
+            .as_ref()
+            .unwrap()
+            .product,
+        Some(1)
+    );
+    assert!(serde_json::from_str::<series::MetricIntakeType>("4").is_err());
+}
+
+fn many_series(count: usize) -> Vec<series::MetricSeries> {
+    (0..count)
+        .map(|i| {
+            series::MetricSeries::new("rpc_latency", series::MetricIntakeType::Count)
+                .with_tag(&format!("worker:{:08}", i))
+                .with_interval(10)
+                .with_point(1660157680, i as f64)
+        })
+        .collect()
+}
+
+#[test]
+fn batch_series_within_payload_limit() {
+    let series = many_series(20_000);
+    let batches = series::batch_series(series.clone(), None)
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert!(batches.len() > 1);
+
+    let mut decoded = vec![];
+    for batch in batches {
+        assert!(batch.body.len() <= series::MAX_PAYLOAD_SIZE);
+        assert_eq!(batch.encoding, None);
+        let payload: series::SeriesPayload = serde_json::from_slice(&batch.body).unwrap();
+        decoded.extend(payload.series);
+    }
+    assert_eq!(decoded, series);
+}
+
+#[test]
+fn batch_series_rejects_oversized_series() {
+    let series = vec![
+        series::MetricSeries::new("rpc_latency", series::MetricIntakeType::Gauge)
+            .with_tag(&"a".repeat(series::MAX_PAYLOAD_SIZE))
+            .with_point(1660157680, 1.0),
+        series::MetricSeries::new("rpc_errors", series::MetricIntakeType::Count)
+            .with_point(1660157680, 2.0),
+    ];
+    let mut batches = series::batch_series(series, None).into_iter();
+    match batches.next() {
+        Some(Err(Error::Validation(_))) => (),
+        r => panic!("Expected a validation error, got {:?}", r),
+    }
+    // The series that fit are still batched
+    let batch = batches.next().unwrap().unwrap();
+    let payload: series::SeriesPayload = serde_json::from_slice(&batch.body).unwrap();
+    assert_eq!(payload.series.len(), 1);
+    assert_eq!(payload.series[0].metric, "rpc_errors");
+    assert!(batches.next().is_none());
+}
+
+#[test]
+fn batch_series_splits_oversized_series_by_points() {
+    let mut series =
+        series::MetricSeries::new("rpc_latency", series::MetricIntakeType::Gauge).with_interval(1);
+    for i in 0..40_000 {
+        series = series.with_point(1660157680 + i, i as f64);
+    }
+    let batches = series::batch_series(vec![series.clone()], None)
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert!(batches.len() > 1);
+
+    let mut points = vec![];
+    for batch in batches {
+        assert!(batch.body.len() <= series::MAX_PAYLOAD_SIZE);
+        let payload: series::SeriesPayload = serde_json::from_slice(&batch.body).unwrap();
+        for part in payload.series {
+            assert_eq!(part.metric, "rpc_latency");
+            points.extend(part.points);
+        }
+    }
+    assert_eq!(points, series.points);
+}
+
+#[test]
+fn post_typed_series_in_batches() {
+    let server = MockServer::start(vec![
+        MockResponse::new(202, r#"{ "errors": [] }"#),
+        MockResponse::new(202, r#"{ "errors": [ "dropped point" ] }"#),
+    ]);
+    let client = Client::new().with_base_url(&server.url);
+    let series = many_series(20_000);
+    let expected_batches = series::batch_series(series.clone(), None).len();
+
+    tokio_test::block_on(async {
+        let response = client
+            .post_series()
+            .unwrap()
+            .series(series)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(response.status, 202);
+        assert_eq!(
+            response.data.errors.len(),
+            expected_batches - 1,
+            "errors from every batch are aggregated"
+        );
+    });
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), expected_batches);
+    let sent: usize = requests
+        .iter()
+        .map(|r| {
+            serde_json::from_slice::<series::SeriesPayload>(&r.body)
+                .unwrap()
+                .series
+                .len()
+        })
+        .sum();
+    assert_eq!(sent, 20_000);
+}
+
+#[test]
+fn post_typed_series_reports_failed_batch() {
+    let server = MockServer::start(vec![
+        MockResponse::new(202, r#"{ "errors": [] }"#),
+        MockResponse::new(413, r#"{ "errors": [ "Payload too large" ] }"#),
+        MockResponse::new(202, r#"{ "errors": [] }"#),
+    ]);
+    let client = Client::new().with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let results = client
+            .post_series()
+            .unwrap()
+            .series(many_series(20_000))
+            .execute_batches()
+            .await;
+        assert!(results.len() > 2);
+        assert!(results[0].is_ok());
+        assert_eq!(
+            results[1].as_ref().unwrap_err().status(),
+            Some(reqwest::StatusCode::PAYLOAD_TOO_LARGE)
+        );
+        assert!(results[2..].iter().all(|r| r.is_ok()));
+    });
+}
+
+#[test]
+fn post_typed_series_sends_batches_around_oversized_series() {
+    let server = MockServer::start(vec![MockResponse::new(202, r#"{ "errors": [] }"#)]);
+    let client = Client::new().with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let results = client
+            .post_series()
+            .unwrap()
+            .series(vec![
+                series::MetricSeries::new("rpc_latency", series::MetricIntakeType::Gauge)
+                    .with_tag(&"a".repeat(series::MAX_PAYLOAD_SIZE))
+                    .with_point(1660157680, 1.0),
+                series::MetricSeries::new("rpc_errors", series::MetricIntakeType::Count)
+                    .with_point(1660157680, 2.0),
+            ])
+            .execute_batches()
+            .await;
+        assert_eq!(results.len(), 2);
+        assert!(matches!(results[0], Err(Error::Validation(_))));
+        assert_eq!(results[1].as_ref().unwrap().status, 202);
+    });
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    let payload: series::SeriesPayload = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(payload.series[0].metric, "rpc_errors");
+}
+
+#[test]
+fn batch_compressed_series() {
+    let series = many_series(20_000);
+    let batches = series::batch_series(series.clone(), Some(&Compression::gzip()))
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    for batch in &batches {
+        assert!(batch.body.len() <= series::MAX_PAYLOAD_SIZE);
+        assert_eq!(batch.encoding, Some(ContentEncoding::Gzip));
+    }
+    assert!(batches.len() < series::batch_series(series, None).len());
 }
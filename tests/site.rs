@@ -0,0 +1,80 @@
+use ddog::prelude::*;
+
+mod common;
+use common::{MockResponse, MockServer};
+
+#[test]
+fn sites_resolve_api_urls() {
+    assert_eq!(Site::US1.api_url(), BASE_API_URL);
+    assert_eq!(Site::US3.api_url(), "https://api.us3.datadoghq.com/api");
+    assert_eq!(Site::US5.api_url(), "https://api.us5.datadoghq.com/api");
+    assert_eq!(Site::EU1.api_url(), "https://api.datadoghq.eu/api");
+    assert_eq!(Site::AP1.api_url(), "https://api.ap1.datadoghq.com/api");
+    assert_eq!(Site::US1Fed.api_url(), "https://api.ddog-gov.com/api");
+    assert_eq!("datadoghq.eu".parse::<Site>().unwrap(), Site::EU1);
+    assert_eq!(
+        "http://localhost:8080/api/"
+            .parse::<Site>()
+            .unwrap()
+            .api_url(),
+        "http://localhost:8080/api"
+    );
+}
+
+#[test]
+fn client_routes_use_configured_site() {
+    let server = MockServer::start(vec![MockResponse::new(202, r#"{ "errors": [] }"#)]);
+    let mut client = Client::new()
+        .with_api_key("<api_key>")
+        .with_site(Site::Custom(server.url.clone()));
+
+    tokio_test::block_on(async {
+        let res = client
+            .v2()
+            .post_series()
+            .unwrap()
+            .body(r#"{ "series": [] }"#)
+            .execute()
+            .await;
+        let response = res.unwrap();
+        assert_eq!(response.status, 202);
+        assert_eq!(response.data, series::SeriesResponse { errors: vec![] });
+    });
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].request_line, "POST /api/v2/series HTTP/1.1");
+    assert_eq!(requests[0].header("dd-api-key"), Some("<api_key>"));
+    assert_eq!(requests[0].body, br#"{ "series": [] }"#.to_vec());
+}
+
+#[test]
+fn builder_routes_use_configured_site() {
+    let server = MockServer::start(vec![MockResponse::new(
+        403,
+        r#"{ "errors": [ "Forbidden" ] }"#,
+    )]);
+    let mut builder = builder::Builder::new();
+    tokio_test::block_on(async {
+        let res = builder
+            .with_site(Site::Custom(server.url.clone()))
+            .v1()
+            .post_distribution()
+            .unwrap()
+            .with_api_key("<api_key>")
+            .body(r#"{ "series": [] }"#)
+            .execute()
+            .await;
+        match res {
+            Err(Error::Status { status, errors, .. }) => {
+                assert_eq!(status, 403);
+                assert_eq!(errors, vec![String::from("Forbidden")]);
+            }
+            r => panic!("Expected a status error, got {:?}", r),
+        }
+    });
+    assert_eq!(
+        server.requests()[0].request_line,
+        "POST /api/v1/distribution_points HTTP/1.1"
+    );
+}
@@ -4,6 +4,24 @@ extern crate dotenv;
 
 use dotenv::dotenv;
 
+mod common;
+use common::{MockResponse, MockServer};
+
+const TAG_CONFIGURATION: &str = r#"{
+    "data": {
+        "type": "manage_tags",
+        "id": "rpc.latency",
+        "attributes": {
+            "aggregations": [ { "space": "sum", "time": "sum" } ],
+            "created_at": "2020-03-25T09:48:37.463835Z",
+            "include_percentiles": false,
+            "metric_type": "count",
+            "modified_at": "2020-03-26T09:48:37.463835Z",
+            "tags": [ "app", "datacenter" ]
+        }
+    }
+}"#;
+
 #[test]
 fn create_new_tag_explicitly() {
     dotenv().ok();
@@ -22,8 +40,9 @@ fn create_new_tag_explicitly() {
         builder_ref = builder_ref.with_subscriber();
     }
     tokio_test::block_on(async {
-        let (status, res) = builder_ref
+        let res = builder_ref
             .create_new_tag_config("rpc_latency")
+            .unwrap()
             .headers(vec![
                 ("Accept", "application/json"),
                 ("Content-Type", "application/json"),
@@ -46,8 +65,7 @@ fn create_new_tag_explicitly() {
             .await;
 
         tracing::info!(target: "v2/metrics/{}/tags", "Response: {:?}", res);
-        tracing::info!(target: "v2/metrics/{}/tags", "Response: {:?}", status);
-        // assert_eq!(status, 201);
+        // assert_eq!(res.unwrap().status, 201);
         // TODO: make api key work
     });
 }
@@ -64,8 +82,9 @@ fn create_new_tag_forbidden_key() {
         builder_ref = builder_ref.with_subscriber();
     }
     tokio_test::block_on(async {
-        let (status, res) = builder_ref
+        let res = builder_ref
             .create_new_tag_config("rpc_latency")
+            .unwrap()
             .headers(vec![
                 ("Accept", "application/json"),
                 ("Content-Type", "application/json"),
@@ -88,10 +107,202 @@ fn create_new_tag_forbidden_key() {
             .await;
 
         tracing::info!(target: "v2/series", "Response: {:?}", res);
-        assert_eq!(status, 403);
+        assert_eq!(
+            res.unwrap_err().status(),
+            Some(reqwest::StatusCode::FORBIDDEN)
+        );
     });
 }
 
+#[test]
+fn create_typed_tag_configuration() {
+    let server = MockServer::start(vec![MockResponse::new(201, TAG_CONFIGURATION)]);
+    let client = Client::new().with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let res = client
+            .create_new_tag_config("rpc.latency")
+            .unwrap()
+            .configuration(
+                tags::TagsResponseAttributes::new(vec!["app", "datacenter"])
+                    .with_metric_type(tags::MetricType::Count)
+                    .with_aggregation(tags::SpaceEnum::Sum, tags::TimeEnum::Sum),
+            )
+            .execute()
+            .await;
+        let attributes = res.unwrap().into_inner().data.attributes;
+        assert_eq!(attributes.type_, Some(tags::MetricType::Count));
+        assert_eq!(
+            attributes.created_at.unwrap().to_rfc3339(),
+            "2020-03-25T09:48:37.463835+00:00"
+        );
+        assert!(attributes.modified_at > attributes.created_at);
+    });
+
+    let request = &server.requests()[0];
+    assert_eq!(
+        request.request_line,
+        "POST /api/v2/metrics/rpc.latency/tags HTTP/1.1"
+    );
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "data": {
+                "type": "manage_tags",
+                "id": "rpc.latency",
+                "attributes": {
+                    "metric_type": "count",
+                    "tags": [ "app", "datacenter" ],
+                    "aggregations": [ { "space": "sum", "time": "sum" } ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn get_update_and_delete_tag_configuration() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, TAG_CONFIGURATION),
+        MockResponse::new(200, TAG_CONFIGURATION),
+        MockResponse::new(204, ""),
+    ]);
+    let mut builder = Builder::new();
+    builder.v2().with_site(Site::Custom(server.url.clone()));
+
+    tokio_test::block_on(async {
+        let res = builder
+            .get_tag_config("rpc.latency")
+            .unwrap()
+            .execute()
+            .await;
+        assert_eq!(
+            res.unwrap().data.data.attributes.tags,
+            vec!["app", "datacenter"]
+        );
+
+        let res = builder
+            .update_tag_config("rpc.latency")
+            .unwrap()
+            .configuration(
+                tags::TagsResponseAttributes::new(vec!["app"]).with_exclude_tags_mode(true),
+            )
+            .execute()
+            .await;
+        assert_eq!(res.unwrap().data.data.id, "rpc.latency");
+
+        let res = builder
+            .delete_tag_config("rpc.latency")
+            .unwrap()
+            .execute()
+            .await;
+        assert_eq!(res.unwrap().status, 204);
+    });
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].request_line,
+        "GET /api/v2/metrics/rpc.latency/tags HTTP/1.1"
+    );
+    assert_eq!(
+        requests[1].request_line,
+        "PATCH /api/v2/metrics/rpc.latency/tags HTTP/1.1"
+    );
+    let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!(
+        body["data"]["attributes"],
+        serde_json::json!({ "tags": [ "app" ], "exclude_tags_mode": true })
+    );
+    assert_eq!(
+        requests[2].request_line,
+        "DELETE /api/v2/metrics/rpc.latency/tags HTTP/1.1"
+    );
+}
+
+#[test]
+fn tag_only_update_omits_include_percentiles() {
+    let server = MockServer::start(vec![MockResponse::new(200, TAG_CONFIGURATION)]);
+    let client = Client::new().with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        client
+            .update_tag_config("rpc.latency")
+            .unwrap()
+            .configuration(tags::TagsResponseAttributes::new(vec!["app"]))
+            .execute()
+            .await
+            .unwrap();
+        client
+            .update_tag_config("rpc.latency")
+            .unwrap()
+            .configuration(
+                tags::TagsResponseAttributes::new(vec!["app"]).with_included_percentiles(false),
+            )
+            .execute()
+            .await
+            .unwrap();
+    });
+
+    let requests = server.requests();
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(
+        body["data"]["attributes"],
+        serde_json::json!({ "tags": [ "app" ] })
+    );
+    let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!(
+        body["data"]["attributes"],
+        serde_json::json!({ "include_percentiles": false, "tags": [ "app" ] })
+    );
+}
+
+#[test]
+fn list_tag_configurations_with_filters() {
+    let server = MockServer::start(vec![MockResponse::new(
+        200,
+        r#"{
+            "data": [
+                { "type": "metrics", "id": "system.cpu.idle" },
+                { "type": "manage_tags", "id": "rpc.latency", "attributes": { "metric_type": "distribution", "include_percentiles": true, "tags": [ "app" ] } }
+            ]
+        }"#,
+    )]);
+    let client = Client::new().with_base_url(&server.url);
+
+    tokio_test::block_on(async {
+        let res = client
+            .list_tag_configs()
+            .unwrap()
+            .with_configured(true)
+            .with_metric_type(list_tag_configurations::MetricTypeFilter::Distribution)
+            .with_tags("env:prod AND service:web")
+            .with_window_seconds(3600)
+            .execute()
+            .await;
+        let data = res.unwrap().into_inner().data;
+        assert_eq!(data[0].type_, tags::MetricTag::Metrics);
+        assert_eq!(data[0].attributes, None);
+        let attributes = data[1].attributes.as_ref().unwrap();
+        assert_eq!(attributes.type_, Some(tags::MetricType::Distribution));
+        assert_eq!(attributes.included_percentiles, Some(true));
+    });
+
+    assert_eq!(
+        server.requests()[0].request_line,
+        "GET /api/v2/metrics?filter%5Bconfigured%5D=true&filter%5Bmetric_type%5D=distribution&filter%5Btags%5D=env%3Aprod+AND+service%3Aweb&window%5Bseconds%5D=3600 HTTP/1.1"
+    );
+}
+
+#[test]
+fn tag_configuration_routes_require_metric_name() {
+    let res: ddog::Result<types::response::Response<tags::TagsResponse>> =
+        tokio_test::block_on(get_tags::GetTags::default().execute());
+    assert!(matches!(res, Err(Error::Validation(_))));
+    let res = tokio_test::block_on(update_tags::UpdateTags::default().execute());
+    assert!(matches!(res, Err(Error::Validation(_))));
+}
+
 // #[test]
 // fn post_metrics_routes() {
 //     let mut builder = builder::Builder::new();